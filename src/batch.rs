@@ -0,0 +1,175 @@
+//! Batch module
+//!
+//! Entry points for running calculations over many rows of input at once, e.g.
+//! a CSV export from a lab system.
+
+use crate::{
+    calculators::egfr_ckd_epi,
+    history::{SexAtBirth, Years},
+    lab::{blood::creatinine::Creatinine, gfr::Gfr},
+    units::{GfrUnit, MgdL, UmolL},
+};
+
+/// Header expected at the top of an eGFR input CSV.
+pub const EGFR_CSV_HEADER: &str = "scr,unit,age,sex";
+
+/// Columns appended to each row of an eGFR output CSV.
+pub const EGFR_CSV_ADDED_COLUMNS: &str = "egfr,ckd_stage";
+
+/// Problems encountered while reading CSV input.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CsvError {
+    /// The input was empty or didn't start with the expected header.
+    MissingHeader,
+    /// A row had the wrong number of comma-separated fields.
+    ColumnCount {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// A field couldn't be interpreted for its column.
+    InvalidValue {
+        line: usize,
+        column: &'static str,
+        value: String,
+    },
+}
+impl std::fmt::Display for CsvError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CsvError::MissingHeader => write!(f, "expected header \"{EGFR_CSV_HEADER}\""),
+            CsvError::ColumnCount {
+                line,
+                expected,
+                found,
+            } => write!(f, "line {line}: expected {expected} columns, found {found}"),
+            CsvError::InvalidValue {
+                line,
+                column,
+                value,
+            } => write!(f, "line {line}: invalid {column} \"{value}\""),
+        }
+    }
+}
+impl std::error::Error for CsvError {}
+
+/// One row of eGFR input, with creatinine standardized to mg/dL.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EgfrRow {
+    pub scr: Creatinine<MgdL>,
+    pub age: Years,
+    pub sex: SexAtBirth,
+}
+impl EgfrRow {
+    /// Parse a single `scr,unit,age,sex` row. `line` is only used for error reporting.
+    ///
+    /// Creatinine may be given in mg/dL or µmol/L ("umol/L" is also accepted); sex
+    /// may be given as "F"/"Female" or "M"/"Male", case-insensitively.
+    pub fn from_csv_row(line: usize, row: &str) -> Result<Self, CsvError> {
+        let fields: Vec<&str> = row.split(',').map(str::trim).collect();
+        if fields.len() != 4 {
+            return Err(CsvError::ColumnCount {
+                line,
+                expected: 4,
+                found: fields.len(),
+            });
+        }
+        let invalid = |column, value: &str| CsvError::InvalidValue {
+            line,
+            column,
+            value: value.to_string(),
+        };
+
+        let scr_value: f64 = fields[0].parse().map_err(|_| invalid("scr", fields[0]))?;
+        let scr = match fields[1].to_lowercase().as_str() {
+            "mg/dl" => Creatinine::<MgdL>::from(scr_value),
+            "µmol/l" | "umol/l" => Creatinine::from(Creatinine::<UmolL>::from(scr_value)),
+            _ => return Err(invalid("unit", fields[1])),
+        };
+        let age: f64 = fields[2].parse().map_err(|_| invalid("age", fields[2]))?;
+        let sex = match fields[3].to_lowercase().as_str() {
+            "f" | "female" => SexAtBirth::Female,
+            "m" | "male" => SexAtBirth::Male,
+            _ => return Err(invalid("sex", fields[3])),
+        };
+
+        Ok(Self {
+            scr,
+            age: Years(age),
+            sex,
+        })
+    }
+}
+
+/// Compute CKD-EPI eGFR and CKD stage for every row of a `scr,unit,age,sex` CSV.
+///
+/// The output repeats each input row with `egfr` (rounded to a whole number) and
+/// `ckd_stage` columns appended; the stage is taken from the rounded eGFR so the
+/// two columns agree. Blank lines are skipped.
+pub fn process_egfr_csv(input: &str) -> Result<String, CsvError> {
+    let mut lines = input.lines().enumerate();
+    match lines.next() {
+        Some((_, header)) if header.trim() == EGFR_CSV_HEADER => {}
+        _ => return Err(CsvError::MissingHeader),
+    }
+
+    let mut output = format!("{EGFR_CSV_HEADER},{EGFR_CSV_ADDED_COLUMNS}\n");
+    for (idx, row) in lines.filter(|(_, row)| !row.trim().is_empty()) {
+        let parsed = EgfrRow::from_csv_row(idx + 1, row)?;
+        let gfr = egfr_ckd_epi(parsed.scr, parsed.age, parsed.sex);
+        let rounded = Gfr::<GfrUnit>::from(gfr.value().round());
+        output.push_str(&format!(
+            "{},{:.0},{}\n",
+            row.trim(),
+            rounded.value(),
+            rounded.stage()
+        ));
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn row_in_umol_l_is_standardized_to_mg_dl() {
+        let row = EgfrRow::from_csv_row(2, "88.4,umol/L,50,M").unwrap();
+        assert!((row.scr.value() - 1.0).abs() < 1e-9);
+        assert_eq!(row.sex, SexAtBirth::Male);
+    }
+
+    #[test]
+    fn row_with_unknown_sex_is_rejected() {
+        let err = EgfrRow::from_csv_row(3, "1.0,mg/dL,50,X").unwrap_err();
+        assert_eq!(
+            err,
+            CsvError::InvalidValue {
+                line: 3,
+                column: "sex",
+                value: "X".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn row_with_missing_column_is_rejected() {
+        let err = EgfrRow::from_csv_row(2, "1.0,mg/dL,50").unwrap_err();
+        assert!(matches!(err, CsvError::ColumnCount { found: 3, .. }));
+    }
+
+    #[test]
+    fn input_without_header_is_rejected() {
+        assert_eq!(
+            process_egfr_csv("1.0,mg/dL,50,M\n"),
+            Err(CsvError::MissingHeader)
+        );
+    }
+
+    #[test]
+    fn stage_agrees_with_rounded_egfr() {
+        // 1.36 mg/dL in a 60-year-old man is an eGFR of about 59.6
+        let output = process_egfr_csv("scr,unit,age,sex\n1.36,mg/dL,60,M\n").unwrap();
+        assert_eq!(output.lines().nth(1), Some("1.36,mg/dL,60,M,60,G2"));
+    }
+}
@@ -9,28 +9,60 @@
 //! and use SI as the "canonical" units for calculations.
 
 use crate::{
-    constants::{SBILI_UMOLL_TO_MGDL, SCR_UMOLL_TO_MGDL},
-    history::{Gender, Years},
+    constants::{M_TO_FT, SBILI_UMOLL_TO_MGDL, SCR_UMOLL_TO_MGDL},
+    history::{AgeGroup, SexAtBirth, Years},
     lab::{
         blood::{
-            bilirubin::Bilirubin, creatinine::Creatinine, glucose::Glucose, inr::Inr,
-            sodium::Sodium,
+            albumin::Albumin, alt::Alt, anion_gap::AnionGap, ast::Ast, bicarbonate::Bicarbonate,
+            bilirubin::Bilirubin, bun::Bun, calcium::Calcium, chloride::Chloride,
+            creatinine::Creatinine, cystatin_c::CystatinC, glucose::Glucose, hba1c::Hba1c,
+            hematocrit::Hematocrit, inr::Inr, osmolality::Osmolality, phosphate::Phosphate,
+            platelets::Platelets, potassium::Potassium, sodium::Sodium,
         },
-        gfr::Gfr,
-        vitals::{Bmi, BmiExt, Height, Weight},
+        gfr::{ClearanceRate, Gfr},
+        urine::Percentage,
+        vitals::{Bmi, BmiExt, BodySurfaceArea, BsaExt, Height, HeightExt, Weight, WeightExt},
     },
     units::{
+        albumin::AlbuminUnit,
+        bicarbonate::BicarbonateUnit,
         bilirubin::BilirubinUnit,
+        bun::BunUnit,
+        calcium::CalciumUnit,
+        chloride::ChlorideUnit,
         creatinine::CreatinineUnit,
+        enzyme::EnzymeUnit,
         glucose::GlucoseUnit,
+        phosphate::PhosphateUnit,
+        potassium::PotassiumUnit,
         sodium::SodiumUnit,
         vitals::{HeightUnit, WeightUnit},
-        GfrUnit, KgM2, MgdL, Unit,
+        GdL, GfrUnit, Kg, KgM2, MeqL, MgL, MgdL, MlMin, MmolL, MosmKg, PerNanoLiter, UmolL, Unit,
+        M2,
     },
 };
 
+pub mod alvarado;
+pub mod ascvd;
+pub mod blatchford;
 pub mod cha2ds2_va;
 pub mod cha2ds2_vasc;
+pub mod child_pugh;
+pub mod curb65;
+pub mod dialysis;
+pub mod dosing;
+pub mod framingham;
+pub mod gcs;
+pub mod has_bled;
+pub mod heart;
+pub mod mehran;
+pub mod meld;
+pub mod psi;
+pub mod qsofa;
+pub mod ranson;
+pub mod sirs;
+pub mod tdm;
+pub mod wells;
 
 /// Sodium correction for hyperglycemia.
 ///
@@ -67,16 +99,545 @@ where
     Sodium::from(N::from_mmol_l(corrected_na))
 }
 
+/// Estimated average glucose (eAG) from HbA1c, by the ADAG equation.
+///
+/// eAG (mg/dL) = 28.7 × A1c - 46.7. The result is returned in whichever glucose
+/// unit is requested, e.g. `eag_from_a1c::<MmolL>(a1c)`.
+pub fn eag_from_a1c<U>(a1c: Hba1c) -> Glucose<U>
+where
+    U: GlucoseUnit,
+    Glucose<U>: From<f64>,
+{
+    let eag_mg_dl = 28.7 * a1c.value() - 46.7;
+    Glucose::from(U::from_mmol_l(<MgdL as GlucoseUnit>::to_mmol_l(eag_mg_dl)))
+}
+
+/// HbA1c corresponding to an average glucose, the inverse of `eag_from_a1c`.
+///
+/// A1c = (eAG (mg/dL) + 46.7) / 28.7
+pub fn a1c_from_eag<U: GlucoseUnit>(glucose: Glucose<U>) -> Hba1c {
+    let eag_mg_dl = <MgdL as GlucoseUnit>::from_mmol_l(U::to_mmol_l(glucose.value()));
+    Hba1c::from((eag_mg_dl + 46.7) / 28.7)
+}
+
+/// Calcium correction for hypoalbuminemia.
+///
+/// Corrected Ca = measured Ca + 0.8 × (4.0 - albumin), with calcium in mg/dL and
+/// albumin in g/dL. Inputs are converted to conventional units for the calculation
+/// and the result is returned in the same units as the input calcium.
+pub fn corrected_calcium<C, A>(total_ca: Calcium<C>, albumin: Albumin<A>) -> Calcium<C>
+where
+    C: CalciumUnit,
+    A: AlbuminUnit,
+    Calcium<C>: From<f64>,
+{
+    let ca_mg_dl = MgdL::from_calcium_mmol_l(C::to_calcium_mmol_l(total_ca.value()));
+    let alb_g_dl = GdL::from_g_l(A::to_g_l(albumin.value()));
+
+    let corrected = ca_mg_dl + 0.8 * (4.0 - alb_g_dl);
+
+    Calcium::from(C::from_calcium_mmol_l(MgdL::to_calcium_mmol_l(corrected)))
+}
+
+/// Estimate total calcium from ionized calcium and albumin.
+///
+/// Roughly half of total calcium is ionized, and albumin binds about 0.8 mg/dL of
+/// calcium per g/dL, so: total Ca ≈ 2 × ionized Ca - 0.8 × (4.0 - albumin), in
+/// mg/dL and g/dL. This is the reverse of `corrected_calcium`, and the result is
+/// returned in the same units as the ionized calcium.
+///
+/// This is only a rough estimate. The ionized fraction shifts with pH (acidosis
+/// raises it), and with phosphate, citrate (e.g. after transfusion) and
+/// paraproteins, none of which are accounted for here. Prefer a measured total
+/// calcium where one is available.
+pub fn estimate_total_calcium<C, A>(ionized_ca: Calcium<C>, albumin: Albumin<A>) -> Calcium<C>
+where
+    C: CalciumUnit,
+    A: AlbuminUnit,
+    Calcium<C>: From<f64>,
+{
+    let ica_mg_dl = MgdL::from_calcium_mmol_l(C::to_calcium_mmol_l(ionized_ca.value()));
+    let alb_g_dl = GdL::from_g_l(A::to_g_l(albumin.value()));
+
+    let total = 2.0 * ica_mg_dl - 0.8 * (4.0 - alb_g_dl);
+
+    Calcium::from(C::from_calcium_mmol_l(MgdL::to_calcium_mmol_l(total)))
+}
+
+/// Calcium-phosphate product (mg²/dL²) above which the risk of metastatic
+/// (vascular and soft-tissue) calcification is considered high.
+pub const CA_PHOS_PRODUCT_HIGH_RISK: f64 = 55.0;
+
+/// Risk category for a calcium-phosphate product.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaPhosRisk {
+    /// Product ≤ 55 mg²/dL²
+    Acceptable,
+    /// Product > 55 mg²/dL²: high risk of metastatic calcification
+    HighRisk,
+}
+
+/// A calcium-phosphate product, in mg²/dL².
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct CaPhosProduct {
+    value: f64,
+}
+impl CaPhosProduct {
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+    /// Classify the product against `CA_PHOS_PRODUCT_HIGH_RISK`.
+    pub fn range(&self) -> CaPhosRisk {
+        if self.value > CA_PHOS_PRODUCT_HIGH_RISK {
+            CaPhosRisk::HighRisk
+        } else {
+            CaPhosRisk::Acceptable
+        }
+    }
+}
+impl std::fmt::Display for CaPhosProduct {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Ca × P ({:.0} mg²/dL²)", self.value)
+    }
+}
+
+/// Calcium-phosphate product for CKD mineral and bone disorder.
+///
+/// Ca × P with both in mg/dL, the conventional way it's reported; SI inputs are
+/// converted first. Use total calcium (corrected for albumin where relevant).
+pub fn calcium_phosphate_product<C, P>(ca: Calcium<C>, phos: Phosphate<P>) -> CaPhosProduct
+where
+    C: CalciumUnit,
+    P: PhosphateUnit,
+{
+    let ca_mg_dl = MgdL::from_calcium_mmol_l(C::to_calcium_mmol_l(ca.value()));
+    let phos_mg_dl = MgdL::from_phosphate_mmol_l(P::to_phosphate_mmol_l(phos.value()));
+    CaPhosProduct {
+        value: ca_mg_dl * phos_mg_dl,
+    }
+}
+
+/// Serum anion gap: Na - (Cl + HCO₃).
+///
+/// All inputs are converted to mmol/L (numerically equal to mEq/L for these
+/// monovalent ions).
+pub fn anion_gap<N, C, B>(na: Sodium<N>, cl: Chloride<C>, hco3: Bicarbonate<B>) -> AnionGap<MeqL>
+where
+    N: SodiumUnit,
+    C: ChlorideUnit,
+    B: BicarbonateUnit,
+{
+    let na_mmol = N::to_mmol_l(na.value());
+    let cl_mmol = C::to_mmol_l(cl.value());
+    let hco3_mmol = B::to_mmol_l(hco3.value());
+
+    AnionGap::from(na_mmol - (cl_mmol + hco3_mmol))
+}
+
+/// Serum anion gap including potassium: (Na + K) - (Cl + HCO₃).
+///
+/// Expect values about 4 mEq/L higher than `anion_gap`; the default `range()`
+/// thresholds are calibrated for the potassium-free gap.
+pub fn anion_gap_with_k<N, K, C, B>(
+    na: Sodium<N>,
+    k: Potassium<K>,
+    cl: Chloride<C>,
+    hco3: Bicarbonate<B>,
+) -> AnionGap<MeqL>
+where
+    N: SodiumUnit,
+    K: PotassiumUnit,
+    C: ChlorideUnit,
+    B: BicarbonateUnit,
+{
+    let without_k = anion_gap(na, cl, hco3);
+    AnionGap::from(without_k.value() + K::to_mmol_l(k.value()))
+}
+
+/// Urine anion gap: (UNa + UK) - UCl, in mmol/L.
+///
+/// Used in a non-gap metabolic acidosis as a proxy for urinary ammonium. A negative
+/// gap means ammonium excretion is intact, suggesting GI bicarbonate loss (e.g.
+/// diarrhea); a positive gap suggests a renal tubular acidosis.
+///
+/// The electrolytes are spot urine values, typed as the serum measurements are.
+pub fn urine_anion_gap<N, K, C>(
+    urine_na: Sodium<N>,
+    urine_k: Potassium<K>,
+    urine_cl: Chloride<C>,
+) -> f64
+where
+    N: SodiumUnit,
+    K: PotassiumUnit,
+    C: ChlorideUnit,
+{
+    N::to_mmol_l(urine_na.value()) + K::to_mmol_l(urine_k.value()) - C::to_mmol_l(urine_cl.value())
+}
+
+/// Calculated serum osmolality: 2 × Na + glucose + urea (all in mmol/L).
+///
+/// This is the SI form of the familiar `2 × Na + glucose/18 + BUN/2.8` (mg/dL), so
+/// inputs in any supported units give the same result.
+pub fn calculated_osmolality<N, G, B>(
+    na: Sodium<N>,
+    glucose: Glucose<G>,
+    bun: Bun<B>,
+) -> Osmolality<MosmKg>
+where
+    N: SodiumUnit,
+    G: GlucoseUnit,
+    B: BunUnit,
+{
+    let na_mmol = N::to_mmol_l(na.value());
+    let glu_mmol = G::to_mmol_l(glucose.value());
+    let urea_mmol = B::to_urea_mmol_l(bun.value());
+
+    Osmolality::from(2.0 * na_mmol + glu_mmol + urea_mmol)
+}
+
+/// Osmolar gap: measured minus calculated osmolality, in mOsm/kg.
+///
+/// A gap above ~10 suggests unmeasured osmoles such as ethanol, methanol or
+/// ethylene glycol.
+pub fn osmolar_gap<N, G, B>(
+    measured: Osmolality<MosmKg>,
+    na: Sodium<N>,
+    glucose: Glucose<G>,
+    bun: Bun<B>,
+) -> f64
+where
+    N: SodiumUnit,
+    G: GlucoseUnit,
+    B: BunUnit,
+{
+    measured.value() - calculated_osmolality(na, glucose, bun).value()
+}
+
+/// Estimated total body water (TBW), in liters.
+///
+/// TBW is weight times a fraction that depends on sex and age: 0.6 for children and
+/// adult men, 0.5 for adult women and elderly men, and 0.45 for elderly women.
+pub fn total_body_water<W: WeightUnit>(
+    weight: Weight<W>,
+    sex: SexAtBirth,
+    age_group: AgeGroup,
+) -> f64 {
+    let tbw_fraction = match (age_group, sex) {
+        (AgeGroup::Geriatric, SexAtBirth::Female) => 0.45,
+        (AgeGroup::Geriatric, SexAtBirth::Male) => 0.5,
+        (AgeGroup::Adult | AgeGroup::Adolescent, SexAtBirth::Female) => 0.5,
+        _ => 0.6,
+    };
+    W::to_kg(weight.value()) * tbw_fraction
+}
+
+/// Free water deficit in hypernatremia, in liters.
+///
+/// Deficit = TBW × (Na / 140 - 1), with TBW from `total_body_water`.
+///
+/// A measured Na of 140 mmol/L or below has no deficit, so the result is clamped to
+/// zero rather than going negative.
+pub fn free_water_deficit<U: SodiumUnit>(
+    weight: Weight<Kg>,
+    measured_na: Sodium<U>,
+    sex: SexAtBirth,
+    age_group: AgeGroup,
+) -> f64 {
+    let na_mmol = U::to_mmol_l(measured_na.value());
+    let deficit = total_body_water(weight, sex, age_group) * (na_mmol / 140.0 - 1.0);
+    deficit.max(0.0)
+}
+
+/// Sodium needed to raise serum Na from `current` to `target` in hyponatremia, in
+/// mmol.
+///
+/// Deficit = TBW × (target - current), with TBW from `total_body_water`. This
+/// ignores ongoing losses and the free water excreted once the cause is treated, so
+/// it's a starting estimate; the serum Na still has to be rechecked. A current Na
+/// at or above the target has no deficit, so the result is clamped to zero.
+pub fn sodium_deficit<N, T, W>(
+    current: Sodium<N>,
+    target: Sodium<T>,
+    weight: Weight<W>,
+    sex: SexAtBirth,
+    age_group: AgeGroup,
+) -> f64
+where
+    N: SodiumUnit,
+    T: SodiumUnit,
+    W: WeightUnit,
+{
+    let shortfall = T::to_mmol_l(target.value()) - N::to_mmol_l(current.value());
+    (total_body_water(weight, sex, age_group) * shortfall).max(0.0)
+}
+
+/// Average rate of change of serum Na between two draws `hours` apart, in
+/// mmol/L per hour. Falling sodium gives a negative rate.
+pub fn na_correction_rate<A: SodiumUnit, B: SodiumUnit>(
+    start: Sodium<A>,
+    end: Sodium<B>,
+    hours: f64,
+) -> f64 {
+    (B::to_mmol_l(end.value()) - A::to_mmol_l(start.value())) / hours
+}
+
+/// Highest rise in serum Na (mmol/L) over any 24 hours when correcting chronic
+/// hyponatremia; faster correction risks osmotic demyelination syndrome (ODS).
+pub const NA_CORRECTION_MAX_24H_MMOL_L: f64 = 8.0;
+
+/// Serum Na (mmol/L) below which hyponatremia is treated as severe by
+/// `plan_na_correction`, and rechecked more often.
+pub const NA_SEVERE_HYPONATREMIA_MMOL_L: f64 = 120.0;
+
+/// A 24-hour sodium correction plan from `plan_na_correction`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NaCorrectionPlan {
+    rise_mmol_l: f64,
+    sodium_mmol: f64,
+    rate_mmol_l_per_h: f64,
+    recheck_hours: Vec<f64>,
+}
+impl NaCorrectionPlan {
+    /// Planned rise in serum Na over 24 hours, in mmol/L.
+    pub fn rise_mmol_l(&self) -> f64 {
+        self.rise_mmol_l
+    }
+    /// Total sodium to give over 24 hours, in mmol.
+    pub fn sodium_mmol(&self) -> f64 {
+        self.sodium_mmol
+    }
+    /// Average planned rise in serum Na, in mmol/L per hour.
+    pub fn rate_mmol_l_per_h(&self) -> f64 {
+        self.rate_mmol_l_per_h
+    }
+    /// Hours after the start at which to recheck serum Na.
+    pub fn recheck_hours(&self) -> &[f64] {
+        &self.recheck_hours
+    }
+}
+
+/// Plan the first 24 hours of sodium replacement in hyponatremia.
+///
+/// The requested rise is capped at `NA_CORRECTION_MAX_24H_MMOL_L` (and floored at
+/// zero), and the sodium to give is the `sodium_deficit` for that rise using adult
+/// TBW fractions. Serum Na is rechecked every 4 hours when the starting Na is below
+/// `NA_SEVERE_HYPONATREMIA_MMOL_L`, otherwise every 6 hours; if a recheck shows the
+/// rise running ahead of the planned rate, slow or stop the sodium.
+pub fn plan_na_correction<N, W>(
+    start: Sodium<N>,
+    target_rise_24h: f64,
+    weight: Weight<W>,
+    sex: SexAtBirth,
+) -> NaCorrectionPlan
+where
+    N: SodiumUnit,
+    W: WeightUnit,
+{
+    let rise = target_rise_24h.clamp(0.0, NA_CORRECTION_MAX_24H_MMOL_L);
+    let start_mmol = N::to_mmol_l(start.value());
+    let start = Sodium::<MmolL>::from(start_mmol);
+    let target = Sodium::<MmolL>::from(start_mmol + rise);
+
+    let interval: u8 = if start_mmol < NA_SEVERE_HYPONATREMIA_MMOL_L {
+        4
+    } else {
+        6
+    };
+    NaCorrectionPlan {
+        rise_mmol_l: rise,
+        sodium_mmol: sodium_deficit(start, target, weight, sex, AgeGroup::Adult),
+        rate_mmol_l_per_h: na_correction_rate(start, target, 24.0),
+        recheck_hours: (interval..=24)
+            .step_by(usize::from(interval))
+            .map(f64::from)
+            .collect(),
+    }
+}
+
+/// Winters' formula: expected PCO₂ in metabolic acidosis.
+///
+/// Expected PCO₂ = 1.5 × HCO₃ + 8 ± 2 mmHg, returned as `(low, high)`. A measured
+/// PCO₂ above the range suggests a concurrent respiratory acidosis; below it, a
+/// concurrent respiratory alkalosis.
+pub fn winters_expected_pco2<B: BicarbonateUnit>(hco3: Bicarbonate<B>) -> (f64, f64) {
+    let expected = 1.5 * B::to_mmol_l(hco3.value()) + 8.0;
+    (expected - 2.0, expected + 2.0)
+}
+
+/// Bicarbonate space (L/kg) used by `bicarbonate_deficit`.
+pub const BICARB_SPACE_L_PER_KG: f64 = 0.5;
+
+/// Bicarbonate space (L/kg) used by `bicarbonate_deficit` in severe acidosis.
+///
+/// This is a deliberate dose-limiting choice, not the physiologic value: the
+/// apparent bicarbonate space grows as acidosis worsens, to roughly 0.7-0.8 L/kg
+/// or more, so 0.4 L/kg underestimates the true deficit.
+pub const BICARB_SPACE_SEVERE_L_PER_KG: f64 = 0.4;
+
+/// Bicarbonate needed to raise serum HCO₃ to `target`, in mEq.
+///
+/// Deficit = space × kg × (target - current). The apparent volume of distribution
+/// of bicarbonate is conventionally taken as 0.5 L/kg. In severe acidosis the true
+/// apparent space is larger (roughly 0.7-0.8 L/kg or more), but with
+/// `severe_acidosis` a smaller 0.4 L/kg is used on purpose to limit the first dose,
+/// so the result deliberately understates the full deficit. Either way the result is
+/// an estimate to guide a partial correction, not a full dose.
+/// A current HCO₃ at or above the target has no deficit, so the result is clamped
+/// to zero.
+pub fn bicarbonate_deficit<B, T, W>(
+    current: Bicarbonate<B>,
+    target: Bicarbonate<T>,
+    weight: Weight<W>,
+    severe_acidosis: bool,
+) -> f64
+where
+    B: BicarbonateUnit,
+    T: BicarbonateUnit,
+    W: WeightUnit,
+{
+    let space = if severe_acidosis {
+        BICARB_SPACE_SEVERE_L_PER_KG
+    } else {
+        BICARB_SPACE_L_PER_KG
+    };
+    let wt_kg = W::to_kg(weight.value());
+    let shortfall = T::to_mmol_l(target.value()) - B::to_mmol_l(current.value());
+    (space * wt_kg * shortfall).max(0.0)
+}
+
+/// Sea-level atmospheric pressure, used by `a_a_gradient` when none is given.
+pub const SEA_LEVEL_ATM_MMHG: f64 = 760.0;
+
+/// Alveolar-arterial oxygen gradient, in mmHg.
+///
+/// Alveolar PAO₂ = FiO₂ × (Patm - 47) - PaCO₂ / 0.8, where 47 mmHg is water vapor
+/// pressure and 0.8 the respiratory quotient; the gradient is PAO₂ - PaO₂. FiO₂ is a
+/// fraction (0.21 on room air) and the pressures are in mmHg. `atm_pressure_mmhg`
+/// defaults to sea level when `None`.
+pub fn a_a_gradient(fio2: f64, paco2: f64, pao2: f64, atm_pressure_mmhg: Option<f64>) -> f64 {
+    let patm = atm_pressure_mmhg.unwrap_or(SEA_LEVEL_ATM_MMHG);
+    let alveolar_po2 = fio2 * (patm - 47.0) - paco2 / 0.8;
+    alveolar_po2 - pao2
+}
+
+/// Upper limit of a normal A-a gradient on room air for age: age / 4 + 4 mmHg.
+pub fn expected_a_a_gradient(age: Years) -> f64 {
+    age.0 / 4.0 + 4.0
+}
+
+/// Formulas for correcting the QT interval for heart rate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QtcMethod {
+    /// QT / √RR; over-corrects at high heart rates
+    Bazett,
+    /// QT / ∛RR
+    Fridericia,
+    /// QT + 154 × (1 - RR)
+    Framingham,
+    /// QT + 1.75 × (HR - 60)
+    Hodges,
+}
+
+/// Corrected QT interval (QTc) in milliseconds.
+///
+/// Takes the measured QT in ms and the RR interval in seconds (see
+/// `rr_interval_from_hr`). All methods agree at a heart rate of 60 (RR = 1 s).
+pub fn qtc(qt_ms: f64, rr_interval_s: f64, method: QtcMethod) -> f64 {
+    match method {
+        QtcMethod::Bazett => qt_ms / rr_interval_s.sqrt(),
+        QtcMethod::Fridericia => qt_ms / rr_interval_s.cbrt(),
+        QtcMethod::Framingham => qt_ms + 154.0 * (1.0 - rr_interval_s),
+        QtcMethod::Hodges => qt_ms + 1.75 * (60.0 / rr_interval_s - 60.0),
+    }
+}
+
+/// RR interval in seconds for a heart rate in beats per minute.
+pub fn rr_interval_from_hr(heart_rate: f64) -> f64 {
+    60.0 / heart_rate
+}
+
+/// Classification of a hyperglycemic crisis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HyperglycemicCrisis {
+    /// Diabetic ketoacidosis
+    Dka,
+    /// Hyperosmolar hyperglycemic state
+    Hhs,
+    /// Hyperosmolar state with concurrent ketoacidosis
+    Mixed,
+}
+
+/// Distinguish DKA from HHS (or an overlap of the two) using ADA criteria.
+///
+/// * DKA: glucose > 250 mg/dL (13.9 mmol/L), pH < 7.30, HCO₃ < 18 mmol/L, ketones present
+/// * HHS: glucose > 600 mg/dL (33.3 mmol/L), effective osmolality > 320 mOsm/kg,
+///   pH > 7.30, HCO₃ > 15 mmol/L, minimal ketones
+///
+/// A hyperosmolar patient who also meets the ketoacidosis criteria is `Mixed`.
+/// Returns `None` if neither set of criteria is met. Effective osmolality is in mOsm/kg.
+pub fn hyperglycemic_crisis_type<G, B>(
+    glucose: Glucose<G>,
+    effective_osm: f64,
+    ph: f64,
+    hco3: Bicarbonate<B>,
+    ketones_present: bool,
+) -> Option<HyperglycemicCrisis>
+where
+    G: GlucoseUnit,
+    B: BicarbonateUnit,
+{
+    let glu_mmol = G::to_mmol_l(glucose.value());
+    let hco3_mmol = B::to_mmol_l(hco3.value());
+
+    let ketoacidotic = glu_mmol > 13.9 && ph < 7.3 && hco3_mmol < 18.0 && ketones_present;
+    let hyperosmolar = glu_mmol > 33.3 && effective_osm > 320.0;
+
+    match (hyperosmolar, ketoacidotic) {
+        (true, true) => Some(HyperglycemicCrisis::Mixed),
+        (false, true) => Some(HyperglycemicCrisis::Dka),
+        (true, false) if ph > 7.3 && hco3_mmol > 15.0 && !ketones_present => {
+            Some(HyperglycemicCrisis::Hhs)
+        }
+        _ => None,
+    }
+}
+
+/// Fractional excretion of sodium (FENa), as a percentage.
+///
+/// FENa = 100 × (urine Na × plasma Cr) / (plasma Na × urine Cr)
+///
+/// Urine and plasma values may be given in different units; each analyte is
+/// converted to SI before the ratio is taken.
+pub fn fena<UN, PN, UC, PC>(
+    urine_na: Sodium<UN>,
+    plasma_na: Sodium<PN>,
+    urine_cr: Creatinine<UC>,
+    plasma_cr: Creatinine<PC>,
+) -> Percentage
+where
+    UN: SodiumUnit,
+    PN: SodiumUnit,
+    UC: CreatinineUnit,
+    PC: CreatinineUnit,
+{
+    let urine_na = UN::to_mmol_l(urine_na.value());
+    let plasma_na = PN::to_mmol_l(plasma_na.value());
+    let urine_cr = UC::to_umol_l(urine_cr.value());
+    let plasma_cr = PC::to_umol_l(plasma_cr.value());
+
+    Percentage::from(100.0 * (urine_na * plasma_cr) / (plasma_na * urine_cr))
+}
+
 /// CKD-EPI 2021 calculation (creatinine only).
 ///
 /// The equation uses serum creatinine expressed in mg/dL.
 pub fn egfr_ckd_epi<U: CreatinineUnit>(
     scr: Creatinine<U>,
     age: Years,
-    sex: Gender,
+    sex: SexAtBirth,
 ) -> Gfr<GfrUnit> {
     // set the sex-determined constants (2021 race-free equation)
-    let (kappa, alpha, sex_mult) = if sex == Gender::Female {
+    let (kappa, alpha, sex_mult) = if sex == SexAtBirth::Female {
         (0.7, -0.241, 1.012)
     } else {
         (0.9, -0.302, 1.0)
@@ -95,6 +656,189 @@ pub fn egfr_ckd_epi<U: CreatinineUnit>(
     Gfr::from(egfr)
 }
 
+/// CKD-EPI cystatin C equation (2012, used unchanged by the 2021 guidance).
+///
+/// eGFR = 133 × min(Scys/0.8, 1)^-0.499 × max(Scys/0.8, 1)^-1.328 × 0.996^age,
+/// × 0.932 if female. Independent of muscle mass, so preferred when creatinine is
+/// unreliable (sarcopenia, amputation, bodybuilders).
+pub fn egfr_ckd_epi_cystatin(cys: CystatinC<MgL>, age: Years, sex: SexAtBirth) -> Gfr<GfrUnit> {
+    let sex_mult = if sex == SexAtBirth::Female {
+        0.932
+    } else {
+        1.0
+    };
+
+    let ratio = cys.value() / 0.8;
+    let egfr = 133.0
+        * ratio.min(1.0).powf(-0.499)
+        * ratio.max(1.0).powf(-1.328)
+        * 0.996_f64.powf(age.0)
+        * sex_mult;
+    Gfr::from(egfr)
+}
+
+/// CKD-EPI 2021 combined creatinine-cystatin C equation (race-free).
+///
+/// eGFR = 135 × min(Scr/κ, 1)^α × max(Scr/κ, 1)^-0.544 × min(Scys/0.8, 1)^-0.323 ×
+/// max(Scys/0.8, 1)^-0.778 × 0.9961^age, × 0.963 if female, with κ = 0.7 (female)
+/// or 0.9 (male) and α = -0.219 (female) or -0.144 (male). Creatinine is converted
+/// to mg/dL internally as in `egfr_ckd_epi`.
+pub fn egfr_ckd_epi_cr_cys<U: CreatinineUnit>(
+    scr: Creatinine<U>,
+    cys: CystatinC<MgL>,
+    age: Years,
+    sex: SexAtBirth,
+) -> Gfr<GfrUnit> {
+    let (kappa, alpha, sex_mult) = if sex == SexAtBirth::Female {
+        (0.7, -0.219, 0.963)
+    } else {
+        (0.9, -0.144, 1.0)
+    };
+
+    let scr_mg_dl = MgdL::from_umol_l(U::to_umol_l(scr.value()));
+    let scr_ratio = scr_mg_dl / kappa;
+    let cys_ratio = cys.value() / 0.8;
+
+    let egfr = 135.0
+        * scr_ratio.min(1.0).powf(alpha)
+        * scr_ratio.max(1.0).powf(-0.544)
+        * cys_ratio.min(1.0).powf(-0.323)
+        * cys_ratio.max(1.0).powf(-0.778)
+        * 0.9961_f64.powf(age.0)
+        * sex_mult;
+    Gfr::from(egfr)
+}
+
+/// MDRD study equation (4-variable, IDMS-traceable).
+///
+/// Like CKD-EPI, uses serum creatinine expressed in mg/dL. MDRD underestimates GFR
+/// at near-normal creatinine, so it reads lower than CKD-EPI for healthy patients.
+pub fn egfr_mdrd<U: CreatinineUnit>(
+    scr: Creatinine<U>,
+    age: Years,
+    sex: SexAtBirth,
+) -> Gfr<GfrUnit> {
+    let sex_mult = if sex == SexAtBirth::Female {
+        0.742
+    } else {
+        1.0
+    };
+
+    let scr_umol_l = U::to_umol_l(scr.value());
+    let scr_mg_dl = MgdL::from_umol_l(scr_umol_l);
+
+    let egfr = 175.0 * scr_mg_dl.powf(-1.154) * age.0.powf(-0.203) * sex_mult;
+    Gfr::from(egfr)
+}
+
+/// Difference between the CKD-EPI 2021 eGFR computed as female and as male for the
+/// same creatinine and age (female - male), in mL/min/1.73m².
+///
+/// A research helper showing the effect of the equation's sex-specific terms. The
+/// lower female κ outweighs the 1.012 multiplier, so for a given creatinine the
+/// female eGFR is lower and the delta is negative.
+pub fn egfr_sex_delta<U: CreatinineUnit>(scr: Creatinine<U>, age: Years) -> f64 {
+    let scr = scr.convert::<UmolL>();
+    let female = egfr_ckd_epi(scr, age, SexAtBirth::Female);
+    let male = egfr_ckd_epi(scr, age, SexAtBirth::Male);
+    female.value() - male.value()
+}
+
+/// Bedside Schwartz equation (2009) for pediatric eGFR.
+///
+/// eGFR = 0.413 × height (cm) / SCr (mg/dL), in mL/min/1.73m². Validated for
+/// children and adolescents aged 1 to 18 years with enzymatic creatinine; use
+/// CKD-EPI for adults, and neither for neonates and infants under a year.
+pub fn egfr_schwartz<H, C>(scr: Creatinine<C>, height: Height<H>) -> Gfr<GfrUnit>
+where
+    H: HeightUnit,
+    C: CreatinineUnit,
+{
+    let ht_cm = H::to_m(height.value()) * 100.0;
+    let scr_mg_dl = MgdL::from_umol_l(C::to_umol_l(scr.value()));
+
+    Gfr::from(0.413 * ht_cm / scr_mg_dl)
+}
+
+/// Pediatric eGFR de-indexed to the child's own body surface area.
+///
+/// Computes the bedside Schwartz eGFR (mL/min/1.73 m²), then multiplies by
+/// BSA / 1.73 using the Mosteller BSA, giving an absolute GFR in mL/min for drug
+/// dosing. Small children have a BSA well under 1.73 m², so the absolute value is
+/// lower than the indexed one.
+pub fn egfr_pediatric_bsa<H, W, C>(
+    height: Height<H>,
+    weight: Weight<W>,
+    scr: Creatinine<C>,
+) -> ClearanceRate<MlMin>
+where
+    H: HeightUnit,
+    W: WeightUnit,
+    C: CreatinineUnit,
+{
+    let ht_m = H::to_m(height.value()).height_in_m();
+    let schwartz = egfr_schwartz(scr, ht_m);
+    let bsa = bsa_mosteller(ht_m, weight);
+    ClearanceRate::from(schwartz.value() * bsa.value() / 1.73)
+}
+
+/// Cockcroft-Gault creatinine clearance.
+///
+/// CrCl = ((140 - age) × weight) / (72 × SCr), × 0.85 if female, with weight in kg
+/// and SCr in mg/dL. Weight must already be in kg; convert `Weight<Lb>` with
+/// `Weight::<Kg>::from` first. Many drug-dosing references are still based on this
+/// equation rather than eGFR, and the result is in mL/min (not indexed to BSA).
+pub fn crcl_cockcroft_gault<U: CreatinineUnit>(
+    scr: Creatinine<U>,
+    age: Years,
+    weight: Weight<Kg>,
+    sex: SexAtBirth,
+) -> ClearanceRate<MlMin> {
+    let scr_mg_dl = MgdL::from_umol_l(U::to_umol_l(scr.value()));
+    let sex_mult = if sex == SexAtBirth::Female { 0.85 } else { 1.0 };
+
+    let crcl = ((140.0 - age.0) * weight.value()) / (72.0 * scr_mg_dl) * sex_mult;
+    ClearanceRate::from(crcl)
+}
+
+/// Creatinine clearance by the Salazar-Corcoran equation, for obese patients.
+///
+/// Cockcroft-Gault with total body weight overestimates clearance in obesity, and
+/// with ideal body weight underestimates it. Salazar-Corcoran was derived in obese
+/// patients and accounts for fat-free mass via height, so prefer it over
+/// Cockcroft-Gault when actual weight is well above ideal (e.g. BMI ≥ 30).
+///
+/// Male:   (137 - age) × (0.285 × Wt + 12.1 × Ht²) / (51 × SCr)
+/// Female: (146 - age) × (0.287 × Wt + 9.74 × Ht²) / (60 × SCr)
+///
+/// with weight in kg, height in m and SCr in mg/dL.
+pub fn crcl_salazar_corcoran<H, W, C>(
+    height: Height<H>,
+    weight: Weight<W>,
+    age: Years,
+    sex: SexAtBirth,
+    scr: Creatinine<C>,
+) -> ClearanceRate<MlMin>
+where
+    H: HeightUnit,
+    W: WeightUnit,
+    C: CreatinineUnit,
+{
+    let ht_m = H::to_m(height.value());
+    let wt_kg = W::to_kg(weight.value());
+    let scr_mg_dl = MgdL::from_umol_l(C::to_umol_l(scr.value()));
+
+    let crcl = match sex {
+        SexAtBirth::Male => {
+            (137.0 - age.0) * (0.285 * wt_kg + 12.1 * ht_m.powi(2)) / (51.0 * scr_mg_dl)
+        }
+        SexAtBirth::Female => {
+            (146.0 - age.0) * (0.287 * wt_kg + 9.74 * ht_m.powi(2)) / (60.0 * scr_mg_dl)
+        }
+    };
+    ClearanceRate::from(crcl)
+}
+
 /// BMI calculation
 pub fn bmi<H, W>(height: Height<H>, weight: Weight<W>) -> Bmi<KgM2>
 where
@@ -107,133 +851,822 @@ where
     (wt / ht.powi(2)).to_bmi()
 }
 
-/// The result of a MELD score calculation.
-pub type MeldScore = u8;
+/// Ideal body weight by the Devine formula.
+///
+/// IBW = 50 kg (male) or 45.5 kg (female) + 2.3 kg per inch of height over 5 feet.
+///
+/// The formula was never meant for patients under 5 feet, so the "inches over 60"
+/// term is floored at zero: anyone 5 feet or shorter gets the base weight.
+pub fn ideal_body_weight<H: HeightUnit>(height: Height<H>, sex: SexAtBirth) -> Weight<Kg> {
+    let height_in = H::to_m(height.value()) * M_TO_FT * 12.0;
+    let inches_over_5ft = (height_in - 60.0).max(0.0);
+    let base_kg = match sex {
+        SexAtBirth::Male => 50.0,
+        SexAtBirth::Female => 45.5,
+    };
+
+    (base_kg + 2.3 * inches_over_5ft).weight_kg()
+}
+
+/// Adjusted body weight, for dosing in obesity: IBW + 0.4 × (actual - IBW).
+pub fn adjusted_body_weight(actual: Weight<Kg>, ibw: Weight<Kg>) -> Weight<Kg> {
+    (ibw.value() + 0.4 * (actual.value() - ibw.value())).weight_kg()
+}
+
+/// Screen for obesity hypoventilation syndrome.
+///
+/// Returns true when BMI is ≥ 30 kg/m² and serum bicarbonate is ≥ 27 mmol/L, the
+/// latter suggesting metabolic compensation for chronic CO₂ retention. A positive
+/// screen warrants an arterial blood gas; it is not a diagnosis.
+pub fn obesity_hypoventilation_risk<B: BicarbonateUnit>(
+    bmi: Bmi<KgM2>,
+    hco3: Bicarbonate<B>,
+) -> bool {
+    let hco3_mmol = B::to_mmol_l(hco3.value());
+    bmi.value() >= 30.0 && hco3_mmol >= 27.0
+}
+
+/// Body surface area by the Mosteller formula: √(height cm × weight kg / 3600).
+pub fn bsa_mosteller<H, W>(height: Height<H>, weight: Weight<W>) -> BodySurfaceArea<M2>
+where
+    H: HeightUnit,
+    W: WeightUnit,
+{
+    let ht_cm = H::to_m(height.value()) * 100.0;
+    let wt_kg = W::to_kg(weight.value());
+
+    (ht_cm * wt_kg / 3600.0).sqrt().to_bsa()
+}
+
+/// Body surface area by the DuBois formula: 0.007184 × height cm^0.725 × weight kg^0.425.
+pub fn bsa_dubois<H, W>(height: Height<H>, weight: Weight<W>) -> BodySurfaceArea<M2>
+where
+    H: HeightUnit,
+    W: WeightUnit,
+{
+    let ht_cm = H::to_m(height.value()) * 100.0;
+    let wt_kg = W::to_kg(weight.value());
+
+    (0.007184 * ht_cm.powf(0.725) * wt_kg.powf(0.425)).to_bsa()
+}
+
+/// The result of a MELD score calculation.
+pub type MeldScore = u8;
+
+/// The time of last dialysis used in calculating a MELD score.
+pub type LastDialysis = Option<u8>;
+
+/// FIB-4 index for liver fibrosis.
+///
+/// FIB-4 = (age × AST) / (platelets × √ALT), with AST and ALT in U/L and platelets in
+/// ×10⁹/L. See `classify_fib4` for interpretation.
+pub fn fib4<A, L>(age: Years, ast: Ast<A>, alt: Alt<L>, platelets: Platelets<PerNanoLiter>) -> f64
+where
+    A: EnzymeUnit,
+    L: EnzymeUnit,
+{
+    let ast_u_l = A::to_u_l(ast.value());
+    let alt_u_l = L::to_u_l(alt.value());
+
+    (age.0 * ast_u_l) / (platelets.value() * alt_u_l.sqrt())
+}
+
+/// Likelihood of advanced fibrosis (F3-F4) by FIB-4.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fib4Risk {
+    /// FIB-4 < 1.3: advanced fibrosis ruled out
+    Low,
+    /// FIB-4 1.3-2.67: further testing (e.g. elastography) needed
+    Indeterminate,
+    /// FIB-4 > 2.67: advanced fibrosis likely
+    High,
+}
+
+/// Classify a FIB-4 index using the 1.3 / 2.67 cutoffs.
+pub fn classify_fib4(fib4: f64) -> Fib4Risk {
+    match fib4 {
+        val if val < 1.3 => Fib4Risk::Low,
+        val if val <= 2.67 => Fib4Risk::Indeterminate,
+        _ => Fib4Risk::High,
+    }
+}
+
+/// Hematocrit (%) taken as normal when correcting a reticulocyte count.
+pub const RPI_NORMAL_HCT_PCT: f64 = 45.0;
+
+/// Reticulocyte production index, for judging the marrow's response to anemia.
+///
+/// The reticulocyte percentage is first corrected for the degree of anemia
+/// (× Hct / 45), then divided by a maturation factor. Anemic patients release
+/// reticulocytes early, and those circulate longer before maturing:
+///
+/// | Hct (%) | maturation (days) |
+/// |---------|-------------------|
+/// | ≥ 36    | 1.0               |
+/// | 26–35   | 1.5               |
+/// | 16–25   | 2.0               |
+/// | < 16    | 2.5               |
+///
+/// An RPI below 2 in an anemic patient suggests inadequate marrow response
+/// (hypoproliferative anemia); above 2-3 suggests blood loss or hemolysis.
+pub fn reticulocyte_production_index(retic_pct: f64, hct: Hematocrit) -> f64 {
+    let maturation_days = match hct.value() {
+        h if h >= 36.0 => 1.0,
+        h if h >= 26.0 => 1.5,
+        h if h >= 16.0 => 2.0,
+        _ => 2.5,
+    };
+    retic_pct * (hct.value() / RPI_NORMAL_HCT_PCT) / maturation_days
+}
+
+/// Model for End-Stage Liver Disease (MELD) Score
+///
+/// MELD = 3.78 * ln(serum_bili_in_mgdL) + 11.2 * ln(INR) + 9.57 * ln(serum_creat_mgdL) + 6.43
+pub fn meld<B, I, C>(
+    bili: Bilirubin<B>,
+    inr: Inr<I>,
+    scr: Creatinine<C>,
+    last_hd: LastDialysis,
+) -> MeldScore
+where
+    B: BilirubinUnit,
+    I: Unit,
+    C: CreatinineUnit,
+{
+    let bili_mgdl = B::to_umoll(bili.value()) * SBILI_UMOLL_TO_MGDL;
+    let scr_mgdl = C::to_umol_l(scr.value()) * SCR_UMOLL_TO_MGDL;
+
+    // UNOS modifications:
+    // 1) floors set at 1.0 for these values
+    // 2) BUT use Scr = 4.0 mg/dL if dialyzed within 7 days
+    let bili_mgdl = bili_mgdl.max(1.0);
+    let scr_mgdl = if let Some(days) = last_hd {
+        if days <= 7 {
+            4.0
+        } else {
+            scr_mgdl.max(1.0)
+        }
+    } else {
+        scr_mgdl.max(1.0)
+    };
+
+    let bili_term = 3.78 * bili_mgdl.ln();
+    let inr_term = 11.2 * inr.value().ln();
+    let scr_term = 9.57 * scr_mgdl.ln();
+
+    let raw_score = bili_term + inr_term + scr_term + 6.43;
+    raw_score.round() as u8
+}
+
+/// MELD-Na score (OPTN/UNOS, 2016)
+///
+/// MELD(i) is the original MELD (see `meld::meld_original`) rounded to the tenth.
+/// If MELD(i) > 11 the sodium correction is applied:
+///
+/// MELD-Na = MELD(i) + 1.32 × (137 - Na) - 0.033 × MELD(i) × (137 - Na)
+///
+/// Bilirubin, creatinine and INR are floored at 1.0; creatinine is capped at 4.0 (or
+/// set to 4.0 when `on_dialysis`, i.e. ≥ 2 dialyses or 24 h of CVVHD in the past
+/// week); sodium is bounded to 125-137 mmol/L. The final score is clamped to 6-40.
+pub fn meld_na<B, C, I, N>(
+    bili: Bilirubin<B>,
+    scr: Creatinine<C>,
+    inr: Inr<I>,
+    sodium: Sodium<N>,
+    on_dialysis: bool,
+) -> MeldScore
+where
+    B: BilirubinUnit,
+    C: CreatinineUnit,
+    I: Unit,
+    N: SodiumUnit,
+{
+    let na_mmol = N::to_mmol_l(sodium.value()).clamp(125.0, 137.0);
+    let meld_i = meld::meld_i(bili, scr, inr, on_dialysis);
+
+    let score = if meld_i > 11.0 {
+        meld_i + 1.32 * (137.0 - na_mmol) - 0.033 * meld_i * (137.0 - na_mmol)
+    } else {
+        meld_i
+    };
+    score.round().clamp(6.0, 40.0) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lab::blood::{
+        glucose::SerumGlucoseExt,
+        sodium::{SerumSodiumExt, Sodium},
+    };
+    use crate::units::{Meter, MgdL, MmolL};
+
+    fn approx_eq(lhs: f64, rhs: f64) {
+        assert!((lhs - rhs).abs() < 1e-9, "{} !~= {}", lhs, rhs);
+    }
+
+    #[test]
+    fn correct_na_uses_katz_below_threshold() {
+        let sodium = 130.0.na_serum_meq();
+        let glucose = 10.0.glu_serum_mmol_l();
+
+        let corrected = correct_na_for_glucose(sodium, glucose);
+        let expected = 130.0 + 0.29 * (10.0 - 5.6);
+
+        approx_eq(corrected.value(), expected);
+    }
+
+    #[test]
+    fn correct_na_uses_hillier_above_threshold() {
+        let sodium = 132.0.na_serum_meq();
+        let glucose = 30.0.glu_serum_mmol_l();
+
+        let corrected = correct_na_for_glucose(sodium, glucose);
+        let expected = 132.0 + 0.43 * (30.0 - 5.6);
+
+        approx_eq(corrected.value(), expected);
+    }
+
+    #[test]
+    fn correct_na_preserves_original_units() {
+        // Work with mmol/L sodium and mg/dL glucose to exercise conversions.
+        let sodium: Sodium<MmolL> = 138.0.na_serum_mmol();
+        let glucose_mgdl = 500.0.glu_serum_mg_dl();
+
+        let corrected = correct_na_for_glucose(sodium, glucose_mgdl);
+
+        // Convert expected result to mmol/L using mg/dL glucose converted to mmol/L.
+        let glucose_mmol = MgdL::to_mmol_l(glucose_mgdl.value());
+        let expected = 138.0 + 0.43 * (glucose_mmol - 5.6);
+
+        approx_eq(corrected.value(), expected);
+    }
+
+    #[test]
+    fn correct_na_threshold_boundary() {
+        // Test just above 22.2 mmol/L threshold (should use Hillier)
+        let sodium = 135.0.na_serum_meq();
+        let glucose = 22.3.glu_serum_mmol_l();
+
+        let corrected = correct_na_for_glucose(sodium, glucose);
+        let expected = 135.0 + 0.43 * (22.3 - 5.6);
+
+        approx_eq(corrected.value(), expected);
+    }
+
+    #[test]
+    fn correct_na_with_mixed_units() {
+        // Test with mg/dL sodium doesn't exist, but test mmol sodium with mmol glucose
+        let sodium = 140.0.na_serum_mmol();
+        let glucose = 15.0.glu_serum_mmol_l();
+
+        let corrected = correct_na_for_glucose(sodium, glucose);
+        let expected = 140.0 + 0.29 * (15.0 - 5.6);
+
+        approx_eq(corrected.value(), expected);
+    }
+
+    #[test]
+    fn correct_na_normal_glucose() {
+        // With normal glucose, correction should be minimal
+        let sodium = 138.0.na_serum_meq();
+        let glucose = 5.6.glu_serum_mmol_l(); // baseline glucose
+
+        let corrected = correct_na_for_glucose(sodium, glucose);
+        approx_eq(corrected.value(), 138.0);
+    }
+
+    // Tests for HbA1c / eAG
+
+    #[test]
+    fn eag_from_a1c_7_pct_is_about_154_mg_dl() {
+        use crate::lab::blood::hba1c::Hba1cExt;
+
+        let eag: Glucose<MgdL> = eag_from_a1c(7.0.a1c_pct());
+        approx_eq(eag.value(), 154.2);
+
+        let eag_mmol: Glucose<MmolL> = eag_from_a1c(7.0.a1c_pct());
+        approx_eq(eag_mmol.value(), 154.2 / 18.0);
+    }
+
+    #[test]
+    fn a1c_from_eag_inverts_eag_from_a1c() {
+        use crate::lab::blood::{glucose::SerumGlucoseExt, hba1c::Hba1cExt};
+
+        approx_eq(a1c_from_eag(154.2.glu_serum_mg_dl()).value(), 7.0);
+        approx_eq(a1c_from_eag((154.2 / 18.0).glu_serum_mmol_l()).value(), 7.0);
+        let eag: Glucose<MmolL> = eag_from_a1c(9.3.a1c_pct());
+        approx_eq(a1c_from_eag(eag).value(), 9.3);
+    }
+
+    // Tests for corrected calcium
+
+    #[test]
+    fn corrected_calcium_raises_for_low_albumin() {
+        use crate::lab::blood::{albumin::SerumAlbuminExt, calcium::SerumCalciumExt};
+
+        let corrected = corrected_calcium(7.6.ca_serum_mg_dl(), 2.0.alb_serum_g_dl());
+        approx_eq(corrected.value(), 7.6 + 0.8 * 2.0);
+    }
+
+    #[test]
+    fn corrected_calcium_preserves_si_units() {
+        use crate::lab::blood::{albumin::SerumAlbuminExt, calcium::SerumCalciumExt};
+
+        // 2.0 mmol/L calcium with 25 g/L albumin
+        let corrected = corrected_calcium(2.0.ca_serum_mmol_l(), 25.0.alb_serum_g_l());
+        let expected_mg_dl = 2.0 * 4.008 + 0.8 * (4.0 - 2.5);
+        approx_eq(corrected.value(), expected_mg_dl / 4.008);
+    }
+
+    #[test]
+    fn estimate_total_calcium_with_normal_albumin() {
+        use crate::lab::blood::{albumin::SerumAlbuminExt, calcium::SerumCalciumExt};
+
+        // ionized 1.2 mmol/L with albumin 4.0 g/dL: total ≈ 2.4 mmol/L
+        let total = estimate_total_calcium(1.2.ca_serum_mmol_l(), 4.0.alb_serum_g_dl());
+        approx_eq(total.value(), 2.4);
+    }
+
+    #[test]
+    fn estimate_total_calcium_is_lower_with_hypoalbuminemia() {
+        use crate::lab::blood::{albumin::SerumAlbuminExt, calcium::SerumCalciumExt};
+
+        // ionized 4.8 mg/dL with albumin 20 g/L: 9.6 - 0.8 × 2.0 = 8.0 mg/dL
+        let total = estimate_total_calcium(4.8.ca_serum_mg_dl(), 20.0.alb_serum_g_l());
+        approx_eq(total.value(), 8.0);
+    }
+
+    // Tests for anion gap
+
+    #[test]
+    fn anion_gap_normal() {
+        use crate::lab::blood::{
+            bicarbonate::SerumBicarbonateExt, chloride::SerumChlorideExt, sodium::SerumSodiumExt,
+        };
+        use crate::lab::{NumericRanged, ResultRange};
+
+        let gap = anion_gap(
+            140.0.na_serum_meq(),
+            104.0.cl_serum_meq(),
+            26.0.hco3_serum_mmol(),
+        );
+        approx_eq(gap.value(), 10.0);
+        assert_eq!(gap.range(), ResultRange::Normal);
+    }
+
+    #[test]
+    fn anion_gap_elevated_in_ketoacidosis() {
+        use crate::lab::blood::{
+            bicarbonate::SerumBicarbonateExt, chloride::SerumChlorideExt,
+            potassium::SerumPotassiumExt, sodium::SerumSodiumExt,
+        };
+        use crate::lab::{NumericRanged, ResultRange};
+
+        let na = 134.0.na_serum_mmol();
+        let cl = 98.0.cl_serum_mmol();
+        let hco3 = 10.0.hco3_serum_meq();
+
+        let gap = anion_gap(na, cl, hco3);
+        approx_eq(gap.value(), 26.0);
+        assert_eq!(gap.range(), ResultRange::CriticalHigh);
+
+        let gap_k = anion_gap_with_k(na, 5.5.k_serum_meq(), cl, hco3);
+        approx_eq(gap_k.value(), 31.5);
+    }
+
+    // Tests for urine anion gap
+
+    #[test]
+    fn urine_anion_gap_negative_in_diarrhea() {
+        use crate::lab::blood::{
+            chloride::SerumChlorideExt, potassium::SerumPotassiumExt, sodium::SerumSodiumExt,
+        };
+
+        let uag = urine_anion_gap(
+            50.0.na_serum_mmol(),
+            20.0.k_serum_meq(),
+            110.0.cl_serum_meq(),
+        );
+        approx_eq(uag, -40.0);
+    }
+
+    #[test]
+    fn urine_anion_gap_positive_in_rta() {
+        use crate::lab::blood::{
+            chloride::SerumChlorideExt, potassium::SerumPotassiumExt, sodium::SerumSodiumExt,
+        };
+
+        let uag = urine_anion_gap(
+            60.0.na_serum_meq(),
+            30.0.k_serum_mmol(),
+            70.0.cl_serum_mmol(),
+        );
+        approx_eq(uag, 20.0);
+    }
+
+    // Tests for osmolality
+
+    #[test]
+    fn calculated_osmolality_matches_conventional_formula() {
+        use crate::lab::blood::{bun::BunExt, glucose::SerumGlucoseExt, sodium::SerumSodiumExt};
+
+        let osm = calculated_osmolality(
+            140.0.na_serum_meq(),
+            90.0.glu_serum_mg_dl(),
+            14.0.bun_mg_dl(),
+        );
+        // 2(140) + 90/18 + 14/2.8 = 290
+        assert!((osm.value() - 290.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn calculated_osmolality_is_unit_independent() {
+        use crate::lab::blood::{bun::BunExt, glucose::SerumGlucoseExt, sodium::SerumSodiumExt};
+
+        let conventional = calculated_osmolality(
+            140.0.na_serum_meq(),
+            90.0.glu_serum_mg_dl(),
+            14.0.bun_mg_dl(),
+        );
+        let si = calculated_osmolality(
+            140.0.na_serum_mmol(),
+            MgdL::to_mmol_l(90.0).glu_serum_mmol_l(),
+            (14.0 * 0.357).urea_mmol_l(),
+        );
+        approx_eq(conventional.value(), si.value());
+    }
+
+    #[test]
+    fn toxic_alcohol_raises_osmolar_gap() {
+        use crate::lab::blood::{
+            bun::BunExt, glucose::SerumGlucoseExt, osmolality::OsmolalityExt,
+            sodium::SerumSodiumExt,
+        };
+
+        // ethylene glycol ingestion: measured osm far above calculated (~290)
+        let gap = osmolar_gap(
+            330.0.osm_serum(),
+            140.0.na_serum_meq(),
+            90.0.glu_serum_mg_dl(),
+            14.0.bun_mg_dl(),
+        );
+        assert!(gap > 10.0);
+        assert!((gap - 40.0).abs() < 0.1);
+    }
+
+    // Tests for calcium_phosphate_product
+
+    #[test]
+    fn ca_phos_product_normal() {
+        use crate::lab::blood::{calcium::SerumCalciumExt, phosphate::SerumPhosphateExt};
+
+        let product = calcium_phosphate_product(9.5.ca_serum_mg_dl(), 4.0.phos_serum_mg_dl());
+        approx_eq(product.value(), 38.0);
+        assert_eq!(product.range(), CaPhosRisk::Acceptable);
+    }
+
+    #[test]
+    fn ca_phos_product_high_with_si_inputs() {
+        use crate::lab::blood::{calcium::SerumCalciumExt, phosphate::SerumPhosphateExt};
+
+        // 2.5 mmol/L Ca ≈ 10.0 mg/dL; 2.6 mmol/L phosphate ≈ 8.0 mg/dL
+        let product = calcium_phosphate_product(2.5.ca_serum_mmol_l(), 2.6.phos_serum_mmol_l());
+        let expected =
+            2.5 * crate::constants::CA_MMOLL_TO_MGDL * 2.6 * crate::constants::PHOS_MMOLL_TO_MGDL;
+        approx_eq(product.value(), expected);
+        assert!(product.value() > 80.0);
+        assert_eq!(product.range(), CaPhosRisk::HighRisk);
+    }
+
+    // Tests for free_water_deficit
 
-/// The time of last dialysis used in calculating a MELD score.
-pub type LastDialysis = Option<u8>;
+    #[test]
+    fn free_water_deficit_adult_male() {
+        use crate::lab::{blood::sodium::SerumSodiumExt, vitals::WeightExt};
+
+        let deficit = free_water_deficit(
+            70.0.weight_kg(),
+            160.0.na_serum_meq(),
+            SexAtBirth::Male,
+            AgeGroup::Adult,
+        );
+        // 70 × 0.6 × (160/140 - 1) = 6.0 L
+        approx_eq(deficit, 6.0);
+    }
 
-/// Model for End-Stage Liver Disease (MELD) Score
-///
-/// MELD = 3.78 * ln(serum_bili_in_mgdL) + 11.2 * ln(INR) + 9.57 * ln(serum_creat_mgdL) + 6.43
-pub fn meld<B, I, C>(
-    bili: Bilirubin<B>,
-    inr: Inr<I>,
-    scr: Creatinine<C>,
-    last_hd: LastDialysis,
-) -> MeldScore
-where
-    B: BilirubinUnit,
-    I: Unit,
-    C: CreatinineUnit,
-{
-    let bili_mgdl = B::to_umoll(bili.value()) * SBILI_UMOLL_TO_MGDL;
-    let scr_mgdl = C::to_umol_l(scr.value()) * SCR_UMOLL_TO_MGDL;
+    #[test]
+    fn free_water_deficit_uses_lower_tbw_for_elderly_female() {
+        use crate::lab::{blood::sodium::SerumSodiumExt, vitals::WeightExt};
+
+        let deficit = free_water_deficit(
+            70.0.weight_kg(),
+            160.0.na_serum_mmol(),
+            SexAtBirth::Female,
+            AgeGroup::Geriatric,
+        );
+        approx_eq(deficit, 70.0 * 0.45 * (160.0 / 140.0 - 1.0));
+    }
 
-    // UNOS modifications:
-    // 1) floors set at 1.0 for these values
-    // 2) BUT use Scr = 4.0 mg/dL if dialyzed within 7 days
-    let bili_mgdl = bili_mgdl.max(1.0);
-    let scr_mgdl = if let Some(days) = last_hd {
-        if days <= 7 {
-            4.0
-        } else {
-            scr_mgdl.max(1.0)
+    #[test]
+    fn free_water_deficit_is_zero_without_hypernatremia() {
+        use crate::lab::{blood::sodium::SerumSodiumExt, vitals::WeightExt};
+
+        for na in [130.0, 140.0] {
+            let deficit = free_water_deficit(
+                70.0.weight_kg(),
+                na.na_serum_meq(),
+                SexAtBirth::Male,
+                AgeGroup::Adult,
+            );
+            assert_eq!(deficit, 0.0);
         }
-    } else {
-        scr_mgdl.max(1.0)
-    };
+    }
 
-    let bili_term = 3.78 * bili_mgdl.ln();
-    let inr_term = 11.2 * inr.value().ln();
-    let scr_term = 9.57 * scr_mgdl.ln();
+    // Tests for sodium correction
 
-    let raw_score = bili_term + inr_term + scr_term + 6.43;
-    raw_score.round() as u8
-}
+    #[test]
+    fn sodium_deficit_uses_tbw() {
+        use crate::lab::vitals::WeightExt;
+
+        let deficit = sodium_deficit(
+            118.0.na_serum_meq(),
+            124.0.na_serum_mmol(),
+            60.0.weight_kg(),
+            SexAtBirth::Female,
+            AgeGroup::Adult,
+        );
+        // 60 × 0.5 × 6 = 180 mmol
+        approx_eq(deficit, 180.0);
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::lab::blood::{
-        glucose::SerumGlucoseExt,
-        sodium::{SerumSodiumExt, Sodium},
-    };
-    use crate::units::{MgdL, MmolL};
+    #[test]
+    fn na_correction_rate_is_per_hour() {
+        let rate = na_correction_rate(120.0.na_serum_meq(), 126.0.na_serum_mmol(), 12.0);
+        approx_eq(rate, 0.5);
+    }
 
-    fn approx_eq(lhs: f64, rhs: f64) {
-        assert!((lhs - rhs).abs() < 1e-9, "{} !~= {}", lhs, rhs);
+    #[test]
+    fn na_correction_plan_never_exceeds_ods_ceiling() {
+        use crate::lab::vitals::WeightExt;
+
+        // severe hyponatremia with an aggressive requested rise
+        let plan = plan_na_correction(
+            110.0.na_serum_meq(),
+            15.0,
+            70.0.weight_kg(),
+            SexAtBirth::Male,
+        );
+        assert!(plan.rise_mmol_l() <= NA_CORRECTION_MAX_24H_MMOL_L);
+        assert!(plan.rate_mmol_l_per_h() * 24.0 <= NA_CORRECTION_MAX_24H_MMOL_L + 1e-9);
+        // 70 × 0.6 × 8 = 336 mmol
+        approx_eq(plan.sodium_mmol(), 336.0);
+        assert_eq!(plan.recheck_hours(), &[4.0, 8.0, 12.0, 16.0, 20.0, 24.0]);
     }
 
     #[test]
-    fn correct_na_uses_katz_below_threshold() {
-        let sodium = 130.0.na_serum_meq();
-        let glucose = 10.0.glu_serum_mmol_l();
+    fn na_correction_plan_keeps_modest_rise_and_rechecks_less_often() {
+        use crate::lab::vitals::WeightExt;
+
+        let plan = plan_na_correction(
+            126.0.na_serum_mmol(),
+            5.0,
+            154.0.weight_lb(),
+            SexAtBirth::Female,
+        );
+        approx_eq(plan.rise_mmol_l(), 5.0);
+        approx_eq(
+            plan.sodium_mmol(),
+            154.0 * crate::constants::LB_TO_KG * 0.5 * 5.0,
+        );
+        assert_eq!(plan.recheck_hours(), &[6.0, 12.0, 18.0, 24.0]);
+    }
 
-        let corrected = correct_na_for_glucose(sodium, glucose);
-        let expected = 130.0 + 0.29 * (10.0 - 5.6);
+    // Tests for ideal / adjusted body weight
 
-        approx_eq(corrected.value(), expected);
+    #[test]
+    fn ideal_body_weight_matches_devine_table() {
+        use crate::lab::vitals::HeightExt;
+
+        // 5'10" male: 50 + 2.3 × 10 = 73.0 kg
+        let male = ideal_body_weight(Height::<Meter>::from_ft_and_in(5, 10.0), SexAtBirth::Male);
+        assert!((male.value() - 73.0).abs() < 1e-6);
+        // 5'4" female: 45.5 + 2.3 × 4 = 54.7 kg
+        let female = ideal_body_weight(Height::<Meter>::from_ft_and_in(5, 4.0), SexAtBirth::Female);
+        assert!((female.value() - 54.7).abs() < 1e-6);
+        // 6 ft given in feet: 50 + 2.3 × 12 = 77.6 kg
+        let tall = ideal_body_weight(6.0.height_in_ft(), SexAtBirth::Male);
+        assert!((tall.value() - 77.6).abs() < 1e-6);
     }
 
     #[test]
-    fn correct_na_uses_hillier_above_threshold() {
-        let sodium = 132.0.na_serum_meq();
-        let glucose = 30.0.glu_serum_mmol_l();
+    fn ideal_body_weight_under_five_feet_is_floored() {
+        use crate::lab::vitals::HeightExt;
 
-        let corrected = correct_na_for_glucose(sodium, glucose);
-        let expected = 132.0 + 0.43 * (30.0 - 5.6);
+        let short = ideal_body_weight(4.5.height_in_ft(), SexAtBirth::Female);
+        approx_eq(short.value(), 45.5);
+    }
 
-        approx_eq(corrected.value(), expected);
+    #[test]
+    fn adjusted_body_weight_is_40_pct_of_excess() {
+        let abw = adjusted_body_weight(120.0.weight_kg(), 70.0.weight_kg());
+        approx_eq(abw.value(), 90.0);
     }
 
+    // Tests for Winters' formula
+
     #[test]
-    fn correct_na_preserves_original_units() {
-        // Work with mmol/L sodium and mg/dL glucose to exercise conversions.
-        let sodium: Sodium<MmolL> = 138.0.na_serum_mmol();
-        let glucose_mgdl = 500.0.glu_serum_mg_dl();
+    fn winters_expected_pco2_for_hco3_of_12() {
+        use crate::lab::blood::bicarbonate::SerumBicarbonateExt;
 
-        let corrected = correct_na_for_glucose(sodium, glucose_mgdl);
+        let (low, high) = winters_expected_pco2(12.0.hco3_serum_meq());
+        approx_eq(low, 24.0);
+        approx_eq(high, 28.0);
+    }
 
-        // Convert expected result to mmol/L using mg/dL glucose converted to mmol/L.
-        let glucose_mmol = MgdL::to_mmol_l(glucose_mgdl.value());
-        let expected = 138.0 + 0.43 * (glucose_mmol - 5.6);
+    // Tests for bicarbonate deficit
 
-        approx_eq(corrected.value(), expected);
+    #[test]
+    fn bicarbonate_deficit_for_70kg_from_10_to_18() {
+        use crate::lab::blood::bicarbonate::SerumBicarbonateExt;
+
+        let deficit = bicarbonate_deficit(
+            10.0.hco3_serum_meq(),
+            18.0.hco3_serum_meq(),
+            70.0.weight_kg(),
+            false,
+        );
+        approx_eq(deficit, 280.0);
+
+        let severe = bicarbonate_deficit(
+            10.0.hco3_serum_mmol(),
+            18.0.hco3_serum_meq(),
+            70.0.weight_kg(),
+            true,
+        );
+        approx_eq(severe, 224.0);
     }
 
     #[test]
-    fn correct_na_threshold_boundary() {
-        // Test just above 22.2 mmol/L threshold (should use Hillier)
-        let sodium = 135.0.na_serum_meq();
-        let glucose = 22.3.glu_serum_mmol_l();
+    fn bicarbonate_deficit_converts_weight_and_clamps_at_zero() {
+        use crate::lab::blood::bicarbonate::SerumBicarbonateExt;
+
+        let kg = bicarbonate_deficit(
+            10.0.hco3_serum_meq(),
+            18.0.hco3_serum_meq(),
+            70.0.weight_kg(),
+            false,
+        );
+        let lb = bicarbonate_deficit(
+            10.0.hco3_serum_meq(),
+            18.0.hco3_serum_meq(),
+            Weight::<crate::units::Lb>::from(70.0.weight_kg()),
+            false,
+        );
+        approx_eq(kg, lb);
+
+        let none = bicarbonate_deficit(
+            24.0.hco3_serum_meq(),
+            18.0.hco3_serum_meq(),
+            70.0.weight_kg(),
+            false,
+        );
+        approx_eq(none, 0.0);
+    }
 
-        let corrected = correct_na_for_glucose(sodium, glucose);
-        let expected = 135.0 + 0.43 * (22.3 - 5.6);
+    // Tests for A-a gradient
 
-        approx_eq(corrected.value(), expected);
+    #[test]
+    fn a_a_gradient_young_patient_on_room_air_is_normal() {
+        // PAO₂ = 0.21 × 713 - 40 / 0.8 = 99.73; gradient = 99.73 - 92
+        let gradient = a_a_gradient(0.21, 40.0, 92.0, None);
+        approx_eq(gradient, 0.21 * 713.0 - 50.0 - 92.0);
+        assert!(gradient < 15.0);
+        assert!(gradient < expected_a_a_gradient(Years(24.0)));
     }
 
     #[test]
-    fn correct_na_with_mixed_units() {
-        // Test with mg/dL sodium doesn't exist, but test mmol sodium with mmol glucose
-        let sodium = 140.0.na_serum_mmol();
-        let glucose = 15.0.glu_serum_mmol_l();
+    fn a_a_gradient_uses_given_atmospheric_pressure() {
+        // Denver, ~630 mmHg
+        let gradient = a_a_gradient(0.21, 35.0, 70.0, Some(630.0));
+        approx_eq(gradient, 0.21 * 583.0 - 35.0 / 0.8 - 70.0);
+    }
 
-        let corrected = correct_na_for_glucose(sodium, glucose);
-        let expected = 140.0 + 0.29 * (15.0 - 5.6);
+    #[test]
+    fn expected_a_a_gradient_rises_with_age() {
+        approx_eq(expected_a_a_gradient(Years(20.0)), 9.0);
+        approx_eq(expected_a_a_gradient(Years(80.0)), 24.0);
+    }
 
-        approx_eq(corrected.value(), expected);
+    // Tests for QTc
+
+    #[test]
+    fn qtc_methods_agree_at_hr_60() {
+        let rr = rr_interval_from_hr(60.0);
+        for method in [
+            QtcMethod::Bazett,
+            QtcMethod::Fridericia,
+            QtcMethod::Framingham,
+            QtcMethod::Hodges,
+        ] {
+            approx_eq(qtc(400.0, rr, method), 400.0);
+        }
     }
 
     #[test]
-    fn correct_na_normal_glucose() {
-        // With normal glucose, correction should be minimal
-        let sodium = 138.0.na_serum_meq();
-        let glucose = 5.6.glu_serum_mmol_l(); // baseline glucose
+    fn qtc_methods_diverge_at_hr_120() {
+        let rr = rr_interval_from_hr(120.0);
+        approx_eq(rr, 0.5);
+
+        let bazett = qtc(300.0, rr, QtcMethod::Bazett);
+        let fridericia = qtc(300.0, rr, QtcMethod::Fridericia);
+        let framingham = qtc(300.0, rr, QtcMethod::Framingham);
+        let hodges = qtc(300.0, rr, QtcMethod::Hodges);
+
+        approx_eq(bazett, 300.0 / 0.5_f64.sqrt()); // ~424
+        approx_eq(fridericia, 300.0 / 0.5_f64.cbrt()); // ~378
+        approx_eq(framingham, 377.0);
+        approx_eq(hodges, 405.0);
+        // Bazett over-corrects at tachycardic rates
+        assert!(bazett > fridericia && bazett > framingham && bazett > hodges);
+    }
 
-        let corrected = correct_na_for_glucose(sodium, glucose);
-        approx_eq(corrected.value(), 138.0);
+    // Tests for hyperglycemic_crisis_type
+
+    #[test]
+    fn classic_dka_is_identified() {
+        use crate::lab::blood::{bicarbonate::SerumBicarbonateExt, glucose::SerumGlucoseExt};
+
+        let crisis = hyperglycemic_crisis_type(
+            450.0.glu_serum_mg_dl(),
+            295.0,
+            7.12,
+            9.0.hco3_serum_meq(),
+            true,
+        );
+        assert_eq!(crisis, Some(HyperglycemicCrisis::Dka));
+    }
+
+    #[test]
+    fn classic_hhs_is_identified() {
+        use crate::lab::blood::{bicarbonate::SerumBicarbonateExt, glucose::SerumGlucoseExt};
+
+        let crisis = hyperglycemic_crisis_type(
+            55.0.glu_serum_mmol_l(),
+            345.0,
+            7.38,
+            22.0.hco3_serum_mmol(),
+            false,
+        );
+        assert_eq!(crisis, Some(HyperglycemicCrisis::Hhs));
+    }
+
+    #[test]
+    fn hyperosmolar_ketoacidosis_is_mixed() {
+        use crate::lab::blood::{bicarbonate::SerumBicarbonateExt, glucose::SerumGlucoseExt};
+
+        let crisis = hyperglycemic_crisis_type(
+            800.0.glu_serum_mg_dl(),
+            340.0,
+            7.2,
+            12.0.hco3_serum_meq(),
+            true,
+        );
+        assert_eq!(crisis, Some(HyperglycemicCrisis::Mixed));
+    }
+
+    // Tests for FENa
+
+    #[test]
+    fn fena_prerenal() {
+        use crate::lab::blood::{creatinine::CreatinineExt, sodium::SerumSodiumExt};
+        use crate::lab::urine::FenaRange;
+
+        let result = fena(
+            10.0.na_serum_meq(),
+            140.0.na_serum_meq(),
+            100.0.cr_serum_mg_dl(),
+            2.0.cr_serum_mg_dl(),
+        );
+        // 100 × (10 × 2) / (140 × 100) ≈ 0.14%
+        approx_eq(result.value(), 100.0 * 20.0 / 14000.0);
+        assert_eq!(result.range(), FenaRange::Prerenal);
+    }
+
+    #[test]
+    fn fena_atn_with_mixed_units() {
+        use crate::lab::blood::{creatinine::CreatinineExt, sodium::SerumSodiumExt};
+        use crate::lab::urine::FenaRange;
+
+        let result = fena(
+            60.0.na_serum_mmol(),
+            135.0.na_serum_meq(),
+            40.0.cr_serum_mg_dl(),
+            (3.0 * crate::constants::SCR_MGDL_TO_UMOLL).cr_serum_umol_l(),
+        );
+        // 100 × (60 × 3) / (135 × 40) ≈ 3.3%
+        approx_eq(result.value(), 100.0 * 180.0 / 5400.0);
+        assert_eq!(result.range(), FenaRange::Intrinsic);
     }
 
     // Tests for egfr_ckd_epi
@@ -244,7 +1677,7 @@ mod tests {
 
         let scr = 1.0.cr_serum_mg_dl();
         let age = Years(40.0);
-        let sex = Gender::Female;
+        let sex = SexAtBirth::Female;
 
         let gfr = egfr_ckd_epi(scr, age, sex);
 
@@ -257,13 +1690,24 @@ mod tests {
         approx_eq(gfr.value(), expected);
     }
 
+    #[test]
+    fn egfr_ckd_epi_high_creatinine_stages_as_g4_or_g5() {
+        use crate::lab::{blood::creatinine::CreatinineExt, gfr::CkdStage};
+
+        // ~16 and ~10 mL/min/1.73m² for a 60-year-old man
+        let g4 = egfr_ckd_epi(4.0.cr_serum_mg_dl(), Years(60.0), SexAtBirth::Male);
+        assert_eq!(g4.stage(), CkdStage::G4);
+        let g5 = egfr_ckd_epi(6.0.cr_serum_mg_dl(), Years(60.0), SexAtBirth::Male);
+        assert_eq!(g5.stage(), CkdStage::G5);
+    }
+
     #[test]
     fn egfr_ckd_epi_male_normal() {
         use crate::lab::blood::creatinine::CreatinineExt;
 
         let scr = 1.2.cr_serum_mg_dl();
         let age = Years(50.0);
-        let sex = Gender::Male;
+        let sex = SexAtBirth::Male;
 
         let gfr = egfr_ckd_epi(scr, age, sex);
 
@@ -283,7 +1727,7 @@ mod tests {
         // Test with µmol/L input
         let scr = 88.4.cr_serum_umol_l(); // 88.4 µmol/L = 1.0 mg/dL
         let age = Years(30.0);
-        let sex = Gender::Female;
+        let sex = SexAtBirth::Female;
 
         let gfr = egfr_ckd_epi(scr, age, sex);
 
@@ -301,7 +1745,7 @@ mod tests {
         // Female with low creatinine (uses min function with ratio <= 1)
         let scr = 0.5.cr_serum_mg_dl();
         let age = Years(25.0);
-        let sex = Gender::Female;
+        let sex = SexAtBirth::Female;
 
         let gfr = egfr_ckd_epi(scr, age, sex);
 
@@ -319,7 +1763,7 @@ mod tests {
         // Male with high creatinine (uses max function with ratio > 1)
         let scr = 3.0.cr_serum_mg_dl();
         let age = Years(70.0);
-        let sex = Gender::Male;
+        let sex = SexAtBirth::Male;
 
         let gfr = egfr_ckd_epi(scr, age, sex);
 
@@ -330,6 +1774,212 @@ mod tests {
         approx_eq(gfr.value(), expected);
     }
 
+    // Tests for Cockcroft-Gault
+
+    #[test]
+    fn crcl_cockcroft_gault_male() {
+        use crate::lab::{blood::creatinine::CreatinineExt, vitals::WeightExt};
+
+        let crcl = crcl_cockcroft_gault(
+            1.0.cr_serum_mg_dl(),
+            Years(60.0),
+            72.0.weight_kg(),
+            SexAtBirth::Male,
+        );
+        // (140 - 60) * 72 / (72 * 1.0) = 80
+        approx_eq(crcl.value(), 80.0);
+    }
+
+    #[test]
+    fn crcl_cockcroft_gault_female_and_umol_units() {
+        use crate::lab::{blood::creatinine::CreatinineExt, vitals::WeightExt};
+
+        let crcl = crcl_cockcroft_gault(
+            176.8.cr_serum_umol_l(), // 2.0 mg/dL
+            Years(80.0),
+            60.0.weight_kg(),
+            SexAtBirth::Female,
+        );
+        let expected = (140.0 - 80.0) * 60.0 / (72.0 * 2.0) * 0.85;
+        approx_eq(crcl.value(), expected);
+    }
+
+    // Tests for Salazar-Corcoran CrCl
+
+    #[test]
+    fn crcl_salazar_corcoran_obese_male() {
+        use crate::lab::{
+            blood::creatinine::CreatinineExt,
+            vitals::{HeightExt, WeightExt},
+        };
+
+        // 50 y, 175 cm, 120 kg, SCr 1.0 mg/dL:
+        // 87 × (0.285 × 120 + 12.1 × 1.75²) / 51 = 87 × 71.256 / 51 ≈ 121.6
+        let crcl = crcl_salazar_corcoran(
+            1.75.height_in_m(),
+            120.0.weight_kg(),
+            Years(50.0),
+            SexAtBirth::Male,
+            1.0.cr_serum_mg_dl(),
+        );
+        assert!((crcl.value() - 121.6).abs() < 0.1);
+
+        // well below Cockcroft-Gault on total body weight
+        let cg = crcl_cockcroft_gault(
+            1.0.cr_serum_mg_dl(),
+            Years(50.0),
+            120.0.weight_kg(),
+            SexAtBirth::Male,
+        );
+        assert!(crcl.value() < cg.value());
+    }
+
+    #[test]
+    fn crcl_salazar_corcoran_obese_female_in_conventional_units() {
+        use crate::lab::{
+            blood::creatinine::CreatinineExt,
+            vitals::{HeightExt, WeightExt},
+        };
+
+        // 40 y, 5.5 ft (1.6764 m), 100 kg, SCr 88.4 µmol/L (1.0 mg/dL)
+        let crcl = crcl_salazar_corcoran(
+            5.5.height_in_ft(),
+            (100.0 * crate::constants::KG_TO_LB).weight_lb(),
+            Years(40.0),
+            SexAtBirth::Female,
+            88.4.cr_serum_umol_l(),
+        );
+        let expected = (146.0 - 40.0) * (0.287 * 100.0 + 9.74 * 1.6764_f64.powi(2)) / 60.0;
+        approx_eq(crcl.value(), expected);
+    }
+
+    // Tests for cystatin C eGFR
+
+    #[test]
+    fn egfr_ckd_epi_cystatin_at_reference_cystatin() {
+        use crate::lab::blood::cystatin_c::CystatinCExt;
+
+        // Scys of 0.8 mg/L zeroes both ratio terms
+        let male = egfr_ckd_epi_cystatin(0.8.cys_c_mg_l(), Years(40.0), SexAtBirth::Male);
+        approx_eq(male.value(), 133.0 * 0.996_f64.powf(40.0));
+        let female = egfr_ckd_epi_cystatin(0.8.cys_c_mg_l(), Years(40.0), SexAtBirth::Female);
+        approx_eq(female.value(), male.value() * 0.932);
+    }
+
+    #[test]
+    fn combined_cr_cys_lies_between_single_marker_equations() {
+        use crate::lab::blood::{creatinine::CreatinineExt, cystatin_c::CystatinCExt};
+
+        // low-muscle patient: normal creatinine, raised cystatin C
+        let age = Years(50.0);
+        let scr = 1.0.cr_serum_mg_dl();
+        let cys = 1.2.cys_c_mg_l();
+
+        let cr_only = egfr_ckd_epi(scr, age, SexAtBirth::Male).value();
+        let cys_only = egfr_ckd_epi_cystatin(cys, age, SexAtBirth::Male).value();
+        let combined = egfr_ckd_epi_cr_cys(scr, cys, age, SexAtBirth::Male).value();
+
+        // ~104, ~64 and ~81 mL/min/1.73m²
+        assert!(cys_only < combined && combined < cr_only);
+    }
+
+    // Tests for MDRD
+
+    #[test]
+    fn egfr_mdrd_matches_published_equation() {
+        use crate::lab::blood::creatinine::CreatinineExt;
+
+        let male = egfr_mdrd(1.0.cr_serum_mg_dl(), Years(50.0), SexAtBirth::Male);
+        approx_eq(male.value(), 175.0 * 50.0_f64.powf(-0.203));
+
+        let female = egfr_mdrd(2.0.cr_serum_mg_dl(), Years(70.0), SexAtBirth::Female);
+        let expected = 175.0 * 2.0_f64.powf(-1.154) * 70.0_f64.powf(-0.203) * 0.742;
+        approx_eq(female.value(), expected);
+    }
+
+    #[test]
+    fn egfr_mdrd_accepts_si_creatinine() {
+        use crate::lab::blood::creatinine::CreatinineExt;
+
+        let conventional = egfr_mdrd(1.5.cr_serum_mg_dl(), Years(40.0), SexAtBirth::Male);
+        let si = egfr_mdrd(
+            (1.5 * crate::constants::SCR_MGDL_TO_UMOLL).cr_serum_umol_l(),
+            Years(40.0),
+            SexAtBirth::Male,
+        );
+        approx_eq(conventional.value(), si.value());
+    }
+
+    #[test]
+    fn mdrd_reads_lower_than_ckd_epi_at_normal_creatinine() {
+        use crate::lab::blood::creatinine::CreatinineExt;
+
+        let scr = 0.9.cr_serum_mg_dl();
+        let mdrd = egfr_mdrd(scr, Years(45.0), SexAtBirth::Male).value();
+        let ckd_epi = egfr_ckd_epi(scr, Years(45.0), SexAtBirth::Male).value();
+        // ~91 vs ~107 mL/min/1.73m²
+        assert!(ckd_epi - mdrd > 10.0);
+    }
+
+    // Tests for eGFR sex delta
+
+    #[test]
+    fn egfr_sex_delta_reports_gap_between_sexes() {
+        use crate::lab::blood::creatinine::CreatinineExt;
+
+        let scr = 1.0.cr_serum_mg_dl();
+        let age = Years(50.0);
+        let female = egfr_ckd_epi(scr, age, SexAtBirth::Female).value();
+        let male = egfr_ckd_epi(scr, age, SexAtBirth::Male).value();
+
+        let delta = egfr_sex_delta(scr, age);
+        approx_eq(delta, female - male);
+        // κ of 0.7 vs 0.9 dominates the female multiplier: ~68.6 vs ~91.7
+        assert!(delta < -20.0);
+        // the gap holds across low creatinine values too
+        assert!(egfr_sex_delta(0.4.cr_serum_mg_dl(), age) < 0.0);
+    }
+
+    // Tests for Schwartz eGFR
+
+    #[test]
+    fn egfr_schwartz_for_100_cm_child() {
+        use crate::lab::{blood::creatinine::CreatinineExt, vitals::HeightExt};
+
+        // 0.413 * 100 / 0.4 = 103.25
+        let egfr = egfr_schwartz(0.4.cr_serum_mg_dl(), 1.0.height_in_m());
+        approx_eq(egfr.value(), 103.25);
+    }
+
+    #[test]
+    fn egfr_schwartz_accepts_feet_and_si_creatinine() {
+        use crate::lab::{blood::creatinine::CreatinineExt, vitals::HeightExt};
+
+        let egfr = egfr_schwartz(
+            (0.4 * crate::constants::SCR_MGDL_TO_UMOLL).cr_serum_umol_l(),
+            (1.0 * M_TO_FT).height_in_ft(),
+        );
+        approx_eq(egfr.value(), 103.25);
+    }
+
+    // Tests for pediatric BSA-de-indexed eGFR
+
+    #[test]
+    fn egfr_pediatric_bsa_is_lower_than_indexed_for_small_child() {
+        use crate::lab::{
+            blood::creatinine::CreatinineExt,
+            vitals::{HeightExt, WeightExt},
+        };
+
+        let absolute =
+            egfr_pediatric_bsa(1.0.height_in_m(), 16.0.weight_kg(), 0.4.cr_serum_mg_dl());
+
+        let indexed = 0.413 * 100.0 / 0.4;
+        let bsa = (100.0_f64 * 16.0 / 3600.0).sqrt();
+        approx_eq(absolute.value(), indexed * bsa / 1.73);
+        assert!(absolute.value() < indexed);
+    }
+
     // Tests for BMI calculation
 
     #[test]
@@ -378,6 +2028,30 @@ mod tests {
         approx_eq(bmi_result.value(), expected);
     }
 
+    #[test]
+    fn bmi_typical_adult_is_about_22_9() {
+        use crate::lab::vitals::{HeightExt, WeightExt};
+
+        let bmi_result = bmi(1.75.height_in_m(), 70.0.weight_kg());
+        assert!((bmi_result.value() - 22.857).abs() < 1e-3);
+    }
+
+    #[test]
+    fn bmi_same_for_lb_and_ft_as_for_kg_and_m() {
+        use crate::lab::vitals::{Height, Weight, WeightExt};
+        use crate::units::{Foot, Lb, Meter};
+
+        let weight_kg = 70.0.weight_kg();
+        let height_m = Height::<Meter>::from_ft_and_in(5, 9.0);
+        let weight_lb: Weight<Lb> = Weight::from(weight_kg);
+        let height_ft: Height<Foot> = Height::from(height_m);
+
+        approx_eq(
+            bmi(height_ft, weight_lb).value(),
+            bmi(height_m, weight_kg).value(),
+        );
+    }
+
     #[test]
     fn bmi_underweight() {
         use crate::lab::vitals::{HeightExt, WeightExt};
@@ -406,6 +2080,118 @@ mod tests {
         assert!(bmi_result.value() > 30.0); // Obese threshold
     }
 
+    // Tests for obesity hypoventilation screening
+
+    #[test]
+    fn ohs_screen_positive_when_obese_with_elevated_hco3() {
+        use crate::lab::blood::bicarbonate::SerumBicarbonateExt;
+
+        assert!(obesity_hypoventilation_risk(
+            38.0.to_bmi(),
+            31.0.hco3_serum_meq()
+        ));
+    }
+
+    #[test]
+    fn ohs_screen_negative_when_hco3_normal_or_not_obese() {
+        use crate::lab::blood::bicarbonate::SerumBicarbonateExt;
+
+        assert!(!obesity_hypoventilation_risk(
+            38.0.to_bmi(),
+            24.0.hco3_serum_mmol()
+        ));
+        assert!(!obesity_hypoventilation_risk(
+            24.0.to_bmi(),
+            31.0.hco3_serum_mmol()
+        ));
+    }
+
+    // Tests for BSA
+
+    #[test]
+    fn bsa_mosteller_and_dubois_agree_for_standard_adult() {
+        use crate::lab::vitals::{HeightExt, WeightExt};
+
+        let mosteller = bsa_mosteller(1.8.height_in_m(), 80.0.weight_kg());
+        let dubois = bsa_dubois(1.8.height_in_m(), 80.0.weight_kg());
+
+        approx_eq(mosteller.value(), 2.0);
+        assert!((dubois.value() - 1.996).abs() < 1e-3);
+        assert!((mosteller.value() - dubois.value()).abs() < 0.05);
+    }
+
+    #[test]
+    fn bsa_accepts_imperial_units() {
+        use crate::lab::vitals::{Height, HeightExt, Weight, WeightExt};
+        use crate::units::{Foot, Lb};
+
+        let height_ft: Height<Foot> = Height::from(1.8.height_in_m());
+        let weight_lb: Weight<Lb> = Weight::from(80.0.weight_kg());
+
+        approx_eq(bsa_mosteller(height_ft, weight_lb).value(), 2.0);
+    }
+
+    // Tests for FIB-4
+
+    #[test]
+    fn fib4_low_risk() {
+        use crate::lab::blood::{alt::AltExt, ast::AstExt, platelets::PlateletsExt};
+
+        let score = fib4(
+            Years(40.0),
+            25.0.ast_u_l(),
+            30.0.alt_u_l(),
+            250.0.plt_x10e9_l(),
+        );
+        approx_eq(score, (40.0 * 25.0) / (250.0 * 30.0_f64.sqrt()));
+        assert_eq!(classify_fib4(score), Fib4Risk::Low);
+    }
+
+    #[test]
+    fn fib4_high_risk_with_si_enzymes() {
+        use crate::lab::blood::{alt::AltExt, ast::AstExt, platelets::PlateletsExt};
+
+        // 1.5 µkat/L = 90 U/L; 0.8 µkat/L = 48 U/L
+        let score = fib4(
+            Years(65.0),
+            1.5.ast_ukat_l(),
+            0.8.alt_ukat_l(),
+            90.0.plt_x10e9_l(),
+        );
+        approx_eq(score, (65.0 * 90.0) / (90.0 * 48.0_f64.sqrt()));
+        assert_eq!(classify_fib4(score), Fib4Risk::High);
+    }
+
+    #[test]
+    fn fib4_classifier_cutoffs() {
+        assert_eq!(classify_fib4(1.29), Fib4Risk::Low);
+        assert_eq!(classify_fib4(1.3), Fib4Risk::Indeterminate);
+        assert_eq!(classify_fib4(2.67), Fib4Risk::Indeterminate);
+        assert_eq!(classify_fib4(2.68), Fib4Risk::High);
+    }
+
+    // Tests for reticulocyte production index
+
+    #[test]
+    fn rpi_at_normal_hematocrit_is_the_raw_count() {
+        use crate::lab::blood::hematocrit::HematocritExt;
+        approx_eq(reticulocyte_production_index(1.0, 45.0.hct_pct()), 1.0);
+    }
+
+    #[test]
+    fn rpi_applies_maturation_factor_in_severe_anemia() {
+        use crate::lab::blood::hematocrit::HematocritExt;
+        // 9% retics at Hct 15%: corrected 3%, then / 2.5 days
+        let rpi = reticulocyte_production_index(9.0, 15.0.hct_pct());
+        approx_eq(rpi, 9.0 * (15.0 / 45.0) / 2.5);
+        assert!(rpi < 2.0);
+        // at Hct 20% the maturation time drops to 2 days
+        approx_eq(
+            reticulocyte_production_index(9.0, 20.0.hct_pct()),
+            9.0 * (20.0 / 45.0) / 2.0,
+        );
+    }
+
     // Tests for MELD score
 
     #[test]
@@ -547,4 +2333,81 @@ mod tests {
         let expected = (bili_term + inr_term + scr_term + 6.43).round() as u8;
         assert_eq!(score, expected);
     }
+
+    // Tests for MELD-Na
+
+    #[test]
+    fn meld_na_normal_values_score_six() {
+        use crate::lab::blood::{
+            bilirubin::BilirubinExt, creatinine::CreatinineExt, inr::InrExt, sodium::SerumSodiumExt,
+        };
+
+        let score = meld_na(
+            1.0.serum_bili_mgdl(),
+            1.0.cr_serum_mg_dl(),
+            1.0.inr(),
+            140.0.na_serum_meq(),
+            false,
+        );
+        // MELD(i) = 6.4, below 11 so no Na correction
+        assert_eq!(score, 6);
+    }
+
+    #[test]
+    fn meld_na_applies_sodium_correction() {
+        use crate::lab::blood::{
+            bilirubin::BilirubinExt, creatinine::CreatinineExt, inr::InrExt, sodium::SerumSodiumExt,
+        };
+
+        let score = meld_na(
+            2.0.serum_bili_mgdl(),
+            1.5.cr_serum_mg_dl(),
+            1.8.inr(),
+            130.0.na_serum_meq(),
+            false,
+        );
+        // MELD(i) = 19.5; 19.5 + 1.32 × 7 - 0.033 × 19.5 × 7 = 24.2
+        assert_eq!(score, 24);
+    }
+
+    #[test]
+    fn meld_na_dialysis_sets_creatinine_to_four() {
+        use crate::lab::blood::{
+            bilirubin::BilirubinExt, creatinine::CreatinineExt, inr::InrExt, sodium::SerumSodiumExt,
+        };
+
+        let on_hd = meld_na(
+            1.0.serum_bili_mgdl(),
+            1.2.cr_serum_mg_dl(),
+            1.0.inr(),
+            137.0.na_serum_meq(),
+            true,
+        );
+        let capped = meld_na(
+            1.0.serum_bili_mgdl(),
+            6.5.cr_serum_mg_dl(),
+            1.0.inr(),
+            137.0.na_serum_meq(),
+            false,
+        );
+        // MELD(i) = 0.957 × ln(4) + 0.643 = 19.7
+        assert_eq!(on_hd, 20);
+        assert_eq!(capped, 20);
+    }
+
+    #[test]
+    fn meld_na_is_capped_at_forty() {
+        use crate::lab::blood::{
+            bilirubin::BilirubinExt, creatinine::CreatinineExt, inr::InrExt, sodium::SerumSodiumExt,
+        };
+
+        let score = meld_na(
+            40.0.serum_bili_mgdl(),
+            5.0.cr_serum_mg_dl(),
+            5.0.inr(),
+            118.0.na_serum_meq(),
+            false,
+        );
+        assert_eq!(score, 40);
+    }
 }
@@ -0,0 +1,172 @@
+//! Alvarado Score
+//!
+//! Clinical likelihood of acute appendicitis from symptoms, signs and lab findings
+//! (the "MANTRELS" mnemonic), scored 0-10.
+//!
+
+use crate::{lab::blood::white_blood_cells::WhiteBloodCells, units::PerNanoLiter};
+
+/// Likelihood of appendicitis for an Alvarado score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppendicitisLikelihood {
+    /// Score 0-4: appendicitis unlikely.
+    Low,
+    /// Score 5-6: equivocal; consider imaging or observation.
+    Intermediate,
+    /// Score 7-10: appendicitis probable.
+    High,
+}
+
+/// An Alvarado score calculator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Alvarado {
+    wbc: f64,
+    migratory_pain: bool,
+    anorexia: bool,
+    nausea: bool,
+    rlq_tenderness: bool,
+    rebound: bool,
+    fever: bool,
+    left_shift: bool,
+    score: Option<u8>,
+}
+impl Alvarado /* builder / setters */ {
+    pub fn new(wbc: WhiteBloodCells<PerNanoLiter>) -> Self {
+        Self {
+            wbc: wbc.value(),
+            migratory_pain: false,
+            anorexia: false,
+            nausea: false,
+            rlq_tenderness: false,
+            rebound: false,
+            fever: false,
+            left_shift: false,
+            score: None,
+        }
+    }
+    /// Pain migrating to the right lower quadrant.
+    pub fn migratory_pain(mut self) -> Self {
+        self.migratory_pain = true;
+        self
+    }
+    pub fn anorexia(mut self) -> Self {
+        self.anorexia = true;
+        self
+    }
+    /// Nausea or vomiting.
+    pub fn nausea(mut self) -> Self {
+        self.nausea = true;
+        self
+    }
+    pub fn rlq_tenderness(mut self) -> Self {
+        self.rlq_tenderness = true;
+        self
+    }
+    pub fn rebound(mut self) -> Self {
+        self.rebound = true;
+        self
+    }
+    /// Temperature ≥ 37.3 °C.
+    pub fn fever(mut self) -> Self {
+        self.fever = true;
+        self
+    }
+    /// More than 75% neutrophils on the differential.
+    pub fn left_shift(mut self) -> Self {
+        self.left_shift = true;
+        self
+    }
+}
+
+impl Alvarado /* getters */ {
+    /// WBC > 10 ×10⁹/L.
+    pub fn leukocytosis(&self) -> bool {
+        self.wbc > 10.0
+    }
+    pub fn score(&self) -> Option<u8> {
+        self.score
+    }
+}
+
+impl Alvarado /* calculations */ {
+    #[must_use]
+    pub fn calculate(mut self) -> Self {
+        let points = [
+            (self.migratory_pain, 1),
+            (self.anorexia, 1),
+            (self.nausea, 1),
+            (self.rlq_tenderness, 2),
+            (self.rebound, 1),
+            (self.fever, 1),
+            (self.leukocytosis(), 2),
+            (self.left_shift, 1),
+        ];
+        self.score = Some(
+            points
+                .iter()
+                .filter(|(present, _)| *present)
+                .map(|(_, pts)| pts)
+                .sum(),
+        );
+        self
+    }
+
+    pub fn likelihood(&self) -> Option<AppendicitisLikelihood> {
+        self.score.map(|score| match score {
+            0..=4 => AppendicitisLikelihood::Low,
+            5..=6 => AppendicitisLikelihood::Intermediate,
+            _ => AppendicitisLikelihood::High,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lab::blood::white_blood_cells::WhiteBloodCellsExt;
+
+    #[test]
+    fn score_is_none_until_calculated() {
+        let alvarado = Alvarado::new(8.0.wbc_x10e9_l());
+        assert!(alvarado.score().is_none());
+        assert!(alvarado.likelihood().is_none());
+    }
+
+    #[test]
+    fn nonspecific_symptoms_with_normal_wbc_are_low_likelihood() {
+        let alvarado = Alvarado::new(8.0.wbc_x10e9_l())
+            .anorexia()
+            .nausea()
+            .calculate();
+        assert_eq!(Some(2), alvarado.score());
+        assert_eq!(Some(AppendicitisLikelihood::Low), alvarado.likelihood());
+    }
+
+    #[test]
+    fn classic_presentation_is_high_likelihood() {
+        let alvarado = Alvarado::new(14.5.wbc_x10e9_l())
+            .migratory_pain()
+            .anorexia()
+            .nausea()
+            .rlq_tenderness()
+            .rebound()
+            .fever()
+            .left_shift()
+            .calculate();
+        assert_eq!(Some(10), alvarado.score());
+        assert_eq!(Some(AppendicitisLikelihood::High), alvarado.likelihood());
+    }
+
+    #[test]
+    fn leukocytosis_scores_two_points() {
+        let alvarado = Alvarado::new(12.0.wbc_x10e9_l())
+            .rlq_tenderness()
+            .rebound()
+            .calculate();
+        assert_eq!(Some(5), alvarado.score());
+        assert_eq!(
+            Some(AppendicitisLikelihood::Intermediate),
+            alvarado.likelihood()
+        );
+    }
+}
@@ -0,0 +1,270 @@
+//! ASCVD Pooled Cohort Equations
+//!
+//! The 2013 ACC/AHA pooled cohort equations (Goff et al.) estimate the 10-year risk
+//! of a first atherosclerotic cardiovascular disease (ASCVD) event: nonfatal MI,
+//! coronary death, or fatal or nonfatal stroke. Separate coefficient sets were fit
+//! for white and African American men and women.
+//!
+//! The equations were derived for ages 40-79 without known ASCVD, total cholesterol
+//! 130-320 mg/dL, HDL 20-100 mg/dL and systolic BP 90-200 mmHg; results outside
+//! those ranges are extrapolations.
+//!
+
+use crate::{
+    history::{SexAtBirth, Years},
+    lab::{
+        blood::cholesterol::{Cholesterol, Hdl},
+        vitals::blood_pressure::BloodPressure,
+    },
+    units::{cholesterol::CholesterolUnit, MgdL},
+};
+
+/// Which pooled cohort coefficient set to use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AscvdRace {
+    AfricanAmerican,
+    /// The guideline recommends the white equations for other groups, while noting
+    /// they may over- or underestimate risk.
+    WhiteOrOther,
+}
+
+/// Yes/no risk factors for `ascvd_risk`; all default to `false`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AscvdRiskFactors {
+    /// On treatment for hypertension.
+    pub bp_treated: bool,
+    pub diabetes: bool,
+    /// Current smoker.
+    pub smoker: bool,
+}
+
+/// One race/sex coefficient set. Terms that don't appear in a set are zero.
+struct PooledCohortCoefficients {
+    ln_age: f64,
+    ln_age_sq: f64,
+    ln_tc: f64,
+    ln_age_ln_tc: f64,
+    ln_hdl: f64,
+    ln_age_ln_hdl: f64,
+    ln_treated_sbp: f64,
+    ln_age_ln_treated_sbp: f64,
+    ln_untreated_sbp: f64,
+    ln_age_ln_untreated_sbp: f64,
+    smoker: f64,
+    ln_age_smoker: f64,
+    diabetes: f64,
+    /// 10-year baseline survival
+    baseline_survival: f64,
+    /// Mean of the individual sums in the derivation cohort
+    mean_sum: f64,
+}
+
+const WHITE_FEMALE: PooledCohortCoefficients = PooledCohortCoefficients {
+    ln_age: -29.799,
+    ln_age_sq: 4.884,
+    ln_tc: 13.540,
+    ln_age_ln_tc: -3.114,
+    ln_hdl: -13.578,
+    ln_age_ln_hdl: 3.149,
+    ln_treated_sbp: 2.019,
+    ln_age_ln_treated_sbp: 0.0,
+    ln_untreated_sbp: 1.957,
+    ln_age_ln_untreated_sbp: 0.0,
+    smoker: 7.574,
+    ln_age_smoker: -1.665,
+    diabetes: 0.661,
+    baseline_survival: 0.9665,
+    mean_sum: -29.18,
+};
+
+const AFRICAN_AMERICAN_FEMALE: PooledCohortCoefficients = PooledCohortCoefficients {
+    ln_age: 17.114,
+    ln_age_sq: 0.0,
+    ln_tc: 0.940,
+    ln_age_ln_tc: 0.0,
+    ln_hdl: -18.920,
+    ln_age_ln_hdl: 4.475,
+    ln_treated_sbp: 29.291,
+    ln_age_ln_treated_sbp: -6.432,
+    ln_untreated_sbp: 27.820,
+    ln_age_ln_untreated_sbp: -6.087,
+    smoker: 0.691,
+    ln_age_smoker: 0.0,
+    diabetes: 0.874,
+    baseline_survival: 0.9533,
+    mean_sum: 86.61,
+};
+
+const WHITE_MALE: PooledCohortCoefficients = PooledCohortCoefficients {
+    ln_age: 12.344,
+    ln_age_sq: 0.0,
+    ln_tc: 11.853,
+    ln_age_ln_tc: -2.664,
+    ln_hdl: -7.990,
+    ln_age_ln_hdl: 1.769,
+    ln_treated_sbp: 1.797,
+    ln_age_ln_treated_sbp: 0.0,
+    ln_untreated_sbp: 1.764,
+    ln_age_ln_untreated_sbp: 0.0,
+    smoker: 7.837,
+    ln_age_smoker: -1.795,
+    diabetes: 0.658,
+    baseline_survival: 0.9144,
+    mean_sum: 61.18,
+};
+
+const AFRICAN_AMERICAN_MALE: PooledCohortCoefficients = PooledCohortCoefficients {
+    ln_age: 2.469,
+    ln_age_sq: 0.0,
+    ln_tc: 0.302,
+    ln_age_ln_tc: 0.0,
+    ln_hdl: -0.307,
+    ln_age_ln_hdl: 0.0,
+    ln_treated_sbp: 1.916,
+    ln_age_ln_treated_sbp: 0.0,
+    ln_untreated_sbp: 1.809,
+    ln_age_ln_untreated_sbp: 0.0,
+    smoker: 0.549,
+    ln_age_smoker: 0.0,
+    diabetes: 0.645,
+    baseline_survival: 0.8954,
+    mean_sum: 19.54,
+};
+
+/// 10-year risk (%) of a first ASCVD event by the pooled cohort equations.
+///
+/// Risk = 1 - S₁₀^exp(Σ - mean), where Σ is the sum of the coefficient-weighted
+/// log-transformed inputs for the patient's race and sex. Cholesterols are
+/// converted to mg/dL and only the systolic pressure is used.
+pub fn ascvd_risk<T, H>(
+    age: Years,
+    sex: SexAtBirth,
+    race: AscvdRace,
+    total_chol: Cholesterol<T>,
+    hdl: Hdl<H>,
+    bp: BloodPressure,
+    risk_factors: AscvdRiskFactors,
+) -> f64
+where
+    T: CholesterolUnit,
+    H: CholesterolUnit,
+{
+    let coef = match (sex, race) {
+        (SexAtBirth::Female, AscvdRace::WhiteOrOther) => &WHITE_FEMALE,
+        (SexAtBirth::Female, AscvdRace::AfricanAmerican) => &AFRICAN_AMERICAN_FEMALE,
+        (SexAtBirth::Male, AscvdRace::WhiteOrOther) => &WHITE_MALE,
+        (SexAtBirth::Male, AscvdRace::AfricanAmerican) => &AFRICAN_AMERICAN_MALE,
+    };
+
+    let ln_age = age.0.ln();
+    let ln_tc = MgdL::from_cholesterol_mmol_l(T::to_cholesterol_mmol_l(total_chol.value())).ln();
+    let ln_hdl = MgdL::from_cholesterol_mmol_l(H::to_cholesterol_mmol_l(hdl.value())).ln();
+    let ln_sbp = bp.systolic().ln();
+
+    let sbp_term = if risk_factors.bp_treated {
+        (coef.ln_treated_sbp + coef.ln_age_ln_treated_sbp * ln_age) * ln_sbp
+    } else {
+        (coef.ln_untreated_sbp + coef.ln_age_ln_untreated_sbp * ln_age) * ln_sbp
+    };
+    let smoker_term = if risk_factors.smoker {
+        coef.smoker + coef.ln_age_smoker * ln_age
+    } else {
+        0.0
+    };
+    let diabetes_term = if risk_factors.diabetes {
+        coef.diabetes
+    } else {
+        0.0
+    };
+
+    let sum = coef.ln_age * ln_age
+        + coef.ln_age_sq * ln_age * ln_age
+        + (coef.ln_tc + coef.ln_age_ln_tc * ln_age) * ln_tc
+        + (coef.ln_hdl + coef.ln_age_ln_hdl * ln_age) * ln_hdl
+        + sbp_term
+        + smoker_term
+        + diabetes_term;
+
+    100.0 * (1.0 - coef.baseline_survival.powf((sum - coef.mean_sum).exp()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lab::blood::cholesterol::{CholesterolExt, HdlExt};
+
+    // Worked example from Goff et al. 2013 (Table A): age 55, TC 213 mg/dL, HDL
+    // 50 mg/dL, untreated SBP 120 mmHg, nonsmoker, no diabetes.
+    fn worked_example(sex: SexAtBirth, race: AscvdRace) -> f64 {
+        ascvd_risk(
+            Years(55.0),
+            sex,
+            race,
+            213.0.chol_total_mg_dl(),
+            50.0.hdl_mg_dl(),
+            BloodPressure::new(120.0, 80.0),
+            AscvdRiskFactors::default(),
+        )
+    }
+
+    fn assert_close(risk: f64, published: f64) {
+        assert!((risk - published).abs() < 0.1, "{} !~= {}", risk, published);
+    }
+
+    #[test]
+    fn matches_published_example_for_women() {
+        assert_close(
+            worked_example(SexAtBirth::Female, AscvdRace::WhiteOrOther),
+            2.1,
+        );
+        assert_close(
+            worked_example(SexAtBirth::Female, AscvdRace::AfricanAmerican),
+            3.0,
+        );
+    }
+
+    #[test]
+    fn matches_published_example_for_men() {
+        assert_close(
+            worked_example(SexAtBirth::Male, AscvdRace::WhiteOrOther),
+            5.3,
+        );
+        assert_close(
+            worked_example(SexAtBirth::Male, AscvdRace::AfricanAmerican),
+            6.1,
+        );
+    }
+
+    #[test]
+    fn si_lipids_give_the_same_risk() {
+        let si = ascvd_risk(
+            Years(55.0),
+            SexAtBirth::Male,
+            AscvdRace::WhiteOrOther,
+            (213.0 * 0.0259).chol_total_mmol_l(),
+            (50.0 * 0.0259).hdl_mmol_l(),
+            BloodPressure::new(120.0, 80.0),
+            AscvdRiskFactors::default(),
+        );
+        assert!((si - worked_example(SexAtBirth::Male, AscvdRace::WhiteOrOther)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn risk_factors_raise_risk() {
+        let baseline = worked_example(SexAtBirth::Female, AscvdRace::WhiteOrOther);
+        let high = ascvd_risk(
+            Years(55.0),
+            SexAtBirth::Female,
+            AscvdRace::WhiteOrOther,
+            213.0.chol_total_mg_dl(),
+            50.0.hdl_mg_dl(),
+            BloodPressure::new(150.0, 90.0),
+            AscvdRiskFactors {
+                bp_treated: true,
+                diabetes: true,
+                smoker: true,
+            },
+        );
+        assert!(high > 3.0 * baseline);
+    }
+}
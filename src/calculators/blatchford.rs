@@ -0,0 +1,187 @@
+//! Glasgow-Blatchford Score
+//!
+//! Risk-stratifies upper GI bleeding by the likelihood that the patient will need
+//! transfusion, endoscopic therapy or surgery. Only a score of 0 identifies patients
+//! who can safely be managed as outpatients.
+//!
+
+use crate::{
+    history::SexAtBirth,
+    lab::{
+        blood::{bun::Bun, hemoglobin::Hemoglobin},
+        vitals::blood_pressure::BloodPressure,
+    },
+    units::{bun::BunUnit, hemoglobin::HemoglobinUnit, GdL, MmolL},
+};
+
+/// A Glasgow-Blatchford score calculator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Blatchford {
+    urea_mmol_l: f64,
+    hemoglobin_g_dl: f64,
+    sex: SexAtBirth,
+    systolic: f64,
+    heart_rate: f64,
+    melena: bool,
+    syncope: bool,
+    hepatic_disease: bool,
+    cardiac_failure: bool,
+    score: Option<u8>,
+}
+impl Blatchford /* builder / setters */ {
+    /// Hemoglobin is scored against the cutoffs for `sex`. Heart rate is in beats
+    /// per minute.
+    pub fn new<B: BunUnit, H: HemoglobinUnit>(
+        urea: Bun<B>,
+        hemoglobin: Hemoglobin<H>,
+        sex: SexAtBirth,
+        bp: BloodPressure,
+        heart_rate: f64,
+    ) -> Self {
+        Self {
+            urea_mmol_l: <MmolL as BunUnit>::from_urea_mmol_l(B::to_urea_mmol_l(urea.value())),
+            hemoglobin_g_dl: GdL::from_g_l(H::to_g_l(hemoglobin.value())),
+            sex,
+            systolic: bp.systolic(),
+            heart_rate,
+            melena: false,
+            syncope: false,
+            hepatic_disease: false,
+            cardiac_failure: false,
+            score: None,
+        }
+    }
+    pub fn melena(mut self) -> Self {
+        self.melena = true;
+        self
+    }
+    pub fn syncope(mut self) -> Self {
+        self.syncope = true;
+        self
+    }
+    pub fn hepatic_disease(mut self) -> Self {
+        self.hepatic_disease = true;
+        self
+    }
+    pub fn cardiac_failure(mut self) -> Self {
+        self.cardiac_failure = true;
+        self
+    }
+}
+
+impl Blatchford /* getters */ {
+    pub fn score(&self) -> Option<u8> {
+        self.score
+    }
+    /// Whether the patient is likely to need transfusion or endoscopic intervention,
+    /// i.e. the score is above 0. `None` until calculated.
+    pub fn needs_intervention(&self) -> Option<bool> {
+        self.score.map(|score| score > 0)
+    }
+}
+
+impl Blatchford /* calculations */ {
+    #[must_use]
+    pub fn calculate(mut self) -> Self {
+        self.score = Some(
+            self.urea_points()
+                + self.hemoglobin_points()
+                + self.systolic_points()
+                + u8::from(self.heart_rate >= 100.0)
+                + u8::from(self.melena)
+                + 2 * u8::from(self.syncope)
+                + 2 * u8::from(self.hepatic_disease)
+                + 2 * u8::from(self.cardiac_failure),
+        );
+        self
+    }
+
+    /// Urea 6.5-8 mmol/L: 2; 8-10: 3; 10-25: 4; 25 or more: 6.
+    fn urea_points(&self) -> u8 {
+        match self.urea_mmol_l {
+            urea if urea >= 25.0 => 6,
+            urea if urea >= 10.0 => 4,
+            urea if urea >= 8.0 => 3,
+            urea if urea >= 6.5 => 2,
+            _ => 0,
+        }
+    }
+
+    /// Men: 12-13 g/dL: 1; 10-12: 3; below 10: 6. Women: 10-12 g/dL: 1; below 10: 6.
+    fn hemoglobin_points(&self) -> u8 {
+        match (self.hemoglobin_g_dl, self.sex) {
+            (hgb, _) if hgb < 10.0 => 6,
+            (hgb, SexAtBirth::Male) if hgb < 12.0 => 3,
+            (hgb, SexAtBirth::Male) if hgb < 13.0 => 1,
+            (hgb, SexAtBirth::Female) if hgb < 12.0 => 1,
+            _ => 0,
+        }
+    }
+
+    /// Systolic 100-109 mmHg: 1; 90-99: 2; below 90: 3.
+    fn systolic_points(&self) -> u8 {
+        match self.systolic {
+            sbp if sbp < 90.0 => 3,
+            sbp if sbp < 100.0 => 2,
+            sbp if sbp < 110.0 => 1,
+            _ => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lab::blood::{bun::BunExt, hemoglobin::SerumHemoglobinExt};
+
+    fn stable(hgb: Hemoglobin<GdL>, sex: SexAtBirth) -> Blatchford {
+        Blatchford::new(
+            5.0.urea_mmol_l(),
+            hgb,
+            sex,
+            BloodPressure::new(128.0, 78.0),
+            82.0,
+        )
+    }
+
+    #[test]
+    fn score_is_none_until_calculated() {
+        let gbs = stable(13.5.hgb_g_dl(), SexAtBirth::Male);
+        assert!(gbs.score().is_none());
+        assert!(gbs.needs_intervention().is_none());
+    }
+
+    #[test]
+    fn normal_findings_score_zero_and_are_safe_for_outpatient_care() {
+        let gbs = stable(13.5.hgb_g_dl(), SexAtBirth::Male).calculate();
+        assert_eq!(Some(0), gbs.score());
+        assert_eq!(Some(false), gbs.needs_intervention());
+    }
+
+    #[test]
+    fn hemoglobin_cutoffs_depend_on_sex() {
+        let male = stable(12.5.hgb_g_dl(), SexAtBirth::Male).calculate();
+        let female = stable(12.5.hgb_g_dl(), SexAtBirth::Female).calculate();
+        assert_eq!(Some(1), male.score());
+        assert_eq!(Some(0), female.score());
+    }
+
+    #[test]
+    fn unstable_bleed_scores_high() {
+        // BUN 80 mg/dL ≈ 28.6 mmol/L urea; Hgb 82 g/L = 8.2 g/dL
+        let gbs = Blatchford::new(
+            80.0.bun_mg_dl(),
+            82.0.hgb_g_l(),
+            SexAtBirth::Female,
+            BloodPressure::new(86.0, 50.0),
+            118.0,
+        )
+        .melena()
+        .syncope()
+        .hepatic_disease()
+        .cardiac_failure()
+        .calculate();
+        assert_eq!(Some(23), gbs.score());
+        assert_eq!(Some(true), gbs.needs_intervention());
+    }
+}
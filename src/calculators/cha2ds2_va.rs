@@ -67,11 +67,10 @@ impl Cha2Ds2VA {
             a if a.0 >= 65.0 => 1,
             _ => 0,
         };
-        tally = tally
-            + [self.htn, self.chf, self.vasc, self.diabetes]
-                .iter()
-                .filter(|&p| *p)
-                .count() as u8;
+        tally += [self.htn, self.chf, self.vasc, self.diabetes]
+            .iter()
+            .filter(|&p| *p)
+            .count() as u8;
         if self.stroke {
             tally += 2;
         }
@@ -82,18 +81,12 @@ impl Cha2Ds2VA {
         self.score
     }
     pub fn annual_cva_risk_no_oac(&self) -> Option<f64> {
-        if let Some(score) = self.score() {
-            Some(ANNUAL_STROKE_PCT_WO_AND_WITH_AC[score as usize].0)
-        } else {
-            None
-        }
+        self.score()
+            .map(|score| ANNUAL_STROKE_PCT_WO_AND_WITH_AC[score as usize].0)
     }
     pub fn annual_cva_risk_with_oac(&self) -> Option<f64> {
-        if let Some(score) = self.score() {
-            Some(ANNUAL_STROKE_PCT_WO_AND_WITH_AC[score as usize].1)
-        } else {
-            None
-        }
+        self.score()
+            .map(|score| ANNUAL_STROKE_PCT_WO_AND_WITH_AC[score as usize].1)
     }
 }
 impl From<cha2ds2_vasc::ChadsVasc> for Cha2Ds2VA {
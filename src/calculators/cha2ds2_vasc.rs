@@ -4,7 +4,7 @@
 //! CHADS-VA does not.
 //!
 
-use crate::history::{Gender, Years};
+use crate::history::{SexAtBirth, Years};
 
 /// CHA₂DS₂-VASc to annual stroke risk table from Friberg (2012)
 const ANNUAL_CVA_RISK_TABLE: [f64; 10] = [0.2, 0.6, 2.2, 3.2, 4.8, 7.2, 9.7, 11.2, 10.8, 12.2];
@@ -13,7 +13,7 @@ const ANNUAL_CVA_RISK_TABLE: [f64; 10] = [0.2, 0.6, 2.2, 3.2, 4.8, 7.2, 9.7, 11.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct ChadsVasc {
     age: Years,
-    sex: Gender,
+    sex: SexAtBirth,
     chf: bool,
     diabetes: bool,
     htn: bool,
@@ -22,7 +22,7 @@ pub struct ChadsVasc {
     score: Option<u8>,
 }
 impl ChadsVasc /* builder / setters */ {
-    pub fn new(age: Years, sex: Gender) -> Self {
+    pub fn new(age: Years, sex: SexAtBirth) -> Self {
         Self {
             age,
             sex,
@@ -60,7 +60,7 @@ impl ChadsVasc /* getters */ {
     pub fn age(&self) -> Years {
         self.age
     }
-    pub fn gender(&self) -> Gender {
+    pub fn gender(&self) -> SexAtBirth {
         self.sex
     }
     pub fn chf(&self) -> bool {
@@ -91,7 +91,7 @@ impl ChadsVasc /* calculations */ {
             age if age >= 65.0 => 1,
             _ => 0,
         };
-        tally += if self.sex == Gender::Female { 1 } else { 0 };
+        tally += if self.sex == SexAtBirth::Female { 1 } else { 0 };
         tally += [self.chf, self.diabetes, self.htn, self.vasc]
             .iter()
             .filter(|&rf| *rf)
@@ -102,11 +102,8 @@ impl ChadsVasc /* calculations */ {
     }
 
     pub fn annual_stroke_risk_pct(&self) -> Option<f64> {
-        if let Some(score) = self.score {
-            Some(ANNUAL_CVA_RISK_TABLE[score as usize])
-        } else {
-            None
-        }
+        self.score
+            .map(|score| ANNUAL_CVA_RISK_TABLE[score as usize])
     }
 }
 
@@ -114,38 +111,38 @@ impl ChadsVasc /* calculations */ {
 mod tests {
     use crate::{
         calculators::cha2ds2_vasc::ChadsVasc,
-        history::{Gender, Years},
+        history::{SexAtBirth, Years},
     };
 
     #[test]
     fn score_is_none_until_calculate_is_run() {
-        let chads_vasc = ChadsVasc::new(Years(50.0), Gender::Male);
+        let chads_vasc = ChadsVasc::new(Years(50.0), SexAtBirth::Male);
         assert!(chads_vasc.score().is_none());
         assert!(chads_vasc.calculate().score().is_some());
     }
 
     #[test]
     fn stroke_risk_pct_returns_none_until_calculate_run() {
-        let chads_vasc = ChadsVasc::new(Years(50.0), Gender::Male);
+        let chads_vasc = ChadsVasc::new(Years(50.0), SexAtBirth::Male);
         assert!(chads_vasc.annual_stroke_risk_pct().is_none());
         assert!(chads_vasc.calculate().annual_stroke_risk_pct().is_some());
     }
 
     #[test]
     fn healthy_male_under_65_scores_zero() {
-        let chads_vasc = ChadsVasc::new(Years(64.0), Gender::Male).calculate();
+        let chads_vasc = ChadsVasc::new(Years(64.0), SexAtBirth::Male).calculate();
         assert_eq!(Some(0), chads_vasc.score());
     }
 
     #[test]
     fn healthy_female_under_65_scores_one() {
-        let chads_vasc = ChadsVasc::new(Years(64.9), Gender::Female).calculate();
+        let chads_vasc = ChadsVasc::new(Years(64.9), SexAtBirth::Female).calculate();
         assert_eq!(Some(1), chads_vasc.score());
     }
 
     #[test]
     fn maximum_risk_factors_scores_nine() {
-        let chads_vasc = ChadsVasc::new(Years(75.0), Gender::Female)
+        let chads_vasc = ChadsVasc::new(Years(75.0), SexAtBirth::Female)
             .has_chf()
             .has_diabetes()
             .has_htn()
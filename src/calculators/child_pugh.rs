@@ -0,0 +1,168 @@
+//! Child-Pugh Score
+//!
+//! Severity of chronic liver disease (cirrhosis), from bilirubin, albumin, INR,
+//! ascites and hepatic encephalopathy. Each component scores 1-3 points.
+//!
+
+use crate::{
+    constants::SBILI_UMOLL_TO_MGDL,
+    lab::blood::{albumin::Albumin, bilirubin::Bilirubin, inr::Inr},
+    units::{albumin::AlbuminUnit, bilirubin::BilirubinUnit, GdL, Unit},
+};
+
+/// Clinical severity of ascites.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ascites {
+    Absent,
+    /// Slight, or controlled with diuretics
+    Slight,
+    /// Moderate to severe, or refractory to diuretics
+    Moderate,
+}
+
+/// Grade of hepatic encephalopathy (West Haven).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encephalopathy {
+    Absent,
+    /// Grade 1-2, or controlled with medication
+    Grade1To2,
+    /// Grade 3-4, or refractory
+    Grade3To4,
+}
+
+/// Child-Pugh class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChildPughClass {
+    /// Score 5-6: well-compensated disease
+    A,
+    /// Score 7-9: significant functional compromise
+    B,
+    /// Score 10-15: decompensated disease
+    C,
+}
+
+/// A Child-Pugh calculator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChildPugh {
+    bili_mg_dl: f64,
+    alb_g_dl: f64,
+    inr: f64,
+    ascites: Ascites,
+    encephalopathy: Encephalopathy,
+    score: Option<u8>,
+}
+impl ChildPugh /* builder / setters */ {
+    /// Lab values may be given in either unit system; they're converted to mg/dL and
+    /// g/dL for scoring. Ascites and encephalopathy default to absent.
+    pub fn new<B, A, I>(bili: Bilirubin<B>, albumin: Albumin<A>, inr: Inr<I>) -> Self
+    where
+        B: BilirubinUnit,
+        A: AlbuminUnit,
+        I: Unit,
+    {
+        Self {
+            bili_mg_dl: B::to_umoll(bili.value()) * SBILI_UMOLL_TO_MGDL,
+            alb_g_dl: GdL::from_g_l(A::to_g_l(albumin.value())),
+            inr: inr.value(),
+            ascites: Ascites::Absent,
+            encephalopathy: Encephalopathy::Absent,
+            score: None,
+        }
+    }
+    pub fn ascites(mut self, ascites: Ascites) -> Self {
+        self.ascites = ascites;
+        self
+    }
+    pub fn encephalopathy(mut self, encephalopathy: Encephalopathy) -> Self {
+        self.encephalopathy = encephalopathy;
+        self
+    }
+}
+
+impl ChildPugh /* getters */ {
+    pub fn score(&self) -> Option<u8> {
+        self.score
+    }
+}
+
+impl ChildPugh /* calculations */ {
+    #[must_use]
+    pub fn calculate(mut self) -> Self {
+        let bili = match self.bili_mg_dl {
+            val if val < 2.0 => 1,
+            val if val <= 3.0 => 2,
+            _ => 3,
+        };
+        let albumin = match self.alb_g_dl {
+            val if val > 3.5 => 1,
+            val if val >= 2.8 => 2,
+            _ => 3,
+        };
+        let inr = match self.inr {
+            val if val < 1.7 => 1,
+            val if val <= 2.3 => 2,
+            _ => 3,
+        };
+        let ascites = match self.ascites {
+            Ascites::Absent => 1,
+            Ascites::Slight => 2,
+            Ascites::Moderate => 3,
+        };
+        let encephalopathy = match self.encephalopathy {
+            Encephalopathy::Absent => 1,
+            Encephalopathy::Grade1To2 => 2,
+            Encephalopathy::Grade3To4 => 3,
+        };
+        self.score = Some(bili + albumin + inr + ascites + encephalopathy);
+        self
+    }
+
+    pub fn class(&self) -> Option<ChildPughClass> {
+        self.score.map(|score| match score {
+            0..=6 => ChildPughClass::A,
+            7..=9 => ChildPughClass::B,
+            _ => ChildPughClass::C,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lab::blood::{albumin::SerumAlbuminExt, bilirubin::BilirubinExt, inr::InrExt};
+
+    #[test]
+    fn score_and_class_are_none_until_calculated() {
+        let child_pugh = ChildPugh::new(1.0.serum_bili_mgdl(), 4.0.alb_serum_g_dl(), 1.0.inr());
+        assert!(child_pugh.score().is_none());
+        assert!(child_pugh.class().is_none());
+    }
+
+    #[test]
+    fn compensated_cirrhosis_is_class_a() {
+        let child_pugh =
+            ChildPugh::new(1.2.serum_bili_mgdl(), 3.8.alb_serum_g_dl(), 1.2.inr()).calculate();
+        assert_eq!(Some(5), child_pugh.score());
+        assert_eq!(Some(ChildPughClass::A), child_pugh.class());
+    }
+
+    #[test]
+    fn decompensated_cirrhosis_in_si_units_is_class_c() {
+        // 85.5 µmol/L = 5.0 mg/dL bilirubin; 25 g/L = 2.5 g/dL albumin
+        let child_pugh = ChildPugh::new(85.5.serum_bili_umoll(), 25.0.alb_serum_g_l(), 2.6.inr())
+            .ascites(Ascites::Moderate)
+            .encephalopathy(Encephalopathy::Grade1To2)
+            .calculate();
+        assert_eq!(Some(14), child_pugh.score());
+        assert_eq!(Some(ChildPughClass::C), child_pugh.class());
+    }
+
+    #[test]
+    fn intermediate_values_are_class_b() {
+        let child_pugh = ChildPugh::new(2.5.serum_bili_mgdl(), 3.0.alb_serum_g_dl(), 1.5.inr())
+            .ascites(Ascites::Slight)
+            .calculate();
+        assert_eq!(Some(8), child_pugh.score());
+        assert_eq!(Some(ChildPughClass::B), child_pugh.class());
+    }
+}
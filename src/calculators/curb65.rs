@@ -0,0 +1,181 @@
+//! CURB-65 Score
+//!
+//! Severity of community-acquired pneumonia, used to decide the site of care:
+//! Confusion, Urea (BUN), Respiratory rate, Blood pressure, age ≥ 65. One point
+//! each.
+//!
+
+use crate::{
+    history::Years,
+    lab::{
+        blood::bun::Bun,
+        vitals::{blood_pressure::BloodPressure, RespiratoryRate},
+    },
+    units::{bun::BunUnit, PerMinute},
+};
+
+/// Suggested site of care for a CURB-65 score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CareSetting {
+    /// Score 0-1: ~1.5% 30-day mortality.
+    Outpatient,
+    /// Score 2: ~9% mortality; short admission or closely supervised outpatient care.
+    Ward,
+    /// Score 3-5: ~22% mortality; admit, and assess for ICU at 4-5.
+    Icu,
+}
+
+/// A CURB-65 calculator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Curb65 {
+    age: Years,
+    urea_mmol_l: f64,
+    resp_rate: f64,
+    bp: BloodPressure,
+    confusion: bool,
+    score: Option<u8>,
+}
+impl Curb65 /* builder / setters */ {
+    pub fn new<B: BunUnit>(
+        age: Years,
+        bun: Bun<B>,
+        resp_rate: RespiratoryRate<PerMinute>,
+        bp: BloodPressure,
+    ) -> Self {
+        Self {
+            age,
+            urea_mmol_l: B::to_urea_mmol_l(bun.value()),
+            resp_rate: resp_rate.value(),
+            bp,
+            confusion: false,
+            score: None,
+        }
+    }
+    /// New disorientation to person, place or time.
+    pub fn has_confusion(mut self) -> Self {
+        self.confusion = true;
+        self
+    }
+}
+
+impl Curb65 /* getters */ {
+    pub fn age(&self) -> Years {
+        self.age
+    }
+    /// Urea > 7 mmol/L, the published criterion. BUN in mg/dL is converted to urea
+    /// first, so the cutoff is BUN > 19.6 mg/dL (often rounded to 19 or 20).
+    pub fn urea_criterion(&self) -> bool {
+        self.urea_mmol_l > 7.0
+    }
+    pub fn respiratory_criterion(&self) -> bool {
+        self.resp_rate >= 30.0
+    }
+    /// Systolic < 90 mmHg or diastolic ≤ 60 mmHg.
+    pub fn blood_pressure_criterion(&self) -> bool {
+        self.bp.systolic() < 90.0 || self.bp.diastolic() <= 60.0
+    }
+    pub fn score(&self) -> Option<u8> {
+        self.score
+    }
+}
+
+impl Curb65 /* calculations */ {
+    #[must_use]
+    pub fn calculate(mut self) -> Self {
+        let tally = [
+            self.confusion,
+            self.urea_criterion(),
+            self.respiratory_criterion(),
+            self.blood_pressure_criterion(),
+            self.age.0 >= 65.0,
+        ]
+        .iter()
+        .filter(|&c| *c)
+        .count() as u8;
+        self.score = Some(tally);
+        self
+    }
+
+    pub fn recommended_setting(&self) -> Option<CareSetting> {
+        self.score.map(|score| match score {
+            0..=1 => CareSetting::Outpatient,
+            2 => CareSetting::Ward,
+            _ => CareSetting::Icu,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lab::{blood::bun::BunExt, vitals::RespiratoryRateExt};
+
+    #[test]
+    fn score_is_none_until_calculated() {
+        let curb = Curb65::new(
+            Years(40.0),
+            12.0.bun_mg_dl(),
+            16.0.breaths_per_min(),
+            BloodPressure::new(120.0, 80.0),
+        );
+        assert!(curb.score().is_none());
+        assert!(curb.recommended_setting().is_none());
+    }
+
+    #[test]
+    fn urea_of_exactly_seven_mmol_l_does_not_score() {
+        fn at_cutoff<B: BunUnit>(bun: Bun<B>) -> Curb65 {
+            Curb65::new(
+                Years(40.0),
+                bun,
+                16.0.breaths_per_min(),
+                BloodPressure::new(120.0, 80.0),
+            )
+        }
+        assert!(!at_cutoff(7.0.urea_mmol_l()).urea_criterion());
+        assert!(!at_cutoff(6.9.urea_mmol_l()).urea_criterion());
+        assert!(at_cutoff(7.1.urea_mmol_l()).urea_criterion());
+        assert!(!at_cutoff(19.5.bun_mg_dl()).urea_criterion());
+        assert!(at_cutoff(20.0.bun_mg_dl()).urea_criterion());
+    }
+
+    #[test]
+    fn low_risk_patient_is_treated_as_outpatient() {
+        let curb = Curb65::new(
+            Years(70.0),
+            5.0.urea_mmol_l(),
+            18.0.breaths_per_min(),
+            BloodPressure::new(132.0, 76.0),
+        )
+        .calculate();
+        assert_eq!(Some(1), curb.score());
+        assert_eq!(Some(CareSetting::Outpatient), curb.recommended_setting());
+    }
+
+    #[test]
+    fn high_risk_patient_goes_to_icu() {
+        let curb = Curb65::new(
+            Years(78.0),
+            9.0.urea_mmol_l(),
+            32.0.breaths_per_min(),
+            BloodPressure::new(96.0, 58.0),
+        )
+        .has_confusion()
+        .calculate();
+        assert_eq!(Some(5), curb.score());
+        assert_eq!(Some(CareSetting::Icu), curb.recommended_setting());
+    }
+
+    #[test]
+    fn score_of_two_is_ward_care() {
+        let curb = Curb65::new(
+            Years(66.0),
+            24.0.bun_mg_dl(),
+            20.0.breaths_per_min(),
+            BloodPressure::new(118.0, 70.0),
+        )
+        .calculate();
+        assert_eq!(Some(2), curb.score());
+        assert_eq!(Some(CareSetting::Ward), curb.recommended_setting());
+    }
+}
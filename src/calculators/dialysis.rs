@@ -0,0 +1,95 @@
+//! Dialysis module
+//!
+//! Hemodialysis adequacy and nutrition markers computed from pre- and post-dialysis
+//! BUN. Both equations assume a thrice-weekly schedule with the values drawn at the
+//! midweek session.
+
+use crate::{
+    lab::{blood::bun::Bun, vitals::Weight},
+    units::{bun::BunUnit, vitals::WeightUnit, MgdL},
+};
+
+/// Single-pool Kt/V by the second-generation Daugirdas equation.
+///
+/// Kt/V = -ln(R - 0.008 × t) + (4 - 3.5 × R) × UF / W, where R is post/pre BUN, t is
+/// the session length in hours, UF the ultrafiltration volume in liters and W the
+/// post-dialysis weight in kg.
+pub fn kt_v_daugirdas<A, B, W>(
+    pre: Bun<A>,
+    post: Bun<B>,
+    session_hours: f64,
+    ultrafiltration_l: f64,
+    post_weight: Weight<W>,
+) -> f64
+where
+    A: BunUnit,
+    B: BunUnit,
+    W: WeightUnit,
+{
+    let pre_mg_dl = MgdL::from_urea_mmol_l(A::to_urea_mmol_l(pre.value()));
+    let post_mg_dl = MgdL::from_urea_mmol_l(B::to_urea_mmol_l(post.value()));
+    let wt_kg = W::to_kg(post_weight.value());
+
+    let ratio = post_mg_dl / pre_mg_dl;
+    -(ratio - 0.008 * session_hours).ln() + (4.0 - 3.5 * ratio) * ultrafiltration_l / wt_kg
+}
+
+/// Normalized protein catabolic rate (nPCR) in g/kg/day, by the Depner-Daugirdas
+/// equation for a midweek session.
+///
+/// nPCR = C₀ / (36.3 + 5.48 × Kt/V + 53.5 / (Kt/V)) + 0.168, where C₀ is the
+/// pre-dialysis BUN in mg/dL. Post-dialysis BUN and weight enter through `kt_v`
+/// (see `kt_v_daugirdas`). An nPCR of 1.0-1.2 g/kg/day is the usual target in ESRD.
+pub fn normalized_protein_catabolic_rate<A: BunUnit>(pre: Bun<A>, kt_v: f64) -> f64 {
+    let pre_mg_dl = MgdL::from_urea_mmol_l(A::to_urea_mmol_l(pre.value()));
+    pre_mg_dl / (36.3 + 5.48 * kt_v + 53.5 / kt_v) + 0.168
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lab::{blood::bun::BunExt, vitals::WeightExt};
+
+    fn approx_eq(lhs: f64, rhs: f64) {
+        assert!((lhs - rhs).abs() < 1e-3, "{} !~= {}", lhs, rhs);
+    }
+
+    #[test]
+    fn kt_v_for_a_typical_session() {
+        // R = 1/3, 4 h, 3 L off, 70 kg: -ln(0.3013) + 2.833 * 3 / 70 ≈ 1.321
+        let kt_v = kt_v_daugirdas(
+            60.0.bun_mg_dl(),
+            20.0.bun_mg_dl(),
+            4.0,
+            3.0,
+            70.0.weight_kg(),
+        );
+        approx_eq(kt_v, 1.321);
+    }
+
+    #[test]
+    fn kt_v_converts_urea_and_weight() {
+        let conventional = kt_v_daugirdas(
+            60.0.bun_mg_dl(),
+            20.0.bun_mg_dl(),
+            4.0,
+            3.0,
+            70.0.weight_kg(),
+        );
+        let si = kt_v_daugirdas(
+            (60.0 * crate::constants::BUN_MGDL_TO_UREA_MMOLL).urea_mmol_l(),
+            (20.0 * crate::constants::BUN_MGDL_TO_UREA_MMOLL).urea_mmol_l(),
+            4.0,
+            3.0,
+            (70.0 / crate::constants::LB_TO_KG).weight_lb(),
+        );
+        approx_eq(conventional, si);
+    }
+
+    #[test]
+    fn npcr_worked_example() {
+        // pre-BUN 70 mg/dL, Kt/V 1.2: 70 / (36.3 + 6.576 + 44.583) + 0.168 ≈ 0.968
+        let npcr = normalized_protein_catabolic_rate(70.0.bun_mg_dl(), 1.2);
+        approx_eq(npcr, 0.968);
+    }
+}
@@ -0,0 +1,203 @@
+//! Dosing module
+//!
+//! Infusion and dose calculations, mostly weight-based.
+
+use std::marker::PhantomData;
+
+use crate::{
+    lab::{blood::glucose::Glucose, vitals::Weight},
+    units::{glucose::GlucoseUnit, vitals::WeightUnit, MgdL, MlHr, Unit},
+};
+
+/// An infusion rate, e.g. for IV fluids.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FluidRate<U: Unit> {
+    value: f64,
+    _unit: PhantomData<U>,
+}
+#[cfg(feature = "serde")]
+crate::serialize::impl_serde_measurement!(FluidRate, _unit);
+crate::ops::impl_measurement_ops!(FluidRate, _unit);
+impl<U: Unit> FluidRate<U> {
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+impl<U: Unit> std::fmt::Display for FluidRate<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.0} {}", self.value, U::ABBR)
+    }
+}
+impl From<f64> for FluidRate<MlHr> {
+    fn from(value: f64) -> Self {
+        FluidRate {
+            value,
+            _unit: PhantomData,
+        }
+    }
+}
+
+/// Glucose infusion rate (GIR) in mg/kg/min.
+///
+/// GIR = (dextrose % × rate mL/hr × 10) / (60 × weight kg), which simplifies to
+/// `% × rate / (6 × kg)`. Typical neonatal targets are 4–8 mg/kg/min.
+pub fn glucose_infusion_rate<W: WeightUnit>(
+    dextrose_pct: f64,
+    rate_ml_hr: f64,
+    weight: Weight<W>,
+) -> f64 {
+    let wt_kg = W::to_kg(weight.value());
+    dextrose_pct * rate_ml_hr / (6.0 * wt_kg)
+}
+
+/// Holliday-Segar maintenance IV fluid rate by the 4-2-1 rule.
+///
+/// 4 mL/kg/hr for the first 10 kg, 2 mL/kg/hr for the next 10 kg, and 1 mL/kg/hr
+/// for each kg above 20.
+pub fn maintenance_fluid_rate<W: WeightUnit>(weight: Weight<W>) -> FluidRate<MlHr> {
+    let wt_kg = W::to_kg(weight.value());
+    let rate = 4.0 * wt_kg.min(10.0)
+        + 2.0 * (wt_kg - 10.0).clamp(0.0, 10.0)
+        + 1.0 * (wt_kg - 20.0).max(0.0);
+    FluidRate::from(rate)
+}
+
+/// Holliday-Segar maintenance fluid volume in mL/day by the 100-50-20 rule.
+///
+/// 100 mL/kg/day for the first 10 kg, 50 mL/kg/day for the next 10 kg, and 20
+/// mL/kg/day for each kg above 20. Slightly less than 24 × the 4-2-1 hourly rate
+/// above 20 kg.
+pub fn daily_maintenance_volume<W: WeightUnit>(weight: Weight<W>) -> f64 {
+    let wt_kg = W::to_kg(weight.value());
+    100.0 * wt_kg.min(10.0)
+        + 50.0 * (wt_kg - 10.0).clamp(0.0, 10.0)
+        + 20.0 * (wt_kg - 20.0).max(0.0)
+}
+
+/// Insulin units to cover a meal: grams of carbohydrate / insulin-to-carb ratio
+/// (grams covered per unit).
+pub fn carb_coverage_dose(carbs_g: f64, carb_ratio_g_per_unit: f64) -> f64 {
+    carbs_g / carb_ratio_g_per_unit
+}
+
+/// Insulin units to bring glucose down to `target`: (current - target) / correction
+/// factor, where the correction factor is the mg/dL drop per unit. Negative when
+/// glucose is below target; `meal_bolus` floors it at zero.
+pub fn correction_dose<G, T>(
+    current: Glucose<G>,
+    target: Glucose<T>,
+    correction_factor_mg_dl: f64,
+) -> f64
+where
+    G: GlucoseUnit,
+    T: GlucoseUnit,
+{
+    let current_mg_dl = MgdL::from_mmol_l(G::to_mmol_l(current.value()));
+    let target_mg_dl = MgdL::from_mmol_l(T::to_mmol_l(target.value()));
+    (current_mg_dl - target_mg_dl) / correction_factor_mg_dl
+}
+
+/// Pre-meal insulin bolus in units: carb coverage plus correction.
+///
+/// A glucose below target doesn't reduce the carb dose; the correction is floored
+/// at zero.
+pub fn meal_bolus<G, T>(
+    carbs_g: f64,
+    current: Glucose<G>,
+    target: Glucose<T>,
+    carb_ratio_g_per_unit: f64,
+    correction_factor_mg_dl: f64,
+) -> f64
+where
+    G: GlucoseUnit,
+    T: GlucoseUnit,
+{
+    carb_coverage_dose(carbs_g, carb_ratio_g_per_unit)
+        + correction_dose(current, target, correction_factor_mg_dl).max(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lab::{blood::glucose::SerumGlucoseExt, vitals::WeightExt};
+
+    fn approx_eq(lhs: f64, rhs: f64) {
+        assert!((lhs - rhs).abs() < 1e-9, "{} !~= {}", lhs, rhs);
+    }
+
+    #[test]
+    fn gir_for_d10_at_maintenance_in_a_neonate() {
+        // D10 at 10 mL/hr for a 3 kg neonate: 10 * 10 / 18 ≈ 5.6 mg/kg/min
+        let gir = glucose_infusion_rate(10.0, 10.0, 3.0.weight_kg());
+        approx_eq(gir, 100.0 / 18.0);
+        assert!((4.0..=8.0).contains(&gir));
+    }
+
+    #[test]
+    fn gir_converts_weight_units() {
+        let kg = glucose_infusion_rate(10.0, 12.0, 3.5.weight_kg());
+        let lb = glucose_infusion_rate(10.0, 12.0, (3.5 / crate::constants::LB_TO_KG).weight_lb());
+        approx_eq(kg, lb);
+    }
+
+    #[test]
+    fn maintenance_rate_for_25_kg_child() {
+        // 40 + 20 + 5 = 65 mL/hr
+        approx_eq(maintenance_fluid_rate(25.0.weight_kg()).value(), 65.0);
+    }
+
+    #[test]
+    fn maintenance_rate_for_70_kg_adult() {
+        // 40 + 20 + 50 = 110 mL/hr
+        let rate = maintenance_fluid_rate(70.0.weight_kg());
+        approx_eq(rate.value(), 110.0);
+        assert_eq!(format!("{}", rate), "110 mL/hr");
+    }
+
+    #[test]
+    fn maintenance_rate_under_10_kg_and_in_lb() {
+        approx_eq(maintenance_fluid_rate(8.0.weight_kg()).value(), 32.0);
+        let lb = maintenance_fluid_rate((25.0 / crate::constants::LB_TO_KG).weight_lb());
+        approx_eq(lb.value(), 65.0);
+    }
+
+    #[test]
+    fn daily_maintenance_volume_100_50_20() {
+        approx_eq(daily_maintenance_volume(8.0.weight_kg()), 800.0);
+        approx_eq(daily_maintenance_volume(25.0.weight_kg()), 1600.0);
+        approx_eq(daily_maintenance_volume(70.0.weight_kg()), 2500.0);
+    }
+
+    #[test]
+    fn meal_bolus_adds_correction_when_high() {
+        // 60 g at 1:10 = 6 units; (250 - 100) / 50 = 3 units
+        let bolus = meal_bolus(
+            60.0,
+            250.0.glu_serum_mg_dl(),
+            100.0.glu_serum_mg_dl(),
+            10.0,
+            50.0,
+        );
+        approx_eq(bolus, 9.0);
+    }
+
+    #[test]
+    fn meal_bolus_floors_negative_correction() {
+        let current = 70.0.glu_serum_mg_dl();
+        let target = 120.0.glu_serum_mg_dl();
+        assert!(correction_dose(current, target, 50.0) < 0.0);
+        approx_eq(meal_bolus(45.0, current, target, 15.0, 50.0), 3.0);
+    }
+
+    #[test]
+    fn meal_bolus_converts_glucose_to_mg_dl() {
+        let mmol = meal_bolus(
+            60.0,
+            (250.0 / 18.0).glu_serum_mmol_l(),
+            100.0.glu_serum_mg_dl(),
+            10.0,
+            50.0,
+        );
+        approx_eq(mmol, 9.0);
+    }
+}
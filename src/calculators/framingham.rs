@@ -0,0 +1,359 @@
+//! Framingham General Cardiovascular Risk
+//!
+//! The 2008 Framingham general CVD profile (D'Agostino et al.) estimates the 10-year
+//! risk of any cardiovascular event: coronary death, MI, coronary insufficiency,
+//! angina, stroke, TIA, claudication or heart failure. This uses the published
+//! sex-specific point tables: points for age, HDL, total cholesterol, systolic BP
+//! (treated or untreated), smoking and diabetes are summed and looked up in the
+//! matching risk table.
+//!
+//! Derived in patients aged 30-74 without CVD at baseline.
+//!
+
+use crate::{
+    history::{SexAtBirth, Years},
+    lab::{
+        blood::cholesterol::{Cholesterol, Hdl},
+        vitals::blood_pressure::BloodPressure,
+    },
+    units::{cholesterol::CholesterolUnit, MgdL},
+};
+
+/// Points for each risk factor, by band. Each band list pairs an exclusive upper
+/// bound with the points for values below it; the last entry covers everything
+/// above the previous bound.
+struct FraminghamPoints {
+    age: &'static [(f64, i32)],
+    hdl_mg_dl: &'static [(f64, i32)],
+    total_chol_mg_dl: &'static [(f64, i32)],
+    untreated_sbp: &'static [(f64, i32)],
+    treated_sbp: &'static [(f64, i32)],
+    smoker: i32,
+    diabetes: i32,
+    /// Point total for the first entry of `risk_pct`
+    risk_first_points: i32,
+    /// 10-year risk (%) for each point total from `risk_first_points` upward
+    risk_pct: &'static [f64],
+}
+
+const FEMALE: FraminghamPoints = FraminghamPoints {
+    age: &[
+        (35.0, 0),
+        (40.0, 2),
+        (45.0, 4),
+        (50.0, 5),
+        (55.0, 7),
+        (60.0, 8),
+        (65.0, 9),
+        (70.0, 10),
+        (75.0, 11),
+        (f64::INFINITY, 12),
+    ],
+    hdl_mg_dl: &[
+        (35.0, 2),
+        (45.0, 1),
+        (50.0, 0),
+        (60.0, -1),
+        (f64::INFINITY, -2),
+    ],
+    total_chol_mg_dl: &[
+        (160.0, 0),
+        (200.0, 1),
+        (240.0, 3),
+        (280.0, 4),
+        (f64::INFINITY, 5),
+    ],
+    untreated_sbp: &[
+        (120.0, -3),
+        (130.0, 0),
+        (140.0, 1),
+        (150.0, 2),
+        (160.0, 3),
+        (f64::INFINITY, 4),
+    ],
+    treated_sbp: &[
+        (120.0, -1),
+        (130.0, 2),
+        (140.0, 3),
+        (150.0, 4),
+        (160.0, 5),
+        (f64::INFINITY, 6),
+    ],
+    smoker: 3,
+    diabetes: 4,
+    risk_first_points: -1,
+    risk_pct: &[
+        1.0, 1.2, 1.5, 1.7, 2.0, 2.4, 2.8, 3.3, 3.9, 4.5, 5.3, 6.3, 7.3, 8.6, 10.0, 11.7, 13.7,
+        15.9, 18.5, 21.5, 24.8, 28.5,
+    ],
+};
+
+const MALE: FraminghamPoints = FraminghamPoints {
+    age: &[
+        (35.0, 0),
+        (40.0, 2),
+        (45.0, 5),
+        (50.0, 6),
+        (55.0, 8),
+        (60.0, 10),
+        (65.0, 11),
+        (70.0, 12),
+        (75.0, 14),
+        (f64::INFINITY, 15),
+    ],
+    hdl_mg_dl: &[
+        (35.0, 2),
+        (45.0, 1),
+        (50.0, 0),
+        (60.0, -1),
+        (f64::INFINITY, -2),
+    ],
+    total_chol_mg_dl: &[
+        (160.0, 0),
+        (200.0, 1),
+        (240.0, 2),
+        (280.0, 3),
+        (f64::INFINITY, 4),
+    ],
+    untreated_sbp: &[
+        (120.0, -2),
+        (130.0, 0),
+        (140.0, 1),
+        (160.0, 2),
+        (f64::INFINITY, 3),
+    ],
+    treated_sbp: &[
+        (120.0, 0),
+        (130.0, 2),
+        (140.0, 3),
+        (160.0, 4),
+        (f64::INFINITY, 5),
+    ],
+    smoker: 4,
+    diabetes: 3,
+    risk_first_points: -2,
+    risk_pct: &[
+        1.1, 1.4, 1.6, 1.9, 2.3, 2.8, 3.3, 3.9, 4.7, 5.6, 6.7, 7.9, 9.4, 11.2, 13.2, 15.6, 18.4,
+        21.6, 25.3, 29.4,
+    ],
+};
+
+/// Points for `value` from a list of `(exclusive upper bound, points)` bands.
+fn band_points(value: f64, bands: &[(f64, i32)]) -> i32 {
+    bands
+        .iter()
+        .find(|(upper, _)| value < *upper)
+        .map_or(0, |(_, points)| *points)
+}
+
+/// A Framingham 10-year general CVD risk calculator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FraminghamRisk {
+    age: Years,
+    sex: SexAtBirth,
+    total_chol_mg_dl: f64,
+    hdl_mg_dl: f64,
+    systolic: f64,
+    bp_treated: bool,
+    smoker: bool,
+    diabetes: bool,
+    points: Option<i32>,
+    risk: Option<f64>,
+}
+impl FraminghamRisk /* builder / setters */ {
+    /// Lipids may be in mg/dL or mmol/L; only the systolic pressure is used.
+    pub fn new<T, H>(
+        age: Years,
+        sex: SexAtBirth,
+        total_chol: Cholesterol<T>,
+        hdl: Hdl<H>,
+        bp: BloodPressure,
+    ) -> Self
+    where
+        T: CholesterolUnit,
+        H: CholesterolUnit,
+    {
+        Self {
+            age,
+            sex,
+            total_chol_mg_dl: MgdL::from_cholesterol_mmol_l(T::to_cholesterol_mmol_l(
+                total_chol.value(),
+            )),
+            hdl_mg_dl: MgdL::from_cholesterol_mmol_l(H::to_cholesterol_mmol_l(hdl.value())),
+            systolic: bp.systolic(),
+            bp_treated: false,
+            smoker: false,
+            diabetes: false,
+            points: None,
+            risk: None,
+        }
+    }
+    /// On treatment for hypertension.
+    pub fn bp_treated(mut self) -> Self {
+        self.bp_treated = true;
+        self
+    }
+    /// Current smoker.
+    pub fn smoker(mut self) -> Self {
+        self.smoker = true;
+        self
+    }
+    pub fn diabetes(mut self) -> Self {
+        self.diabetes = true;
+        self
+    }
+}
+
+impl FraminghamRisk /* getters */ {
+    /// Total points from the sex-specific table.
+    pub fn points(&self) -> Option<i32> {
+        self.points
+    }
+    /// 10-year CVD risk as a percentage. The tables report totals beyond their
+    /// ends only as "<1%" and ">30%"; those are returned as 1.0 and 30.0.
+    pub fn risk_pct(&self) -> Option<f64> {
+        self.risk
+    }
+}
+
+impl FraminghamRisk /* calculations */ {
+    #[must_use]
+    pub fn calculate(mut self) -> Self {
+        let table = match self.sex {
+            SexAtBirth::Female => &FEMALE,
+            SexAtBirth::Male => &MALE,
+        };
+        let sbp_bands = if self.bp_treated {
+            table.treated_sbp
+        } else {
+            table.untreated_sbp
+        };
+        let points = band_points(self.age.0, table.age)
+            + band_points(self.hdl_mg_dl, table.hdl_mg_dl)
+            + band_points(self.total_chol_mg_dl, table.total_chol_mg_dl)
+            + band_points(self.systolic, sbp_bands)
+            + if self.smoker { table.smoker } else { 0 }
+            + if self.diabetes { table.diabetes } else { 0 };
+
+        let index = points - table.risk_first_points;
+        let risk = if index < 0 {
+            1.0
+        } else {
+            table.risk_pct.get(index as usize).copied().unwrap_or(30.0)
+        };
+        self.points = Some(points);
+        self.risk = Some(risk);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lab::blood::cholesterol::{CholesterolExt, HdlExt};
+
+    fn assert_near(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 0.1,
+            "{actual} not within 0.1 of {expected}"
+        );
+    }
+
+    #[test]
+    fn risk_is_none_until_calculated() {
+        let fram = FraminghamRisk::new(
+            Years(50.0),
+            SexAtBirth::Male,
+            200.0.chol_total_mg_dl(),
+            50.0.hdl_mg_dl(),
+            BloodPressure::new(120.0, 80.0),
+        );
+        assert!(fram.risk_pct().is_none());
+    }
+
+    #[test]
+    fn published_female_example() {
+        // D'Agostino 2008: 61-year-old female smoker, untreated SBP 124 → 13 points,
+        // 10.0%
+        let fram = FraminghamRisk::new(
+            Years(61.0),
+            SexAtBirth::Female,
+            180.0.chol_total_mg_dl(),
+            47.0.hdl_mg_dl(),
+            BloodPressure::new(124.0, 80.0),
+        )
+        .smoker()
+        .calculate();
+        assert_eq!(fram.points(), Some(13));
+        assert_near(fram.risk_pct().unwrap(), 10.0);
+    }
+
+    #[test]
+    fn published_male_example() {
+        // D'Agostino 2008: 53-year-old diabetic male, treated SBP 125 → 13 points,
+        // 15.6%
+        let fram = FraminghamRisk::new(
+            Years(53.0),
+            SexAtBirth::Male,
+            161.0.chol_total_mg_dl(),
+            55.0.hdl_mg_dl(),
+            BloodPressure::new(125.0, 80.0),
+        )
+        .bp_treated()
+        .diabetes()
+        .calculate();
+        assert_eq!(fram.points(), Some(13));
+        assert_near(fram.risk_pct().unwrap(), 15.6);
+    }
+
+    #[test]
+    fn lipids_in_mmol_l_give_the_same_risk() {
+        let mg_dl = FraminghamRisk::new(
+            Years(61.0),
+            SexAtBirth::Female,
+            180.0.chol_total_mg_dl(),
+            47.0.hdl_mg_dl(),
+            BloodPressure::new(124.0, 80.0),
+        )
+        .smoker()
+        .calculate();
+        let mmol_l = FraminghamRisk::new(
+            Years(61.0),
+            SexAtBirth::Female,
+            (180.0 * 0.0259).chol_total_mmol_l(),
+            (47.0 * 0.0259).hdl_mmol_l(),
+            BloodPressure::new(124.0, 80.0),
+        )
+        .smoker()
+        .calculate();
+        assert!((mg_dl.risk_pct().unwrap() - mmol_l.risk_pct().unwrap()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn totals_beyond_the_table_are_reported_at_its_bounds() {
+        let low = FraminghamRisk::new(
+            Years(32.0),
+            SexAtBirth::Female,
+            150.0.chol_total_mg_dl(),
+            65.0.hdl_mg_dl(),
+            BloodPressure::new(110.0, 70.0),
+        )
+        .calculate();
+        assert_eq!(low.points(), Some(-5));
+        assert_eq!(low.risk_pct(), Some(1.0));
+
+        let high = FraminghamRisk::new(
+            Years(72.0),
+            SexAtBirth::Male,
+            290.0.chol_total_mg_dl(),
+            30.0.hdl_mg_dl(),
+            BloodPressure::new(170.0, 90.0),
+        )
+        .bp_treated()
+        .smoker()
+        .diabetes()
+        .calculate();
+        assert_eq!(high.points(), Some(32));
+        assert_eq!(high.risk_pct(), Some(30.0));
+    }
+}
@@ -0,0 +1,131 @@
+//! Glasgow Coma Scale
+//!
+//! Level of consciousness from the best eye (1-4), verbal (1-5) and motor (1-6)
+//! responses, for a total of 3-15.
+//!
+
+/// A GCS component that was outside its valid range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GcsError {
+    /// Eye response must be 1-4.
+    Eye(u8),
+    /// Verbal response must be 1-5.
+    Verbal(u8),
+    /// Motor response must be 1-6.
+    Motor(u8),
+}
+impl std::fmt::Display for GcsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GcsError::Eye(value) => write!(f, "eye response {value} is outside 1-4"),
+            GcsError::Verbal(value) => write!(f, "verbal response {value} is outside 1-5"),
+            GcsError::Motor(value) => write!(f, "motor response {value} is outside 1-6"),
+        }
+    }
+}
+impl std::error::Error for GcsError {}
+
+/// Traumatic brain injury severity by GCS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GcsSeverity {
+    /// GCS 13-15
+    Mild,
+    /// GCS 9-12
+    Moderate,
+    /// GCS ≤ 8; airway protection is generally needed.
+    Severe,
+}
+
+/// A Glasgow Coma Scale assessment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GlasgowComaScale {
+    eye: u8,
+    verbal: u8,
+    motor: u8,
+}
+impl GlasgowComaScale {
+    /// Returns an error naming the first component outside its valid range.
+    pub fn new(eye: u8, verbal: u8, motor: u8) -> Result<Self, GcsError> {
+        if !(1..=4).contains(&eye) {
+            return Err(GcsError::Eye(eye));
+        }
+        if !(1..=5).contains(&verbal) {
+            return Err(GcsError::Verbal(verbal));
+        }
+        if !(1..=6).contains(&motor) {
+            return Err(GcsError::Motor(motor));
+        }
+        Ok(Self { eye, verbal, motor })
+    }
+    pub fn eye(&self) -> u8 {
+        self.eye
+    }
+    pub fn verbal(&self) -> u8 {
+        self.verbal
+    }
+    pub fn motor(&self) -> u8 {
+        self.motor
+    }
+    /// Sum of the three components, 3-15.
+    pub fn total(&self) -> u8 {
+        self.eye + self.verbal + self.motor
+    }
+    pub fn severity(&self) -> GcsSeverity {
+        match self.total() {
+            13..=15 => GcsSeverity::Mild,
+            9..=12 => GcsSeverity::Moderate,
+            _ => GcsSeverity::Severe,
+        }
+    }
+}
+impl std::fmt::Display for GlasgowComaScale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "GCS {} (E{} V{} M{})",
+            self.total(),
+            self.eye,
+            self.verbal,
+            self.motor
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fully_alert_patient_scores_fifteen() {
+        let gcs = GlasgowComaScale::new(4, 5, 6).unwrap();
+        assert_eq!(gcs.total(), 15);
+        assert_eq!(gcs.severity(), GcsSeverity::Mild);
+        assert_eq!(format!("{gcs}"), "GCS 15 (E4 V5 M6)");
+    }
+
+    #[test]
+    fn comatose_patient_scores_three() {
+        let gcs = GlasgowComaScale::new(1, 1, 1).unwrap();
+        assert_eq!(gcs.total(), 3);
+        assert_eq!(gcs.severity(), GcsSeverity::Severe);
+    }
+
+    #[test]
+    fn severity_bands() {
+        assert_eq!(
+            GlasgowComaScale::new(3, 4, 5).unwrap().severity(),
+            GcsSeverity::Moderate
+        );
+        assert_eq!(
+            GlasgowComaScale::new(2, 2, 4).unwrap().severity(),
+            GcsSeverity::Severe
+        );
+    }
+
+    #[test]
+    fn out_of_range_components_are_rejected() {
+        assert_eq!(GlasgowComaScale::new(4, 5, 7), Err(GcsError::Motor(7)));
+        assert_eq!(GlasgowComaScale::new(0, 5, 6), Err(GcsError::Eye(0)));
+        assert_eq!(GlasgowComaScale::new(4, 6, 6), Err(GcsError::Verbal(6)));
+    }
+}
@@ -0,0 +1,158 @@
+//! HAS-BLED Score Calculator
+//!
+//! For estimating major bleeding risk in patients anticoagulated for atrial
+//! fibrillation. Often weighed against CHA₂DS₂-VASc when deciding on anticoagulation.
+//!
+
+use crate::history::Years;
+
+/// HAS-BLED to annual major bleeding risk table from Pisters (2010). Too few patients
+/// scored above 5 for a separate estimate, so those share the 5-point risk.
+const ANNUAL_BLEED_RISK_TABLE: [f64; 6] = [1.13, 1.02, 1.88, 3.74, 8.70, 12.5];
+
+/// A HAS-BLED calculator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HasBled {
+    age: Years,
+    htn: bool,
+    renal: bool,
+    liver: bool,
+    stroke: bool,
+    bleeding: bool,
+    labile_inr: bool,
+    drugs: bool,
+    alcohol: bool,
+    score: Option<u8>,
+}
+impl HasBled /* builder / setters */ {
+    pub fn new(age: Years) -> Self {
+        Self {
+            age,
+            htn: false,
+            renal: false,
+            liver: false,
+            stroke: false,
+            bleeding: false,
+            labile_inr: false,
+            drugs: false,
+            alcohol: false,
+            score: None,
+        }
+    }
+    /// Uncontrolled hypertension, SBP > 160 mmHg
+    pub fn has_htn(mut self) -> Self {
+        self.htn = true;
+        self
+    }
+    /// Dialysis, renal transplant or Cr > 2.26 mg/dL (200 µmol/L)
+    pub fn abnormal_renal(mut self) -> Self {
+        self.renal = true;
+        self
+    }
+    /// Cirrhosis, or bilirubin > 2× ULN with AST/ALT/ALP > 3× ULN
+    pub fn abnormal_liver(mut self) -> Self {
+        self.liver = true;
+        self
+    }
+    pub fn stroke_hx(mut self) -> Self {
+        self.stroke = true;
+        self
+    }
+    /// Prior major bleeding or predisposition to bleeding
+    pub fn bleeding_hx(mut self) -> Self {
+        self.bleeding = true;
+        self
+    }
+    /// Time in therapeutic range < 60%
+    pub fn labile_inr(mut self) -> Self {
+        self.labile_inr = true;
+        self
+    }
+    /// Concomitant antiplatelet agents or NSAIDs
+    pub fn drugs(mut self) -> Self {
+        self.drugs = true;
+        self
+    }
+    /// ≥ 8 drinks per week
+    pub fn alcohol(mut self) -> Self {
+        self.alcohol = true;
+        self
+    }
+}
+
+impl HasBled /* getters */ {
+    pub fn age(&self) -> Years {
+        self.age
+    }
+    /// Age over 65, derived from the age given to the constructor.
+    pub fn elderly(&self) -> bool {
+        self.age.0 > 65.0
+    }
+    pub fn score(&self) -> Option<u8> {
+        self.score
+    }
+}
+
+impl HasBled /* calculations */ {
+    #[must_use]
+    pub fn calculate(mut self) -> Self {
+        let tally = [
+            self.htn,
+            self.renal,
+            self.liver,
+            self.stroke,
+            self.bleeding,
+            self.labile_inr,
+            self.elderly(),
+            self.drugs,
+            self.alcohol,
+        ]
+        .iter()
+        .filter(|&rf| *rf)
+        .count() as u8;
+        self.score = Some(tally);
+        self
+    }
+
+    pub fn annual_bleeding_risk_pct(&self) -> Option<f64> {
+        self.score
+            .map(|score| ANNUAL_BLEED_RISK_TABLE[(score as usize).min(5)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{calculators::has_bled::HasBled, history::Years};
+
+    #[test]
+    fn score_and_risk_are_none_until_calculated() {
+        let has_bled = HasBled::new(Years(50.0));
+        assert!(has_bled.score().is_none());
+        assert!(has_bled.annual_bleeding_risk_pct().is_none());
+        assert!(has_bled.calculate().annual_bleeding_risk_pct().is_some());
+    }
+
+    #[test]
+    fn healthy_patient_65_or_under_scores_zero() {
+        let has_bled = HasBled::new(Years(65.0)).calculate();
+        assert!(!has_bled.elderly());
+        assert_eq!(Some(0), has_bled.score());
+        assert_eq!(Some(1.13), has_bled.annual_bleeding_risk_pct());
+    }
+
+    #[test]
+    fn maximum_risk_factors_scores_nine() {
+        let has_bled = HasBled::new(Years(66.0))
+            .has_htn()
+            .abnormal_renal()
+            .abnormal_liver()
+            .stroke_hx()
+            .bleeding_hx()
+            .labile_inr()
+            .drugs()
+            .alcohol()
+            .calculate();
+        assert_eq!(Some(9), has_bled.score());
+        assert_eq!(Some(12.5), has_bled.annual_bleeding_risk_pct());
+    }
+}
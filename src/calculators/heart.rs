@@ -0,0 +1,180 @@
+//! HEART Score
+//!
+//! Risk of a major adverse cardiac event (MACE) within 6 weeks for patients
+//! presenting with chest pain: History, ECG, Age, Risk factors, Troponin.
+//!
+
+use crate::{
+    history::{SexAtBirth, Years},
+    lab::blood::troponin::Troponin,
+    units::troponin::TroponinUnit,
+};
+
+/// How suspicious the history is for ACS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistorySuspicion {
+    Slightly,
+    Moderately,
+    Highly,
+}
+
+/// Most significant ECG finding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EcgFinding {
+    Normal,
+    /// LBBB, LVH, repolarization changes, paced rhythm, etc.
+    NonspecificRepolarization,
+    SignificantStDeviation,
+}
+
+/// 6-week MACE risk band for a HEART score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MaceRisk {
+    /// Score 0-3: ~0.9-1.7% risk; candidate for early discharge.
+    Low,
+    /// Score 4-6: ~12-16.6% risk; observe and further testing.
+    Moderate,
+    /// Score 7-10: ~50-65% risk; early invasive strategy.
+    High,
+}
+
+/// A HEART score calculator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeartScore {
+    age: Years,
+    troponin_url_multiple: f64,
+    history: HistorySuspicion,
+    ecg: EcgFinding,
+    risk_factors: u8,
+    atherosclerosis: bool,
+    score: Option<u8>,
+}
+impl HeartScore /* builder / setters */ {
+    /// Troponin is compared against its default sex-specific upper reference limit.
+    pub fn new<U: TroponinUnit>(age: Years, sex: SexAtBirth, troponin: Troponin<U>) -> Self {
+        Self {
+            age,
+            troponin_url_multiple: troponin.multiple_of_url(sex),
+            history: HistorySuspicion::Slightly,
+            ecg: EcgFinding::Normal,
+            risk_factors: 0,
+            atherosclerosis: false,
+            score: None,
+        }
+    }
+    pub fn history(mut self, suspicion: HistorySuspicion) -> Self {
+        self.history = suspicion;
+        self
+    }
+    pub fn ecg(mut self, finding: EcgFinding) -> Self {
+        self.ecg = finding;
+        self
+    }
+    /// Count of: hypertension, hypercholesterolemia, diabetes, obesity (BMI > 30),
+    /// current or recent smoking, family history of premature CAD.
+    pub fn risk_factors(mut self, count: u8) -> Self {
+        self.risk_factors = count;
+        self
+    }
+    /// Known atherosclerotic disease (prior MI, PCI/CABG, stroke/TIA, PAD) scores
+    /// the maximum for risk factors regardless of count.
+    pub fn has_atherosclerotic_disease(mut self) -> Self {
+        self.atherosclerosis = true;
+        self
+    }
+}
+
+impl HeartScore /* getters */ {
+    pub fn age(&self) -> Years {
+        self.age
+    }
+    pub fn score(&self) -> Option<u8> {
+        self.score
+    }
+}
+
+impl HeartScore /* calculations */ {
+    #[must_use]
+    pub fn calculate(mut self) -> Self {
+        let history = match self.history {
+            HistorySuspicion::Slightly => 0,
+            HistorySuspicion::Moderately => 1,
+            HistorySuspicion::Highly => 2,
+        };
+        let ecg = match self.ecg {
+            EcgFinding::Normal => 0,
+            EcgFinding::NonspecificRepolarization => 1,
+            EcgFinding::SignificantStDeviation => 2,
+        };
+        let age = match self.age.0 {
+            age if age >= 65.0 => 2,
+            age if age >= 45.0 => 1,
+            _ => 0,
+        };
+        let risk = match self.risk_factors {
+            _ if self.atherosclerosis => 2,
+            n if n >= 3 => 2,
+            n if n >= 1 => 1,
+            _ => 0,
+        };
+        let troponin = match self.troponin_url_multiple {
+            x if x > 3.0 => 2,
+            x if x > 1.0 => 1,
+            _ => 0,
+        };
+        self.score = Some(history + ecg + age + risk + troponin);
+        self
+    }
+
+    pub fn mace_risk(&self) -> Option<MaceRisk> {
+        self.score.map(|score| match score {
+            0..=3 => MaceRisk::Low,
+            4..=6 => MaceRisk::Moderate,
+            _ => MaceRisk::High,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lab::blood::troponin::TroponinExt;
+
+    #[test]
+    fn score_is_none_until_calculated() {
+        let heart = HeartScore::new(Years(40.0), SexAtBirth::Male, 5.0.trop_ng_l());
+        assert!(heart.score().is_none());
+        assert!(heart.mace_risk().is_none());
+    }
+
+    #[test]
+    fn young_patient_with_normal_workup_is_low_risk() {
+        let heart = HeartScore::new(Years(38.0), SexAtBirth::Female, 4.0.trop_ng_l())
+            .history(HistorySuspicion::Moderately)
+            .risk_factors(1)
+            .calculate();
+        assert_eq!(Some(2), heart.score());
+        assert_eq!(Some(MaceRisk::Low), heart.mace_risk());
+    }
+
+    #[test]
+    fn elderly_patient_with_st_changes_and_high_troponin_is_high_risk() {
+        // 0.12 ng/mL = 120 ng/L, > 3x the male URL of 34 ng/L
+        let heart = HeartScore::new(Years(72.0), SexAtBirth::Male, 0.12.trop_ng_ml())
+            .history(HistorySuspicion::Highly)
+            .ecg(EcgFinding::SignificantStDeviation)
+            .has_atherosclerotic_disease()
+            .calculate();
+        assert_eq!(Some(10), heart.score());
+        assert_eq!(Some(MaceRisk::High), heart.mace_risk());
+    }
+
+    #[test]
+    fn troponin_points_use_sex_specific_url() {
+        // 60 ng/L is >3x the female URL (16) but <3x the male URL (34)
+        let female = HeartScore::new(Years(30.0), SexAtBirth::Female, 60.0.trop_ng_l()).calculate();
+        let male = HeartScore::new(Years(30.0), SexAtBirth::Male, 60.0.trop_ng_l()).calculate();
+        assert_eq!(Some(2), female.score());
+        assert_eq!(Some(1), male.score());
+    }
+}
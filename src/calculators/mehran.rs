@@ -0,0 +1,89 @@
+//! Contrast-induced nephropathy
+//!
+//! Ceilings on iodinated contrast volume for angiography and CT, to limit the risk
+//! of contrast-induced acute kidney injury in patients with reduced renal function.
+
+use crate::{
+    lab::{blood::creatinine::Creatinine, gfr::ClearanceRate, vitals::Weight},
+    units::{creatinine::CreatinineUnit, vitals::WeightUnit, MgdL, MlMin},
+};
+
+/// Absolute ceiling for the Cigarroa formula, regardless of weight or creatinine.
+pub const CIGARROA_MAX_ML: f64 = 300.0;
+
+/// Contrast volume / CrCl ratio above which the risk of nephropathy rises sharply
+/// (Laskey et al.).
+pub const CONTRAST_CRCL_RATIO_LIMIT: f64 = 3.7;
+
+/// A recommended maximum contrast volume.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContrastLimit {
+    max_ml: f64,
+}
+impl ContrastLimit {
+    pub fn max_ml(&self) -> f64 {
+        self.max_ml
+    }
+    /// Whether a planned contrast volume (mL) would go over the ceiling.
+    pub fn is_exceeded_by(&self, planned_ml: f64) -> bool {
+        planned_ml > self.max_ml
+    }
+}
+impl std::fmt::Display for ContrastLimit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "max contrast {:.0} mL", self.max_ml)
+    }
+}
+
+/// Maximum acceptable contrast dose by the Cigarroa formula:
+/// 5 mL × weight (kg) / serum creatinine (mg/dL), up to 300 mL.
+pub fn max_contrast_volume<W, C>(weight: Weight<W>, scr: Creatinine<C>) -> ContrastLimit
+where
+    W: WeightUnit,
+    C: CreatinineUnit,
+{
+    let wt_kg = W::to_kg(weight.value());
+    let scr_mg_dl = MgdL::from_umol_l(C::to_umol_l(scr.value()));
+
+    ContrastLimit {
+        max_ml: (5.0 * wt_kg / scr_mg_dl).min(CIGARROA_MAX_ML),
+    }
+}
+
+/// Maximum contrast volume keeping the volume-to-creatinine-clearance ratio at or
+/// below 3.7, for when a measured or estimated CrCl is at hand.
+pub fn max_contrast_volume_for_crcl(crcl: ClearanceRate<MlMin>) -> ContrastLimit {
+    ContrastLimit {
+        max_ml: CONTRAST_CRCL_RATIO_LIMIT * crcl.value(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lab::{blood::creatinine::CreatinineExt, vitals::WeightExt};
+
+    #[test]
+    fn normal_renal_function_hits_the_300_ml_cap() {
+        // 5 × 80 / 0.9 ≈ 444 mL, capped at 300
+        let limit = max_contrast_volume(80.0.weight_kg(), 0.9.cr_serum_mg_dl());
+        assert_eq!(limit.max_ml(), CIGARROA_MAX_ML);
+        assert!(!limit.is_exceeded_by(250.0));
+    }
+
+    #[test]
+    fn impaired_renal_function_lowers_the_ceiling() {
+        // 5 × 70 / 2.5 = 140 mL, with creatinine given in µmol/L
+        let limit = max_contrast_volume(70.0.weight_kg(), (2.5 * 88.4).cr_serum_umol_l());
+        assert!((limit.max_ml() - 140.0).abs() < 1e-9);
+        assert!(limit.is_exceeded_by(200.0));
+        assert!(!limit.is_exceeded_by(140.0));
+    }
+
+    #[test]
+    fn crcl_based_ceiling() {
+        let limit = max_contrast_volume_for_crcl(ClearanceRate::from(30.0));
+        assert!((limit.max_ml() - 111.0).abs() < 1e-9);
+        assert!(limit.is_exceeded_by(150.0));
+    }
+}
@@ -0,0 +1,138 @@
+//! Original MELD Score
+//!
+//! The pre-2016 UNOS MELD, without the sodium correction. Still used in some
+//! contexts (e.g. TIPS risk) and the base on which MELD-Na is built.
+//!
+
+use super::MeldScore;
+use crate::{
+    constants::{SBILI_UMOLL_TO_MGDL, SCR_UMOLL_TO_MGDL},
+    lab::blood::{bilirubin::Bilirubin, creatinine::Creatinine, inr::Inr},
+    units::{bilirubin::BilirubinUnit, creatinine::CreatinineUnit, Unit},
+};
+
+/// MELD(i): 10 × (0.957 × ln(Cr) + 0.378 × ln(bili) + 1.120 × ln(INR) + 0.643),
+/// rounded to the tenth, with UNOS clamping applied to the inputs.
+pub(crate) fn meld_i<B, C, I>(
+    bili: Bilirubin<B>,
+    scr: Creatinine<C>,
+    inr: Inr<I>,
+    on_dialysis: bool,
+) -> f64
+where
+    B: BilirubinUnit,
+    C: CreatinineUnit,
+    I: Unit,
+{
+    let bili_mgdl = (B::to_umoll(bili.value()) * SBILI_UMOLL_TO_MGDL).max(1.0);
+    let scr_mgdl = if on_dialysis {
+        4.0
+    } else {
+        (C::to_umol_l(scr.value()) * SCR_UMOLL_TO_MGDL).clamp(1.0, 4.0)
+    };
+    let inr = inr.value().max(1.0);
+
+    let meld_i = 0.957 * scr_mgdl.ln() + 0.378 * bili_mgdl.ln() + 1.120 * inr.ln() + 0.643;
+    (meld_i * 100.0).round() / 10.0
+}
+
+/// Original (pre-sodium) MELD score
+///
+/// MELD = 10 × (0.957 × ln(Cr) + 0.378 × ln(bili) + 1.120 × ln(INR) + 0.643)
+///
+/// Bilirubin, creatinine and INR are floored at 1.0; creatinine is capped at 4.0 (or
+/// set to 4.0 when `on_dialysis`, i.e. ≥ 2 dialyses or 24 h of CVVHD in the past
+/// week). The final score is clamped to 6-40.
+pub fn meld_original<B, C, I>(
+    bili: Bilirubin<B>,
+    scr: Creatinine<C>,
+    inr: Inr<I>,
+    on_dialysis: bool,
+) -> MeldScore
+where
+    B: BilirubinUnit,
+    C: CreatinineUnit,
+    I: Unit,
+{
+    meld_i(bili, scr, inr, on_dialysis).round().clamp(6.0, 40.0) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        calculators::meld_na,
+        lab::blood::{
+            bilirubin::BilirubinExt, creatinine::CreatinineExt, inr::InrExt, sodium::SerumSodiumExt,
+        },
+    };
+
+    #[test]
+    fn normal_labs_score_six() {
+        let score = meld_original(
+            1.0.serum_bili_mgdl(),
+            1.0.cr_serum_mg_dl(),
+            1.0.inr(),
+            false,
+        );
+        assert_eq!(score, 6);
+    }
+
+    #[test]
+    fn moderate_disease_matches_published_value() {
+        // bili 2.0, Cr 1.5, INR 1.8: MELD(i) = 19.5, reported as 20
+        let score = meld_original(
+            2.0.serum_bili_mgdl(),
+            1.5.cr_serum_mg_dl(),
+            1.8.inr(),
+            false,
+        );
+        assert_eq!(score, 20);
+    }
+
+    #[test]
+    fn severe_disease_in_si_units() {
+        // bili 171 µmol/L (10 mg/dL), Cr 265.2 µmol/L (3 mg/dL), INR 2.5
+        // 9.57 × ln 3 + 3.78 × ln 10 + 11.2 × ln 2.5 + 6.43 = 35.9
+        let score = meld_original(
+            171.0.serum_bili_umoll(),
+            265.2.cr_serum_umol_l(),
+            2.5.inr(),
+            false,
+        );
+        assert_eq!(score, 36);
+    }
+
+    #[test]
+    fn dialysis_sets_creatinine_to_four() {
+        let on_hd = meld_original(1.0.serum_bili_mgdl(), 0.8.cr_serum_mg_dl(), 1.0.inr(), true);
+        let capped = meld_original(
+            1.0.serum_bili_mgdl(),
+            8.0.cr_serum_mg_dl(),
+            1.0.inr(),
+            false,
+        );
+        assert_eq!(on_hd, 20);
+        assert_eq!(capped, on_hd);
+    }
+
+    #[test]
+    fn meld_na_equals_original_when_sodium_is_137() {
+        for (bili, scr, inr) in [(1.0, 1.0, 1.0), (2.0, 1.5, 1.8), (6.0, 2.7, 2.2)] {
+            let original = meld_original(
+                bili.serum_bili_mgdl(),
+                scr.cr_serum_mg_dl(),
+                inr.inr(),
+                false,
+            );
+            let with_na = meld_na(
+                bili.serum_bili_mgdl(),
+                scr.cr_serum_mg_dl(),
+                inr.inr(),
+                137.0.na_serum_meq(),
+                false,
+            );
+            assert_eq!(original, with_na);
+        }
+    }
+}
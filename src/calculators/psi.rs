@@ -0,0 +1,336 @@
+//! Pneumonia Severity Index (PSI / PORT score)
+//!
+//! Estimates 30-day mortality in community-acquired pneumonia from demographics,
+//! comorbidities, exam findings, and labs, to guide outpatient vs inpatient care.
+//! Labs that weren't measured score no points.
+//!
+
+use crate::{
+    history::{SexAtBirth, Years},
+    lab::{
+        blood::{bun::Bun, glucose::Glucose, hematocrit::Hematocrit, sodium::Sodium},
+        vitals::{blood_pressure::BloodPressure, RespiratoryRate, Temperature},
+    },
+    units::{
+        bun::BunUnit, glucose::GlucoseUnit, sodium::SodiumUnit, vitals::TemperatureUnit, MgdL,
+        PerMinute,
+    },
+};
+
+/// PSI risk class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PsiClass {
+    /// Age ≤ 50 with no comorbidities or abnormal exam findings; ~0.1% mortality.
+    I,
+    /// Score ≤ 70; ~0.6% mortality.
+    II,
+    /// Score 71-90; ~0.9-2.8% mortality.
+    III,
+    /// Score 91-130; ~8-9% mortality.
+    IV,
+    /// Score > 130; ~27-31% mortality.
+    V,
+}
+
+/// Suggested site of care for a PSI class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PsiDisposition {
+    /// Classes I-II.
+    Outpatient,
+    /// Class III: outpatient or brief observation admission.
+    Observation,
+    /// Classes IV-V; consider ICU for class V.
+    Inpatient,
+}
+
+/// A Pneumonia Severity Index calculator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Psi {
+    age: Years,
+    sex: SexAtBirth,
+    nursing_home: bool,
+    neoplastic_disease: bool,
+    liver_disease: bool,
+    chf: bool,
+    cerebrovascular_disease: bool,
+    renal_disease: bool,
+    altered_mental_status: bool,
+    resp_rate: Option<f64>,
+    systolic: Option<f64>,
+    temp_c: Option<f64>,
+    heart_rate: Option<f64>,
+    ph: Option<f64>,
+    bun_mg_dl: Option<f64>,
+    na_mmol_l: Option<f64>,
+    glucose_mg_dl: Option<f64>,
+    hematocrit: Option<f64>,
+    hypoxemia: bool,
+    pleural_effusion: bool,
+    score: Option<u16>,
+}
+impl Psi /* builder / setters */ {
+    pub fn new(age: Years, sex: SexAtBirth) -> Self {
+        Self {
+            age,
+            sex,
+            nursing_home: false,
+            neoplastic_disease: false,
+            liver_disease: false,
+            chf: false,
+            cerebrovascular_disease: false,
+            renal_disease: false,
+            altered_mental_status: false,
+            resp_rate: None,
+            systolic: None,
+            temp_c: None,
+            heart_rate: None,
+            ph: None,
+            bun_mg_dl: None,
+            na_mmol_l: None,
+            glucose_mg_dl: None,
+            hematocrit: None,
+            hypoxemia: false,
+            pleural_effusion: false,
+            score: None,
+        }
+    }
+    pub fn nursing_home_resident(mut self) -> Self {
+        self.nursing_home = true;
+        self
+    }
+    pub fn neoplastic_disease(mut self) -> Self {
+        self.neoplastic_disease = true;
+        self
+    }
+    pub fn liver_disease(mut self) -> Self {
+        self.liver_disease = true;
+        self
+    }
+    pub fn chf(mut self) -> Self {
+        self.chf = true;
+        self
+    }
+    pub fn cerebrovascular_disease(mut self) -> Self {
+        self.cerebrovascular_disease = true;
+        self
+    }
+    pub fn renal_disease(mut self) -> Self {
+        self.renal_disease = true;
+        self
+    }
+    pub fn altered_mental_status(mut self) -> Self {
+        self.altered_mental_status = true;
+        self
+    }
+    pub fn resp_rate(mut self, rr: RespiratoryRate<PerMinute>) -> Self {
+        self.resp_rate = Some(rr.value());
+        self
+    }
+    pub fn blood_pressure(mut self, bp: BloodPressure) -> Self {
+        self.systolic = Some(bp.systolic());
+        self
+    }
+    pub fn temperature<T: TemperatureUnit>(mut self, temp: Temperature<T>) -> Self {
+        self.temp_c = Some(T::to_celsius(temp.value()));
+        self
+    }
+    /// Heart rate in beats per minute.
+    pub fn heart_rate(mut self, bpm: f64) -> Self {
+        self.heart_rate = Some(bpm);
+        self
+    }
+    /// Arterial pH.
+    pub fn arterial_ph(mut self, ph: f64) -> Self {
+        self.ph = Some(ph);
+        self
+    }
+    pub fn bun<B: BunUnit>(mut self, bun: Bun<B>) -> Self {
+        self.bun_mg_dl = Some(MgdL::from_urea_mmol_l(B::to_urea_mmol_l(bun.value())));
+        self
+    }
+    pub fn sodium<N: SodiumUnit>(mut self, na: Sodium<N>) -> Self {
+        self.na_mmol_l = Some(N::to_mmol_l(na.value()));
+        self
+    }
+    pub fn glucose<G: GlucoseUnit>(mut self, glucose: Glucose<G>) -> Self {
+        self.glucose_mg_dl = Some(<MgdL as GlucoseUnit>::from_mmol_l(G::to_mmol_l(
+            glucose.value(),
+        )));
+        self
+    }
+    pub fn hematocrit(mut self, hct: Hematocrit) -> Self {
+        self.hematocrit = Some(hct.value());
+        self
+    }
+    /// PaO₂ < 60 mmHg or SpO₂ < 90%.
+    pub fn hypoxemia(mut self) -> Self {
+        self.hypoxemia = true;
+        self
+    }
+    pub fn pleural_effusion(mut self) -> Self {
+        self.pleural_effusion = true;
+        self
+    }
+}
+
+impl Psi /* getters */ {
+    pub fn age(&self) -> Years {
+        self.age
+    }
+    pub fn score(&self) -> Option<u16> {
+        self.score
+    }
+    fn has_comorbidity(&self) -> bool {
+        self.neoplastic_disease
+            || self.liver_disease
+            || self.chf
+            || self.cerebrovascular_disease
+            || self.renal_disease
+    }
+    fn exam_points(&self) -> u16 {
+        let criteria = [
+            (self.altered_mental_status, 20),
+            (self.resp_rate.is_some_and(|rr| rr >= 30.0), 20),
+            (self.systolic.is_some_and(|sbp| sbp < 90.0), 20),
+            (self.temp_c.is_some_and(|t| !(35.0..40.0).contains(&t)), 15),
+            (self.heart_rate.is_some_and(|hr| hr >= 125.0), 10),
+        ];
+        tally(&criteria)
+    }
+}
+
+impl Psi /* calculations */ {
+    #[must_use]
+    pub fn calculate(mut self) -> Self {
+        let age_points = match self.sex {
+            SexAtBirth::Male => self.age.0,
+            SexAtBirth::Female => self.age.0 - 10.0,
+        }
+        .max(0.0) as u16;
+        let history = [
+            (self.nursing_home, 10),
+            (self.neoplastic_disease, 30),
+            (self.liver_disease, 20),
+            (self.chf, 10),
+            (self.cerebrovascular_disease, 10),
+            (self.renal_disease, 10),
+        ];
+        let labs = [
+            (self.ph.is_some_and(|ph| ph < 7.35), 30),
+            (self.bun_mg_dl.is_some_and(|bun| bun >= 30.0), 20),
+            (self.na_mmol_l.is_some_and(|na| na < 130.0), 20),
+            (self.glucose_mg_dl.is_some_and(|glu| glu >= 250.0), 10),
+            (self.hematocrit.is_some_and(|hct| hct < 30.0), 10),
+            (self.hypoxemia, 10),
+            (self.pleural_effusion, 10),
+        ];
+        self.score = Some(age_points + tally(&history) + self.exam_points() + tally(&labs));
+        self
+    }
+
+    /// Class I is assigned without the score, to patients 50 or younger with none
+    /// of the five comorbidities and no abnormal exam findings.
+    pub fn risk_class(&self) -> Option<PsiClass> {
+        self.score.map(|score| {
+            if self.age.0 <= 50.0 && !self.has_comorbidity() && self.exam_points() == 0 {
+                return PsiClass::I;
+            }
+            match score {
+                0..=70 => PsiClass::II,
+                71..=90 => PsiClass::III,
+                91..=130 => PsiClass::IV,
+                _ => PsiClass::V,
+            }
+        })
+    }
+
+    pub fn disposition(&self) -> Option<PsiDisposition> {
+        self.risk_class().map(|class| match class {
+            PsiClass::I | PsiClass::II => PsiDisposition::Outpatient,
+            PsiClass::III => PsiDisposition::Observation,
+            PsiClass::IV | PsiClass::V => PsiDisposition::Inpatient,
+        })
+    }
+}
+
+fn tally(criteria: &[(bool, u16)]) -> u16 {
+    criteria
+        .iter()
+        .filter(|(present, _)| *present)
+        .map(|(_, pts)| pts)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lab::{
+        blood::{
+            bun::BunExt, glucose::SerumGlucoseExt, hematocrit::HematocritExt,
+            sodium::SerumSodiumExt,
+        },
+        vitals::{RespiratoryRateExt, TemperatureExt},
+    };
+
+    #[test]
+    fn score_is_none_until_calculated() {
+        let psi = Psi::new(Years(40.0), SexAtBirth::Male);
+        assert!(psi.score().is_none());
+        assert!(psi.risk_class().is_none());
+    }
+
+    #[test]
+    fn young_healthy_patient_is_class_i_outpatient() {
+        let psi = Psi::new(Years(35.0), SexAtBirth::Female)
+            .resp_rate(18.0.breaths_per_min())
+            .blood_pressure(BloodPressure::new(118.0, 76.0))
+            .temperature(38.2.temp_c())
+            .heart_rate(96.0)
+            .sodium(138.0.na_serum_mmol())
+            .calculate();
+        assert_eq!(Some(25), psi.score());
+        assert_eq!(Some(PsiClass::I), psi.risk_class());
+        assert_eq!(Some(PsiDisposition::Outpatient), psi.disposition());
+    }
+
+    #[test]
+    fn young_patient_with_comorbidity_is_scored() {
+        let psi = Psi::new(Years(45.0), SexAtBirth::Male).chf().calculate();
+        assert_eq!(Some(55), psi.score());
+        assert_eq!(Some(PsiClass::II), psi.risk_class());
+    }
+
+    #[test]
+    fn sick_elderly_nursing_home_patient_is_class_v_admission() {
+        // 82 + 10 (nursing home) + 10 (CHF) + 20 (AMS) + 20 (RR) + 20 (SBP) + 10 (HR)
+        // + 30 (pH) + 20 (BUN) + 20 (Na) + 10 (glucose) + 10 (Hct) + 10 (hypoxemia)
+        let psi = Psi::new(Years(82.0), SexAtBirth::Male)
+            .nursing_home_resident()
+            .chf()
+            .altered_mental_status()
+            .resp_rate(32.0.breaths_per_min())
+            .blood_pressure(BloodPressure::new(84.0, 50.0))
+            .temperature(37.8.temp_c())
+            .heart_rate(128.0)
+            .arterial_ph(7.30)
+            .bun(14.0.urea_mmol_l()) // ≈ 39 mg/dL
+            .sodium(127.0.na_serum_meq())
+            .glucose(300.0.glu_serum_mg_dl())
+            .hematocrit(28.0.hct_pct())
+            .hypoxemia()
+            .calculate();
+        assert_eq!(Some(272), psi.score());
+        assert_eq!(Some(PsiClass::V), psi.risk_class());
+        assert_eq!(Some(PsiDisposition::Inpatient), psi.disposition());
+    }
+
+    #[test]
+    fn hypothermia_scores_temperature_points() {
+        let psi = Psi::new(Years(70.0), SexAtBirth::Female)
+            .temperature(94.0.temp_f()) // ≈ 34.4 °C
+            .calculate();
+        assert_eq!(Some(75), psi.score());
+        assert_eq!(Some(PsiClass::III), psi.risk_class());
+        assert_eq!(Some(PsiDisposition::Observation), psi.disposition());
+    }
+}
@@ -0,0 +1,127 @@
+//! qSOFA (quick SOFA) Score
+//!
+//! Bedside screen for patients with suspected infection who are likely to have a
+//! poor outcome: one point each for respiratory rate ≥ 22/min, systolic BP ≤ 100
+//! mmHg and altered mentation (GCS < 15). A score of 2 or more is high risk.
+//!
+
+use crate::{
+    calculators::gcs::GlasgowComaScale,
+    lab::vitals::{blood_pressure::BloodPressure, RespiratoryRate},
+    units::PerMinute,
+};
+
+/// A qSOFA calculator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QSofa {
+    resp_rate: f64,
+    systolic: f64,
+    altered_mentation: bool,
+    score: Option<u8>,
+}
+impl QSofa /* builder / setters */ {
+    pub fn new(resp_rate: RespiratoryRate<PerMinute>, bp: BloodPressure) -> Self {
+        Self {
+            resp_rate: resp_rate.value(),
+            systolic: bp.systolic(),
+            altered_mentation: false,
+            score: None,
+        }
+    }
+    /// Any altered mentation, i.e. a GCS below 15.
+    pub fn has_altered_mentation(mut self) -> Self {
+        self.altered_mentation = true;
+        self
+    }
+    /// Sets the mentation criterion from a GCS assessment.
+    pub fn gcs(mut self, gcs: GlasgowComaScale) -> Self {
+        self.altered_mentation = gcs.total() < 15;
+        self
+    }
+}
+
+impl QSofa /* getters */ {
+    pub fn respiratory_criterion(&self) -> bool {
+        self.resp_rate >= 22.0
+    }
+    pub fn hypotension_criterion(&self) -> bool {
+        self.systolic <= 100.0
+    }
+    pub fn mentation_criterion(&self) -> bool {
+        self.altered_mentation
+    }
+    pub fn score(&self) -> Option<u8> {
+        self.score
+    }
+}
+
+impl QSofa /* calculations */ {
+    #[must_use]
+    pub fn calculate(mut self) -> Self {
+        let tally = [
+            self.respiratory_criterion(),
+            self.hypotension_criterion(),
+            self.mentation_criterion(),
+        ]
+        .iter()
+        .filter(|&c| *c)
+        .count() as u8;
+        self.score = Some(tally);
+        self
+    }
+
+    /// True when two or more criteria are met.
+    pub fn high_risk(&self) -> Option<bool> {
+        self.score.map(|score| score >= 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        calculators::{gcs::GlasgowComaScale, qsofa::QSofa},
+        lab::vitals::{blood_pressure::BloodPressure, RespiratoryRateExt},
+    };
+
+    #[test]
+    fn score_is_none_until_calculated() {
+        let qsofa = QSofa::new(16.0.breaths_per_min(), BloodPressure::new(120.0, 80.0));
+        assert!(qsofa.score().is_none());
+        assert!(qsofa.high_risk().is_none());
+    }
+
+    #[test]
+    fn well_patient_scores_zero() {
+        let qsofa = QSofa::new(16.0.breaths_per_min(), BloodPressure::new(124.0, 78.0)).calculate();
+        assert_eq!(Some(0), qsofa.score());
+        assert_eq!(Some(false), qsofa.high_risk());
+    }
+
+    #[test]
+    fn septic_patient_meets_all_criteria() {
+        let qsofa = QSofa::new(28.0.breaths_per_min(), BloodPressure::new(88.0, 50.0))
+            .has_altered_mentation()
+            .calculate();
+        assert_eq!(Some(3), qsofa.score());
+        assert_eq!(Some(true), qsofa.high_risk());
+    }
+
+    #[test]
+    fn gcs_below_fifteen_is_altered_mentation() {
+        let base = QSofa::new(16.0.breaths_per_min(), BloodPressure::new(120.0, 80.0));
+        let alert = base
+            .gcs(GlasgowComaScale::new(4, 5, 6).unwrap())
+            .calculate();
+        let confused = base
+            .gcs(GlasgowComaScale::new(4, 4, 6).unwrap())
+            .calculate();
+        assert_eq!(Some(0), alert.score());
+        assert_eq!(Some(1), confused.score());
+    }
+
+    #[test]
+    fn thresholds_are_inclusive() {
+        let qsofa = QSofa::new(22.0.breaths_per_min(), BloodPressure::new(100.0, 60.0)).calculate();
+        assert_eq!(Some(2), qsofa.score());
+    }
+}
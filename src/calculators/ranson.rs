@@ -0,0 +1,231 @@
+//! Ranson Criteria
+//!
+//! Predicts mortality in acute (non-gallstone) pancreatitis from five findings on
+//! admission and six over the first 48 hours, one point each. Criteria that weren't
+//! assessed score no points, so the score is only final once the 48-hour values are
+//! in.
+//!
+
+use crate::{
+    history::Years,
+    lab::blood::{
+        ast::Ast, bun::Bun, calcium::Calcium, glucose::Glucose, hematocrit::Hematocrit,
+        white_blood_cells::WhiteBloodCells,
+    },
+    units::{
+        bun::BunUnit, calcium::CalciumUnit, enzyme::EnzymeUnit, glucose::GlucoseUnit, MgdL,
+        PerNanoLiter,
+    },
+};
+
+/// Mortality (%) by Ranson score: 0-2, 3-4, 5-6 and 7 or more criteria (Ranson 1974).
+const RANSON_MORTALITY_PCT: [f64; 4] = [0.9, 16.0, 40.0, 100.0];
+
+/// A Ranson criteria calculator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ranson {
+    age: Years,
+    wbc: Option<f64>,
+    glucose_mg_dl: Option<f64>,
+    ast_u_l: Option<f64>,
+    ldh_u_l: Option<f64>,
+    hematocrit_drop: Option<f64>,
+    bun_rise_mg_dl: Option<f64>,
+    calcium_mg_dl: Option<f64>,
+    pao2_mmhg: Option<f64>,
+    base_deficit: Option<f64>,
+    fluid_sequestration_l: Option<f64>,
+    score: Option<u8>,
+}
+impl Ranson /* builder / setters */ {
+    pub fn new(age: Years) -> Self {
+        Self {
+            age,
+            wbc: None,
+            glucose_mg_dl: None,
+            ast_u_l: None,
+            ldh_u_l: None,
+            hematocrit_drop: None,
+            bun_rise_mg_dl: None,
+            calcium_mg_dl: None,
+            pao2_mmhg: None,
+            base_deficit: None,
+            fluid_sequestration_l: None,
+            score: None,
+        }
+    }
+    pub fn wbc(mut self, wbc: WhiteBloodCells<PerNanoLiter>) -> Self {
+        self.wbc = Some(wbc.value());
+        self
+    }
+    pub fn glucose<G: GlucoseUnit>(mut self, glucose: Glucose<G>) -> Self {
+        self.glucose_mg_dl = Some(<MgdL as GlucoseUnit>::from_mmol_l(G::to_mmol_l(
+            glucose.value(),
+        )));
+        self
+    }
+    pub fn ast<E: EnzymeUnit>(mut self, ast: Ast<E>) -> Self {
+        self.ast_u_l = Some(E::to_u_l(ast.value()));
+        self
+    }
+    /// Serum LDH in U/L.
+    pub fn ldh_u_l(mut self, ldh: f64) -> Self {
+        self.ldh_u_l = Some(ldh);
+        self
+    }
+    /// Hematocrit on admission and at 48 hours.
+    pub fn hematocrit_drop(mut self, admission: Hematocrit, at_48h: Hematocrit) -> Self {
+        self.hematocrit_drop = Some(admission.value() - at_48h.value());
+        self
+    }
+    /// BUN on admission and at 48 hours.
+    pub fn bun_rise<A: BunUnit, B: BunUnit>(mut self, admission: Bun<A>, at_48h: Bun<B>) -> Self {
+        let admission = MgdL::from_urea_mmol_l(A::to_urea_mmol_l(admission.value()));
+        let at_48h = MgdL::from_urea_mmol_l(B::to_urea_mmol_l(at_48h.value()));
+        self.bun_rise_mg_dl = Some(at_48h - admission);
+        self
+    }
+    /// Serum calcium at 48 hours.
+    pub fn calcium<C: CalciumUnit>(mut self, calcium: Calcium<C>) -> Self {
+        self.calcium_mg_dl = Some(MgdL::from_calcium_mmol_l(C::to_calcium_mmol_l(
+            calcium.value(),
+        )));
+        self
+    }
+    /// Arterial PaO₂ in mmHg.
+    pub fn pao2_mmhg(mut self, pao2: f64) -> Self {
+        self.pao2_mmhg = Some(pao2);
+        self
+    }
+    /// Base deficit in mEq/L.
+    pub fn base_deficit(mut self, deficit: f64) -> Self {
+        self.base_deficit = Some(deficit);
+        self
+    }
+    /// Estimated fluid sequestration (intake minus output) over 48 hours, in liters.
+    pub fn fluid_sequestration_l(mut self, liters: f64) -> Self {
+        self.fluid_sequestration_l = Some(liters);
+        self
+    }
+}
+
+impl Ranson /* getters */ {
+    pub fn age(&self) -> Years {
+        self.age
+    }
+    /// Number of admission criteria met: age > 55, WBC > 16 ×10⁹/L, glucose > 200
+    /// mg/dL, AST > 250 U/L and LDH > 350 U/L.
+    pub fn admission_criteria(&self) -> u8 {
+        [
+            self.age.0 > 55.0,
+            self.wbc.is_some_and(|wbc| wbc > 16.0),
+            self.glucose_mg_dl.is_some_and(|glu| glu > 200.0),
+            self.ast_u_l.is_some_and(|ast| ast > 250.0),
+            self.ldh_u_l.is_some_and(|ldh| ldh > 350.0),
+        ]
+        .iter()
+        .filter(|&met| *met)
+        .count() as u8
+    }
+    /// Number of 48-hour criteria met: hematocrit drop > 10 points, BUN rise > 5
+    /// mg/dL, calcium < 8 mg/dL, PaO₂ < 60 mmHg, base deficit > 4 mEq/L and fluid
+    /// sequestration > 6 L.
+    pub fn criteria_at_48h(&self) -> u8 {
+        [
+            self.hematocrit_drop.is_some_and(|drop| drop > 10.0),
+            self.bun_rise_mg_dl.is_some_and(|rise| rise > 5.0),
+            self.calcium_mg_dl.is_some_and(|ca| ca < 8.0),
+            self.pao2_mmhg.is_some_and(|pao2| pao2 < 60.0),
+            self.base_deficit.is_some_and(|deficit| deficit > 4.0),
+            self.fluid_sequestration_l.is_some_and(|fluid| fluid > 6.0),
+        ]
+        .iter()
+        .filter(|&met| *met)
+        .count() as u8
+    }
+    pub fn score(&self) -> Option<u8> {
+        self.score
+    }
+}
+
+impl Ranson /* calculations */ {
+    #[must_use]
+    pub fn calculate(mut self) -> Self {
+        self.score = Some(self.admission_criteria() + self.criteria_at_48h());
+        self
+    }
+
+    /// Published mortality (%) for the score.
+    pub fn mortality_estimate(&self) -> Option<f64> {
+        self.score.map(|score| match score {
+            0..=2 => RANSON_MORTALITY_PCT[0],
+            3..=4 => RANSON_MORTALITY_PCT[1],
+            5..=6 => RANSON_MORTALITY_PCT[2],
+            _ => RANSON_MORTALITY_PCT[3],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lab::blood::{
+        ast::AstExt, bun::BunExt, calcium::SerumCalciumExt, glucose::SerumGlucoseExt,
+        hematocrit::HematocritExt, white_blood_cells::WhiteBloodCellsExt,
+    };
+
+    #[test]
+    fn score_is_none_until_calculated() {
+        let ranson = Ranson::new(Years(60.0));
+        assert!(ranson.score().is_none());
+        assert!(ranson.mortality_estimate().is_none());
+    }
+
+    #[test]
+    fn mild_pancreatitis_scores_low() {
+        let ranson = Ranson::new(Years(40.0))
+            .wbc(12.0.wbc_x10e9_l())
+            .glucose(150.0.glu_serum_mg_dl())
+            .ast(80.0.ast_u_l())
+            .ldh_u_l(250.0)
+            .hematocrit_drop(42.0.hct_pct(), 38.0.hct_pct())
+            .bun_rise(14.0.bun_mg_dl(), 16.0.bun_mg_dl())
+            .calcium(8.8.ca_serum_mg_dl())
+            .pao2_mmhg(85.0)
+            .base_deficit(1.0)
+            .fluid_sequestration_l(3.0)
+            .calculate();
+        assert_eq!(Some(0), ranson.score());
+        assert_eq!(Some(0.9), ranson.mortality_estimate());
+    }
+
+    #[test]
+    fn severe_pancreatitis_scores_high() {
+        let ranson = Ranson::new(Years(68.0))
+            .wbc(19.0.wbc_x10e9_l())
+            .glucose(14.0.glu_serum_mmol_l())
+            .ast(300.0.ast_u_l())
+            .ldh_u_l(500.0)
+            .hematocrit_drop(45.0.hct_pct(), 33.0.hct_pct())
+            .bun_rise(5.0.urea_mmol_l(), 9.0.urea_mmol_l())
+            .calcium(1.8.ca_serum_mmol_l())
+            .pao2_mmhg(70.0)
+            .base_deficit(3.0)
+            .calculate();
+        assert_eq!(5, ranson.admission_criteria());
+        // hematocrit, BUN (4 mmol/L urea ≈ 11 mg/dL) and calcium (≈ 7.2 mg/dL)
+        assert_eq!(3, ranson.criteria_at_48h());
+        assert_eq!(Some(8), ranson.score());
+        assert_eq!(Some(100.0), ranson.mortality_estimate());
+    }
+
+    #[test]
+    fn moderate_score_maps_to_sixteen_percent() {
+        let ranson = Ranson::new(Years(60.0))
+            .wbc(17.0.wbc_x10e9_l())
+            .base_deficit(5.0)
+            .calculate();
+        assert_eq!(Some(3), ranson.score());
+        assert_eq!(Some(16.0), ranson.mortality_estimate());
+    }
+}
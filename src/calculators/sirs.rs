@@ -0,0 +1,158 @@
+//! SIRS Criteria
+//!
+//! Systemic inflammatory response syndrome: two or more of abnormal temperature,
+//! tachycardia, tachypnea, or abnormal white count.
+//!
+
+use crate::{
+    lab::{
+        blood::white_blood_cells::WhiteBloodCells,
+        vitals::{RespiratoryRate, Temperature},
+    },
+    units::{vitals::TemperatureUnit, PerMinute, PerNanoLiter},
+};
+
+/// A SIRS criteria counter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sirs {
+    temp_c: f64,
+    heart_rate: f64,
+    resp_rate: f64,
+    wbc: f64,
+    paco2_low: bool,
+    bands: bool,
+    score: Option<u8>,
+}
+impl Sirs /* builder / setters */ {
+    /// Heart rate is in beats per minute.
+    pub fn new<T: TemperatureUnit>(
+        temp: Temperature<T>,
+        heart_rate: f64,
+        resp_rate: RespiratoryRate<PerMinute>,
+        wbc: WhiteBloodCells<PerNanoLiter>,
+    ) -> Self {
+        Self {
+            temp_c: T::to_celsius(temp.value()),
+            heart_rate,
+            resp_rate: resp_rate.value(),
+            wbc: wbc.value(),
+            paco2_low: false,
+            bands: false,
+            score: None,
+        }
+    }
+    /// PaCO₂ < 32 mmHg satisfies the respiratory criterion regardless of rate.
+    pub fn has_low_paco2(mut self) -> Self {
+        self.paco2_low = true;
+        self
+    }
+    /// More than 10% immature (band) forms satisfies the WBC criterion regardless of count.
+    pub fn has_bandemia(mut self) -> Self {
+        self.bands = true;
+        self
+    }
+}
+
+impl Sirs /* getters */ {
+    pub fn temperature_criterion(&self) -> bool {
+        self.temp_c < 36.0 || self.temp_c > 38.0
+    }
+    pub fn heart_rate_criterion(&self) -> bool {
+        self.heart_rate > 90.0
+    }
+    pub fn respiratory_criterion(&self) -> bool {
+        self.resp_rate > 20.0 || self.paco2_low
+    }
+    pub fn wbc_criterion(&self) -> bool {
+        self.wbc > 12.0 || self.wbc < 4.0 || self.bands
+    }
+    pub fn score(&self) -> Option<u8> {
+        self.score
+    }
+}
+
+impl Sirs /* calculations */ {
+    #[must_use]
+    pub fn calculate(mut self) -> Self {
+        let tally = [
+            self.temperature_criterion(),
+            self.heart_rate_criterion(),
+            self.respiratory_criterion(),
+            self.wbc_criterion(),
+        ]
+        .iter()
+        .filter(|&c| *c)
+        .count() as u8;
+        self.score = Some(tally);
+        self
+    }
+
+    /// True when two or more criteria are met.
+    pub fn meets_sirs(&self) -> Option<bool> {
+        self.score.map(|score| score >= 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        calculators::sirs::Sirs,
+        lab::{
+            blood::white_blood_cells::WhiteBloodCellsExt,
+            vitals::{RespiratoryRateExt, TemperatureExt},
+        },
+    };
+
+    #[test]
+    fn score_is_none_until_calculated() {
+        let sirs = Sirs::new(
+            37.0.temp_c(),
+            70.0,
+            14.0.breaths_per_min(),
+            7.0.wbc_x10e9_l(),
+        );
+        assert!(sirs.score().is_none());
+        assert!(sirs.meets_sirs().is_none());
+    }
+
+    #[test]
+    fn febrile_tachycardic_patient_meets_sirs() {
+        // 101.5 °F ≈ 38.6 °C
+        let sirs = Sirs::new(
+            101.5.temp_f(),
+            112.0,
+            16.0.breaths_per_min(),
+            9.0.wbc_x10e9_l(),
+        )
+        .calculate();
+        assert_eq!(Some(2), sirs.score());
+        assert_eq!(Some(true), sirs.meets_sirs());
+    }
+
+    #[test]
+    fn normal_vitals_and_wbc_do_not_meet_sirs() {
+        let sirs = Sirs::new(
+            98.6.temp_f(),
+            78.0,
+            14.0.breaths_per_min(),
+            7.5.wbc_x10e9_l(),
+        )
+        .calculate();
+        assert_eq!(Some(0), sirs.score());
+        assert_eq!(Some(false), sirs.meets_sirs());
+    }
+
+    #[test]
+    fn bandemia_and_low_paco2_count_toward_criteria() {
+        let sirs = Sirs::new(
+            37.0.temp_c(),
+            80.0,
+            16.0.breaths_per_min(),
+            8.0.wbc_x10e9_l(),
+        )
+        .has_bandemia()
+        .has_low_paco2()
+        .calculate();
+        assert_eq!(Some(true), sirs.meets_sirs());
+    }
+}
@@ -0,0 +1,133 @@
+//! Therapeutic drug monitoring
+//!
+//! Albumin corrections for highly protein-bound drugs. Only the unbound drug is
+//! active, but assays report the total; when albumin is low a larger share is free,
+//! so a "therapeutic" total can hide a toxic free level.
+//!
+//! All drug concentrations are in mg/L (equivalently µg/mL).
+
+use crate::{
+    lab::blood::albumin::Albumin,
+    units::{albumin::AlbuminUnit, GdL},
+};
+
+/// Parameters for a Sheiner-Tozer style correction:
+/// normalized total = measured / (albumin_coefficient × albumin + intercept),
+/// with albumin in g/dL.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BindingParams {
+    pub albumin_coefficient: f64,
+    pub intercept: f64,
+}
+impl BindingParams {
+    /// Derive the parameters for a drug whose binding to albumin is linear, from its
+    /// unbound fraction at a normal albumin concentration (g/dL).
+    pub fn from_free_fraction(free_fraction: f64, normal_albumin_g_dl: f64) -> Self {
+        Self {
+            albumin_coefficient: (1.0 - free_fraction) / normal_albumin_g_dl,
+            intercept: free_fraction,
+        }
+    }
+}
+
+/// Sheiner-Tozer parameters for phenytoin (~10% free at normal albumin).
+pub const PHENYTOIN: BindingParams = BindingParams {
+    albumin_coefficient: 0.2,
+    intercept: 0.1,
+};
+
+/// Phenytoin parameters for CrCl < 20 mL/min, where uremia further displaces
+/// phenytoin from albumin.
+pub const PHENYTOIN_RENAL_FAILURE: BindingParams = BindingParams {
+    albumin_coefficient: 0.1,
+    intercept: 0.1,
+};
+
+/// Valproate is ~90% albumin-bound at therapeutic levels and normal albumin
+/// (taken as 4.5 g/dL).
+pub const VALPROATE: BindingParams = BindingParams {
+    albumin_coefficient: 0.9 / 4.5,
+    intercept: 0.1,
+};
+
+/// Total concentration the measured level would correspond to at normal albumin,
+/// for comparison against the usual total-level therapeutic range.
+pub fn protein_bound_drug_correction<A: AlbuminUnit>(
+    measured: f64,
+    albumin: Albumin<A>,
+    params: BindingParams,
+) -> f64 {
+    let alb_g_dl = GdL::from_g_l(A::to_g_l(albumin.value()));
+    measured / (params.albumin_coefficient * alb_g_dl + params.intercept)
+}
+
+/// Albumin-corrected phenytoin (Sheiner-Tozer), in mg/L. Therapeutic total range
+/// is 10-20 mg/L.
+pub fn corrected_phenytoin<A: AlbuminUnit>(
+    measured: f64,
+    albumin: Albumin<A>,
+    renal_failure: bool,
+) -> f64 {
+    let params = if renal_failure {
+        PHENYTOIN_RENAL_FAILURE
+    } else {
+        PHENYTOIN
+    };
+    protein_bound_drug_correction(measured, albumin, params)
+}
+
+/// Estimated unbound valproate (mg/L) from a total level and albumin.
+///
+/// Assumes linear binding, so it's least reliable at high totals (> ~100 mg/L)
+/// where binding saturates and the true free fraction is higher still. Free
+/// valproate is normally ~5-15 mg/L.
+pub fn free_valproate_estimate<A: AlbuminUnit>(total: f64, albumin: Albumin<A>) -> f64 {
+    protein_bound_drug_correction(total, albumin, VALPROATE) * VALPROATE.intercept
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lab::blood::albumin::SerumAlbuminExt;
+
+    fn approx_eq(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{a} != {b}");
+    }
+
+    #[test]
+    fn phenytoin_corrected_for_low_albumin() {
+        // 8 / (0.2 × 2.0 + 0.1) = 16 mg/L: therapeutic, not subtherapeutic
+        approx_eq(corrected_phenytoin(8.0, 2.0.alb_serum_g_dl(), false), 16.0);
+        // 8 / (0.1 × 2.0 + 0.1) ≈ 26.7 mg/L in renal failure
+        approx_eq(
+            corrected_phenytoin(8.0, 20.0.alb_serum_g_l(), true),
+            8.0 / 0.3,
+        );
+    }
+
+    #[test]
+    fn valproate_free_fraction_rises_at_low_albumin() {
+        // normal albumin: 10% free
+        approx_eq(free_valproate_estimate(80.0, 4.5.alb_serum_g_dl()), 8.0);
+        // albumin 2.0: 80 × 0.1 / (0.2 × 2.0 + 0.1) = 16 mg/L, a toxic free level
+        let free = free_valproate_estimate(80.0, 2.0.alb_serum_g_dl());
+        approx_eq(free, 16.0);
+    }
+
+    #[test]
+    fn generic_correction_with_derived_params() {
+        // a drug 20% free at albumin 4.0 g/dL
+        let params = BindingParams::from_free_fraction(0.2, 4.0);
+        approx_eq(params.albumin_coefficient, 0.2);
+        // at normal albumin the correction is a no-op
+        approx_eq(
+            protein_bound_drug_correction(50.0, 4.0.alb_serum_g_dl(), params),
+            50.0,
+        );
+        // 50 / (0.2 × 3.0 + 0.2) = 62.5
+        approx_eq(
+            protein_bound_drug_correction(50.0, 30.0.alb_serum_g_l(), params),
+            62.5,
+        );
+    }
+}
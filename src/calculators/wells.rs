@@ -0,0 +1,340 @@
+//! Wells Criteria for DVT and PE
+//!
+//! Pretest probability of deep vein thrombosis (modified Wells, 2003) and of
+//! pulmonary embolism (Wells, 2000), to decide between D-dimer testing and imaging.
+//!
+
+/// DVT pretest probability by the three-tier Wells model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DvtRisk {
+    /// Score ≤ 0: ~5% prevalence.
+    Low,
+    /// Score 1-2: ~17% prevalence.
+    Moderate,
+    /// Score ≥ 3: ~53% prevalence.
+    High,
+}
+
+/// PE pretest probability by the two-tier Wells model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PeRisk {
+    /// Score ≤ 4: a negative D-dimer rules out PE.
+    Unlikely,
+    /// Score > 4: proceed to CT pulmonary angiography.
+    Likely,
+}
+
+/// A Wells DVT calculator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WellsDvt {
+    active_cancer: bool,
+    immobilization: bool,
+    bedridden_or_surgery: bool,
+    venous_tenderness: bool,
+    leg_swollen: bool,
+    calf_swelling: bool,
+    pitting_edema: bool,
+    collateral_veins: bool,
+    previous_dvt: bool,
+    alternative_diagnosis: bool,
+    score: Option<i8>,
+}
+impl Default for WellsDvt {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl WellsDvt /* builder / setters */ {
+    pub fn new() -> Self {
+        Self {
+            active_cancer: false,
+            immobilization: false,
+            bedridden_or_surgery: false,
+            venous_tenderness: false,
+            leg_swollen: false,
+            calf_swelling: false,
+            pitting_edema: false,
+            collateral_veins: false,
+            previous_dvt: false,
+            alternative_diagnosis: false,
+            score: None,
+        }
+    }
+    /// Treatment ongoing, within 6 months, or palliative.
+    pub fn active_cancer(mut self) -> Self {
+        self.active_cancer = true;
+        self
+    }
+    /// Paralysis, paresis, or recent plaster immobilization of the legs.
+    pub fn immobilization(mut self) -> Self {
+        self.immobilization = true;
+        self
+    }
+    /// Recently bedridden ≥ 3 days, or major surgery within 12 weeks.
+    pub fn bedridden_or_surgery(mut self) -> Self {
+        self.bedridden_or_surgery = true;
+        self
+    }
+    /// Localized tenderness along the deep venous system.
+    pub fn venous_tenderness(mut self) -> Self {
+        self.venous_tenderness = true;
+        self
+    }
+    /// Entire leg swollen.
+    pub fn leg_swollen(mut self) -> Self {
+        self.leg_swollen = true;
+        self
+    }
+    /// Calf swelling > 3 cm compared with the other leg.
+    pub fn calf_swelling(mut self) -> Self {
+        self.calf_swelling = true;
+        self
+    }
+    /// Pitting edema confined to the symptomatic leg.
+    pub fn pitting_edema(mut self) -> Self {
+        self.pitting_edema = true;
+        self
+    }
+    /// Collateral (nonvaricose) superficial veins.
+    pub fn collateral_veins(mut self) -> Self {
+        self.collateral_veins = true;
+        self
+    }
+    pub fn previous_dvt(mut self) -> Self {
+        self.previous_dvt = true;
+        self
+    }
+    /// An alternative diagnosis at least as likely as DVT; subtracts 2 points.
+    pub fn alternative_diagnosis(mut self) -> Self {
+        self.alternative_diagnosis = true;
+        self
+    }
+}
+
+impl WellsDvt /* getters */ {
+    pub fn score(&self) -> Option<i8> {
+        self.score
+    }
+}
+
+impl WellsDvt /* calculations */ {
+    #[must_use]
+    pub fn calculate(mut self) -> Self {
+        let points = [
+            self.active_cancer,
+            self.immobilization,
+            self.bedridden_or_surgery,
+            self.venous_tenderness,
+            self.leg_swollen,
+            self.calf_swelling,
+            self.pitting_edema,
+            self.collateral_veins,
+            self.previous_dvt,
+        ]
+        .iter()
+        .filter(|&c| *c)
+        .count() as i8;
+        let penalty = if self.alternative_diagnosis { 2 } else { 0 };
+        self.score = Some(points - penalty);
+        self
+    }
+
+    pub fn risk_category(&self) -> Option<DvtRisk> {
+        self.score.map(|score| match score {
+            i8::MIN..=0 => DvtRisk::Low,
+            1..=2 => DvtRisk::Moderate,
+            _ => DvtRisk::High,
+        })
+    }
+}
+
+/// A Wells PE calculator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WellsPe {
+    dvt_signs: bool,
+    pe_most_likely: bool,
+    tachycardia: bool,
+    immobilization: bool,
+    previous_vte: bool,
+    hemoptysis: bool,
+    active_cancer: bool,
+    score: Option<f64>,
+}
+impl Default for WellsPe {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl WellsPe /* builder / setters */ {
+    pub fn new() -> Self {
+        Self {
+            dvt_signs: false,
+            pe_most_likely: false,
+            tachycardia: false,
+            immobilization: false,
+            previous_vte: false,
+            hemoptysis: false,
+            active_cancer: false,
+            score: None,
+        }
+    }
+    /// Clinical signs and symptoms of DVT (3 points).
+    pub fn dvt_signs(mut self) -> Self {
+        self.dvt_signs = true;
+        self
+    }
+    /// PE is the most likely diagnosis (3 points).
+    pub fn pe_most_likely(mut self) -> Self {
+        self.pe_most_likely = true;
+        self
+    }
+    /// Heart rate > 100 (1.5 points).
+    pub fn tachycardia(mut self) -> Self {
+        self.tachycardia = true;
+        self
+    }
+    /// Immobilization ≥ 3 days or surgery in the previous 4 weeks (1.5 points).
+    pub fn immobilization(mut self) -> Self {
+        self.immobilization = true;
+        self
+    }
+    /// Previously diagnosed DVT or PE (1.5 points).
+    pub fn previous_vte(mut self) -> Self {
+        self.previous_vte = true;
+        self
+    }
+    /// 1 point.
+    pub fn hemoptysis(mut self) -> Self {
+        self.hemoptysis = true;
+        self
+    }
+    /// Treated within 6 months, or palliative (1 point).
+    pub fn active_cancer(mut self) -> Self {
+        self.active_cancer = true;
+        self
+    }
+}
+
+impl WellsPe /* getters */ {
+    /// The score, in half-point steps.
+    pub fn score(&self) -> Option<f64> {
+        self.score
+    }
+}
+
+impl WellsPe /* calculations */ {
+    #[must_use]
+    pub fn calculate(mut self) -> Self {
+        let criteria = [
+            (self.dvt_signs, 3.0),
+            (self.pe_most_likely, 3.0),
+            (self.tachycardia, 1.5),
+            (self.immobilization, 1.5),
+            (self.previous_vte, 1.5),
+            (self.hemoptysis, 1.0),
+            (self.active_cancer, 1.0),
+        ];
+        let total: f64 = criteria
+            .iter()
+            .filter(|(present, _)| *present)
+            .map(|(_, pts)| pts)
+            .sum();
+        // every weight is a multiple of 0.5, so this only clears float noise
+        self.score = Some((total * 2.0).round() / 2.0);
+        self
+    }
+
+    pub fn risk_category(&self) -> Option<PeRisk> {
+        self.score.map(|score| {
+            if score > 4.0 {
+                PeRisk::Likely
+            } else {
+                PeRisk::Unlikely
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Tests for WellsDvt
+
+    #[test]
+    fn dvt_score_is_none_until_calculated() {
+        let wells = WellsDvt::new().active_cancer();
+        assert!(wells.score().is_none());
+        assert!(wells.risk_category().is_none());
+    }
+
+    #[test]
+    fn dvt_alternative_diagnosis_can_go_negative() {
+        let wells = WellsDvt::new().alternative_diagnosis().calculate();
+        assert_eq!(Some(-2), wells.score());
+        assert_eq!(Some(DvtRisk::Low), wells.risk_category());
+
+        let wells = WellsDvt::new()
+            .calf_swelling()
+            .pitting_edema()
+            .alternative_diagnosis()
+            .calculate();
+        assert_eq!(Some(0), wells.score());
+        assert_eq!(Some(DvtRisk::Low), wells.risk_category());
+    }
+
+    #[test]
+    fn dvt_thresholds() {
+        let one = WellsDvt::new().immobilization().calculate();
+        assert_eq!(Some(DvtRisk::Moderate), one.risk_category());
+
+        let two = WellsDvt::new()
+            .immobilization()
+            .venous_tenderness()
+            .calculate();
+        assert_eq!(Some(DvtRisk::Moderate), two.risk_category());
+
+        let three = WellsDvt::new()
+            .active_cancer()
+            .leg_swollen()
+            .previous_dvt()
+            .calculate();
+        assert_eq!(Some(3), three.score());
+        assert_eq!(Some(DvtRisk::High), three.risk_category());
+    }
+
+    // Tests for WellsPe
+
+    #[test]
+    fn pe_score_uses_half_points() {
+        let wells = WellsPe::new().tachycardia().hemoptysis().calculate();
+        assert_eq!(Some(2.5), wells.score());
+        assert_eq!(Some(PeRisk::Unlikely), wells.risk_category());
+    }
+
+    #[test]
+    fn pe_four_is_unlikely_and_above_four_is_likely() {
+        let four = WellsPe::new().pe_most_likely().active_cancer().calculate();
+        assert_eq!(Some(4.0), four.score());
+        assert_eq!(Some(PeRisk::Unlikely), four.risk_category());
+
+        let four_and_a_half = WellsPe::new().pe_most_likely().tachycardia().calculate();
+        assert_eq!(Some(4.5), four_and_a_half.score());
+        assert_eq!(Some(PeRisk::Likely), four_and_a_half.risk_category());
+    }
+
+    #[test]
+    fn pe_maximum_score() {
+        let wells = WellsPe::new()
+            .dvt_signs()
+            .pe_most_likely()
+            .tachycardia()
+            .immobilization()
+            .previous_vte()
+            .hemoptysis()
+            .active_cancer()
+            .calculate();
+        assert_eq!(Some(12.5), wells.score());
+        assert_eq!(Some(PeRisk::Likely), wells.risk_category());
+    }
+}
@@ -31,3 +31,75 @@ pub const SBILI_MGDL_TO_UMOLL: f64 = 17.1;
 
 /// Multiply by this factor to convert µmol/L bilirubin to mg/dL
 pub const SBILI_UMOLL_TO_MGDL: f64 = SBILI_MGDL_TO_UMOLL.recip();
+
+/// Multiply by this factor to convert cortisol µg/dL to nmol/L
+pub const CORTISOL_UGDL_TO_NMOLL: f64 = 27.59;
+
+/// Multiply by this factor to convert cortisol nmol/L to µg/dL
+pub const CORTISOL_NMOLL_TO_UGDL: f64 = CORTISOL_UGDL_TO_NMOLL.recip();
+
+/// Multiply by this factor to convert calcium mg/dL to mmol/L
+pub const CA_MGDL_TO_MMOLL: f64 = CA_MMOLL_TO_MGDL.recip();
+
+/// Multiply by this factor to convert calcium mmol/L to mg/dL
+pub const CA_MMOLL_TO_MGDL: f64 = 4.008;
+
+/// Multiply by this factor to convert magnesium mg/dL to mmol/L
+pub const MG_MGDL_TO_MMOLL: f64 = 0.411;
+
+/// Multiply by this factor to convert magnesium mmol/L to mg/dL
+pub const MG_MMOLL_TO_MGDL: f64 = MG_MGDL_TO_MMOLL.recip();
+
+/// Multiply by this factor to convert phosphate mg/dL to mmol/L
+pub const PHOS_MGDL_TO_MMOLL: f64 = 0.323;
+
+/// Multiply by this factor to convert phosphate mmol/L to mg/dL
+pub const PHOS_MMOLL_TO_MGDL: f64 = PHOS_MGDL_TO_MMOLL.recip();
+
+/// Multiply by this factor to convert cholesterol (total, HDL or LDL) mg/dL to mmol/L
+pub const CHOL_MGDL_TO_MMOLL: f64 = 0.0259;
+
+/// Multiply by this factor to convert cholesterol (total, HDL or LDL) mmol/L to mg/dL
+pub const CHOL_MMOLL_TO_MGDL: f64 = CHOL_MGDL_TO_MMOLL.recip();
+
+/// Multiply by this factor to convert lactate mmol/L to mg/dL
+pub const LAC_MMOLL_TO_MGDL: f64 = 9.0;
+
+/// Multiply by this factor to convert lactate mg/dL to mmol/L
+pub const LAC_MGDL_TO_MMOLL: f64 = LAC_MMOLL_TO_MGDL.recip();
+
+/// Multiply by this factor to convert albumin g/dL to g/L
+pub const ALB_GDL_TO_GL: f64 = 10.0;
+
+/// Multiply by this factor to convert albumin g/L to g/dL
+pub const ALB_GL_TO_GDL: f64 = ALB_GDL_TO_GL.recip();
+
+/// Multiply by this factor to convert hemoglobin g/dL to g/L
+pub const HGB_GDL_TO_GL: f64 = 10.0;
+
+/// Multiply by this factor to convert hemoglobin g/L to g/dL
+pub const HGB_GL_TO_GDL: f64 = HGB_GDL_TO_GL.recip();
+
+/// Multiply by this factor to convert troponin ng/mL to ng/L
+pub const TROP_NGML_TO_NGL: f64 = 1000.0;
+
+/// Multiply by this factor to convert troponin ng/L to ng/mL
+pub const TROP_NGL_TO_NGML: f64 = TROP_NGML_TO_NGL.recip();
+
+/// Multiply by this factor to convert BUN mg/dL to urea mmol/L
+pub const BUN_MGDL_TO_UREA_MMOLL: f64 = 0.357;
+
+/// Multiply by this factor to convert urea mmol/L to BUN mg/dL
+pub const UREA_MMOLL_TO_BUN_MGDL: f64 = BUN_MGDL_TO_UREA_MMOLL.recip();
+
+/// Multiply by this factor to convert enzyme activity µkat/L to U/L
+pub const ENZ_UKATL_TO_UL: f64 = 60.0;
+
+/// Multiply by this factor to convert enzyme activity U/L to µkat/L
+pub const ENZ_UL_TO_UKATL: f64 = ENZ_UKATL_TO_UL.recip();
+
+/// Multiply by this factor to convert a cell count in ×10⁹/L to cells/L
+pub const CELLS_PER_NL_TO_PER_L: f64 = 1.0e9;
+
+/// Multiply by this factor to convert a cell count in cells/L to ×10⁹/L
+pub const CELLS_PER_L_TO_PER_NL: f64 = CELLS_PER_NL_TO_PER_L.recip();
@@ -0,0 +1,89 @@
+//! Error module
+//!
+//! Validation for raw numbers before they become measurements. The infallible
+//! `*Ext` constructors accept any f64; the `try_*` constructors check values here
+//! first so NaN, infinite, negative and implausibly high inputs are rejected rather
+//! than silently classified.
+
+/// Reasons a number can't be used as a measurement.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MeasurementError {
+    /// The value was NaN.
+    NotANumber,
+    /// The value was positive or negative infinity.
+    Infinite,
+    /// The value was below zero.
+    Negative(f64),
+    /// The value was above the plausibility ceiling for the analyte.
+    Implausible { value: f64, ceiling: f64 },
+}
+impl std::fmt::Display for MeasurementError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MeasurementError::NotANumber => write!(f, "value is not a number"),
+            MeasurementError::Infinite => write!(f, "value is infinite"),
+            MeasurementError::Negative(value) => write!(f, "negative value {value}"),
+            MeasurementError::Implausible { value, ceiling } => {
+                write!(f, "value {value} is above the plausible maximum {ceiling}")
+            }
+        }
+    }
+}
+impl std::error::Error for MeasurementError {}
+
+/// Check that `value` is finite and within `0.0..=ceiling`, returning it unchanged.
+pub fn validate_measurement(value: f64, ceiling: f64) -> Result<f64, MeasurementError> {
+    if value.is_nan() {
+        Err(MeasurementError::NotANumber)
+    } else if value.is_infinite() {
+        Err(MeasurementError::Infinite)
+    } else if value < 0.0 {
+        Err(MeasurementError::Negative(value))
+    } else if value > ceiling {
+        Err(MeasurementError::Implausible { value, ceiling })
+    } else {
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_nan_and_infinity() {
+        assert_eq!(
+            validate_measurement(f64::NAN, 100.0),
+            Err(MeasurementError::NotANumber)
+        );
+        assert_eq!(
+            validate_measurement(f64::INFINITY, 100.0),
+            Err(MeasurementError::Infinite)
+        );
+        assert_eq!(
+            validate_measurement(f64::NEG_INFINITY, 100.0),
+            Err(MeasurementError::Infinite)
+        );
+    }
+
+    #[test]
+    fn rejects_negative_and_implausible_values() {
+        assert_eq!(
+            validate_measurement(-1.0, 100.0),
+            Err(MeasurementError::Negative(-1.0))
+        );
+        assert_eq!(
+            validate_measurement(150.0, 100.0),
+            Err(MeasurementError::Implausible {
+                value: 150.0,
+                ceiling: 100.0
+            })
+        );
+    }
+
+    #[test]
+    fn accepts_zero_and_the_ceiling() {
+        assert_eq!(validate_measurement(0.0, 100.0), Ok(0.0));
+        assert_eq!(validate_measurement(100.0, 100.0), Ok(100.0));
+    }
+}
@@ -1,15 +1,150 @@
 //! History module
 //!
 //! This module contains types for historic factors that may come into play in various calculations,
-//! Examples include age and gender.
+//! Examples include age and sex at birth.
 
 /// Age in years.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Years(pub f64);
+impl Years {
+    /// An age given in months, e.g. `Years::from_months(18.0)` is 1.5 years.
+    pub fn from_months(months: f64) -> Self {
+        Years(months / MONTHS_PER_YEAR)
+    }
 
-/// Closest physiologic gender.
+    /// An age given in days, using 365.25-day years.
+    pub fn from_days(days: f64) -> Self {
+        Years(days / DAYS_PER_YEAR)
+    }
+
+    /// Bin this age into a life stage for reference-range selection.
+    ///
+    /// Cutoffs (lower bound inclusive): neonate < 28 days, infant < 1 y,
+    /// toddler < 3 y, child < 12 y, adolescent < 18 y, adult < 65 y, geriatric ≥ 65 y.
+    pub fn age_group(&self) -> AgeGroup {
+        match self.0 {
+            age if age < NEONATE_MAX_DAYS / DAYS_PER_YEAR => AgeGroup::Neonate,
+            age if age < 1.0 => AgeGroup::Infant,
+            age if age < 3.0 => AgeGroup::Toddler,
+            age if age < 12.0 => AgeGroup::Child,
+            age if age < 18.0 => AgeGroup::Adolescent,
+            age if age < 65.0 => AgeGroup::Adult,
+            _ => AgeGroup::Geriatric,
+        }
+    }
+}
+
+impl std::ops::Add for Years {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Years(self.0 + rhs.0)
+    }
+}
+impl std::ops::Sub for Years {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Years(self.0 - rhs.0)
+    }
+}
+
+const DAYS_PER_YEAR: f64 = 365.25;
+const MONTHS_PER_YEAR: f64 = 12.0;
+const NEONATE_MAX_DAYS: f64 = 28.0;
+
+/// Life stage, used to pick age-appropriate reference ranges and to branch
+/// calculators between pediatric, adult and geriatric formulas. Build one from an
+/// age with `AgeGroup::from(years)` or `years.age_group()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AgeGroup {
+    /// Under 28 days
+    Neonate,
+    /// 28 days to under 1 year
+    Infant,
+    /// 1 to under 3 years
+    Toddler,
+    /// 3 to under 12 years
+    Child,
+    /// 12 to under 18 years
+    Adolescent,
+    /// 18 to under 65 years
+    Adult,
+    /// 65 years and over (elderly)
+    Geriatric,
+}
+impl From<Years> for AgeGroup {
+    fn from(age: Years) -> Self {
+        age.age_group()
+    }
+}
+
+/// Sex assigned at birth, as used by the published equations.
+///
+/// eGFR, body water, ideal body weight, risk scores and sex-specific reference
+/// ranges were derived and validated in cohorts recorded as female or male, so
+/// their coefficients are only defined for these two values. This is a
+/// physiologic input, not gender identity. For intersex patients or those on
+/// long-term gender-affirming hormones, no validated choice exists; computing with
+/// both values and reporting the range is the usual practice (see
+/// `calculators::egfr_sex_delta`).
 #[derive(Debug, Copy, Clone, PartialEq)]
-pub enum Gender {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SexAtBirth {
     Female,
     Male,
 }
+
+/// The former name of `SexAtBirth`, kept so existing code still compiles.
+#[deprecated(note = "use `SexAtBirth`; the equations need sex at birth, not gender")]
+pub type Gender = SexAtBirth;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(deprecated)]
+    fn gender_alias_still_works() {
+        let sex: Gender = Gender::Female;
+        assert_eq!(sex, SexAtBirth::Female);
+    }
+
+    #[test]
+    fn years_from_months_and_days() {
+        assert_eq!(Years::from_months(18.0), Years(1.5));
+        assert_eq!(Years::from_days(365.25 * 2.0), Years(2.0));
+        assert_eq!(Years::from_days(27.0).age_group(), AgeGroup::Neonate);
+    }
+
+    #[test]
+    fn years_add_and_subtract() {
+        assert_eq!(Years(70.0) - Years(45.5), Years(24.5));
+        assert_eq!(Years(1.5) + Years::from_months(6.0), Years(2.0));
+        assert!(Years(65.0) > Years(64.9));
+    }
+
+    #[test]
+    fn age_groups_at_each_boundary() {
+        assert_eq!(Years(0.0).age_group(), AgeGroup::Neonate);
+        assert_eq!(Years(27.0 / 365.25).age_group(), AgeGroup::Neonate);
+        assert_eq!(Years(28.0 / 365.25).age_group(), AgeGroup::Infant);
+        assert_eq!(Years(0.99).age_group(), AgeGroup::Infant);
+        assert_eq!(Years(1.0).age_group(), AgeGroup::Toddler);
+        assert_eq!(Years(3.0).age_group(), AgeGroup::Child);
+        assert_eq!(Years(12.0).age_group(), AgeGroup::Adolescent);
+        assert_eq!(Years(17.9).age_group(), AgeGroup::Adolescent);
+        assert_eq!(Years(18.0).age_group(), AgeGroup::Adult);
+        assert_eq!(Years(64.9).age_group(), AgeGroup::Adult);
+        assert_eq!(Years(65.0).age_group(), AgeGroup::Geriatric);
+    }
+
+    #[test]
+    fn age_group_from_years() {
+        assert_eq!(AgeGroup::from(Years::from_days(10.0)), AgeGroup::Neonate);
+        assert_eq!(AgeGroup::from(Years::from_months(6.0)), AgeGroup::Infant);
+        assert_eq!(AgeGroup::from(Years(8.0)), AgeGroup::Child);
+        assert_eq!(AgeGroup::from(Years(17.99)), AgeGroup::Adolescent);
+        assert_eq!(AgeGroup::from(Years(18.0)), AgeGroup::Adult);
+        assert_eq!(AgeGroup::from(Years(80.0)), AgeGroup::Geriatric);
+    }
+}
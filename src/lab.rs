@@ -1,7 +1,24 @@
-use crate::units::Unit;
+use crate::units::{MmolL, MosmKg, NmolL, PerNanoLiter, UkatL, UmolL, Unit, GL};
+
+use reference::AnalyteKind;
+
+use blood::{
+    albumin::Albumin, alt::Alt, ast::Ast, bicarbonate::Bicarbonate, bilirubin::Bilirubin, bun::Bun,
+    calcium::Calcium, chloride::Chloride, cortisol::Cortisol, creatinine::Creatinine,
+    glucose::Glucose, osmolality::Osmolality, potassium::Potassium, sodium::Sodium,
+    white_blood_cells::WhiteBloodCells,
+};
 
 pub mod blood;
+pub mod comparison;
+pub mod concentration;
 pub mod gfr;
+pub mod panel;
+pub mod parse;
+pub mod reference;
+pub mod reference_ranges;
+pub mod series;
+pub mod urine;
 pub mod vitals;
 
 /// Trait shared by numeric lab values with defined normal and abnormal ranges
@@ -10,14 +27,62 @@ pub trait NumericRanged<U: Unit> {
     fn value(&self) -> f64;
     /// Get a descriptive category (high/low/critical) for a numeric lab result.
     fn range(&self) -> ResultRange;
+    /// Like `range()`, but against caller-supplied thresholds (e.g. pediatric,
+    /// pregnancy or institution-specific ranges) instead of the module defaults.
+    /// The thresholds must be in this value's units.
+    fn range_with(&self, thresholds: &RangeThreshold) -> ResultRange {
+        select_range(self.value(), thresholds)
+    }
     /// Get the units for this value
     fn units(&self) -> &'static str {
         U::ABBR
     }
+    /// Format the value followed by its range flag, e.g.
+    /// `Glucose (200.0 mg/dL) [High]`.
+    fn fmt_with_range(&self) -> String
+    where
+        Self: std::fmt::Display,
+    {
+        format!("{} [{}]", self, self.range())
+    }
+    /// Direction of change from `prior` (same analyte, same units) to this value.
+    /// Changes no larger than `significance` (in this value's units) read as `Flat`.
+    fn trend_from(&self, prior: &Self, significance: f64) -> TrendArrow
+    where
+        Self: Sized,
+    {
+        let delta = self.value() - prior.value();
+        if delta > significance {
+            TrendArrow::Up
+        } else if delta < -significance {
+            TrendArrow::Down
+        } else {
+            TrendArrow::Flat
+        }
+    }
+}
+
+/// Direction of change between two results, for compact display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrendArrow {
+    Up,
+    Down,
+    Flat,
+}
+impl std::fmt::Display for TrendArrow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let arrow = match self {
+            TrendArrow::Up => "↑",
+            TrendArrow::Down => "↓",
+            TrendArrow::Flat => "→",
+        };
+        write!(f, "{arrow}")
+    }
 }
 
 /// Describes possible ranges for numeric results.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ResultRange {
     CriticalLow,
     Low,
@@ -25,9 +90,48 @@ pub enum ResultRange {
     High,
     CriticalHigh,
 }
+impl std::fmt::Display for ResultRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            ResultRange::CriticalLow => "Critical Low",
+            ResultRange::Low => "Low",
+            ResultRange::Normal => "Normal",
+            ResultRange::High => "High",
+            ResultRange::CriticalHigh => "Critical High",
+        };
+        write!(f, "{label}")
+    }
+}
+impl ResultRange {
+    /// Signed severity code: 0 for normal, ±1 for low/high and ±2 for critical
+    /// results, negative below the normal range.
+    pub fn severity_code(&self) -> i8 {
+        match self {
+            ResultRange::CriticalLow => -2,
+            ResultRange::Low => -1,
+            ResultRange::Normal => 0,
+            ResultRange::High => 1,
+            ResultRange::CriticalHigh => 2,
+        }
+    }
+
+    /// Distance from normal regardless of direction: 0 for normal, 1 for low or high
+    /// and 2 for critical results. Low and High tie, as do CriticalLow and
+    /// CriticalHigh, so sorting by this key keeps equally abnormal results in their
+    /// original order; use `severity_code()` to tell the directions apart.
+    pub fn severity(&self) -> u8 {
+        self.severity_code().unsigned_abs()
+    }
+
+    /// Whether the result is beyond a critical threshold in either direction.
+    pub fn is_critical(&self) -> bool {
+        matches!(self, ResultRange::CriticalLow | ResultRange::CriticalHigh)
+    }
+}
 
 /// Holds range thresholds for numeric results.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RangeThreshold {
     pub crit_low: f64,
     pub low_norm: f64,
@@ -36,12 +140,399 @@ pub struct RangeThreshold {
 }
 
 /// Determine an named range (e.g. normal or critical high) for a given value.
+///
+/// A value exactly on a threshold belongs to the range nearer normal, so the
+/// normal range is inclusive at both ends (`low_norm..=norm_hi`) and a result is
+/// only critical once it's strictly beyond `crit_low` or `hi_crit`. Every analyte
+/// classifies through here so boundaries behave the same everywhere.
 pub fn select_range(value: f64, thresholds: &RangeThreshold) -> ResultRange {
     match value {
-        val if val <= thresholds.crit_low => ResultRange::CriticalLow,
-        val if val <= thresholds.low_norm => ResultRange::Low,
+        val if val < thresholds.crit_low => ResultRange::CriticalLow,
+        val if val < thresholds.low_norm => ResultRange::Low,
         val if val <= thresholds.norm_hi => ResultRange::Normal,
         val if val <= thresholds.hi_crit => ResultRange::High,
         _ => ResultRange::CriticalHigh,
     }
 }
+
+/// A single lab result of any supported analyte, for storing mixed results in one
+/// collection without boxing.
+///
+/// Measurements are held in SI units; the `From` impls convert conventional-unit
+/// values on the way in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LabResult {
+    Albumin(Albumin<GL>),
+    Alt(Alt<UkatL>),
+    Ast(Ast<UkatL>),
+    Bicarbonate(Bicarbonate<MmolL>),
+    Bilirubin(Bilirubin<UmolL>),
+    Bun(Bun<MmolL>),
+    Calcium(Calcium<MmolL>),
+    Chloride(Chloride<MmolL>),
+    Cortisol(Cortisol<NmolL>),
+    Creatinine(Creatinine<UmolL>),
+    Glucose(Glucose<MmolL>),
+    Osmolality(Osmolality<MosmKg>),
+    Potassium(Potassium<MmolL>),
+    Sodium(Sodium<MmolL>),
+    WhiteBloodCells(WhiteBloodCells<PerNanoLiter>),
+}
+impl LabResult {
+    /// Get a descriptive category (high/low/critical) for the inner result.
+    pub fn range(&self) -> ResultRange {
+        match self {
+            LabResult::Albumin(v) => v.range(),
+            LabResult::Alt(v) => v.range(),
+            LabResult::Ast(v) => v.range(),
+            LabResult::Bicarbonate(v) => v.range(),
+            LabResult::Bilirubin(v) => v.range(),
+            LabResult::Bun(v) => v.range(),
+            LabResult::Calcium(v) => v.range(),
+            LabResult::Chloride(v) => v.range(),
+            LabResult::Cortisol(v) => v.range(),
+            LabResult::Creatinine(v) => v.range(),
+            LabResult::Glucose(v) => v.range(),
+            LabResult::Osmolality(v) => v.range(),
+            LabResult::Potassium(v) => v.range(),
+            LabResult::Sodium(v) => v.range(),
+            LabResult::WhiteBloodCells(v) => v.range(),
+        }
+    }
+
+    /// Name of the analyte.
+    pub fn label(&self) -> &'static str {
+        match self {
+            LabResult::Albumin(_) => "Albumin",
+            LabResult::Alt(_) => "ALT",
+            LabResult::Ast(_) => "AST",
+            LabResult::Bicarbonate(_) => "Bicarbonate",
+            LabResult::Bilirubin(_) => "Bilirubin",
+            LabResult::Bun(_) => "BUN",
+            LabResult::Calcium(_) => "Calcium",
+            LabResult::Chloride(_) => "Chloride",
+            LabResult::Cortisol(_) => "Cortisol",
+            LabResult::Creatinine(_) => "Creatinine",
+            LabResult::Glucose(_) => "Glucose",
+            LabResult::Osmolality(_) => "Osmolality",
+            LabResult::Potassium(_) => "Potassium",
+            LabResult::Sodium(_) => "Sodium",
+            LabResult::WhiteBloodCells(_) => "WBC",
+        }
+    }
+
+    /// Which analyte this result is for.
+    pub fn kind(&self) -> AnalyteKind {
+        match self {
+            LabResult::Albumin(_) => AnalyteKind::Albumin,
+            LabResult::Alt(_) => AnalyteKind::Alt,
+            LabResult::Ast(_) => AnalyteKind::Ast,
+            LabResult::Bicarbonate(_) => AnalyteKind::Bicarbonate,
+            LabResult::Bilirubin(_) => AnalyteKind::Bilirubin,
+            LabResult::Bun(_) => AnalyteKind::Bun,
+            LabResult::Calcium(_) => AnalyteKind::Calcium,
+            LabResult::Chloride(_) => AnalyteKind::Chloride,
+            LabResult::Cortisol(_) => AnalyteKind::Cortisol,
+            LabResult::Creatinine(_) => AnalyteKind::Creatinine,
+            LabResult::Glucose(_) => AnalyteKind::Glucose,
+            LabResult::Osmolality(_) => AnalyteKind::Osmolality,
+            LabResult::Potassium(_) => AnalyteKind::Potassium,
+            LabResult::Sodium(_) => AnalyteKind::Sodium,
+            LabResult::WhiteBloodCells(_) => AnalyteKind::WhiteBloodCells,
+        }
+    }
+
+    /// Numeric value of the inner result, in the SI units it is stored in.
+    pub fn value(&self) -> f64 {
+        match self {
+            LabResult::Albumin(v) => v.value(),
+            LabResult::Alt(v) => v.value(),
+            LabResult::Ast(v) => v.value(),
+            LabResult::Bicarbonate(v) => v.value(),
+            LabResult::Bilirubin(v) => v.value(),
+            LabResult::Bun(v) => v.value(),
+            LabResult::Calcium(v) => v.value(),
+            LabResult::Chloride(v) => v.value(),
+            LabResult::Cortisol(v) => v.value(),
+            LabResult::Creatinine(v) => v.value(),
+            LabResult::Glucose(v) => v.value(),
+            LabResult::Osmolality(v) => v.value(),
+            LabResult::Potassium(v) => v.value(),
+            LabResult::Sodium(v) => v.value(),
+            LabResult::WhiteBloodCells(v) => v.value(),
+        }
+    }
+
+    /// Units abbreviation of the inner result.
+    pub fn units(&self) -> &'static str {
+        match self {
+            LabResult::Albumin(v) => v.units(),
+            LabResult::Alt(v) => v.units(),
+            LabResult::Ast(v) => v.units(),
+            LabResult::Bicarbonate(v) => v.units(),
+            LabResult::Bilirubin(v) => v.units(),
+            LabResult::Bun(v) => v.units(),
+            LabResult::Calcium(v) => v.units(),
+            LabResult::Chloride(v) => v.units(),
+            LabResult::Cortisol(v) => v.units(),
+            LabResult::Creatinine(v) => v.units(),
+            LabResult::Glucose(v) => v.units(),
+            LabResult::Osmolality(v) => v.units(),
+            LabResult::Potassium(v) => v.units(),
+            LabResult::Sodium(v) => v.units(),
+            LabResult::WhiteBloodCells(v) => v.units(),
+        }
+    }
+}
+
+/*
+ *      From impls into LabResult
+ */
+
+impl<U: Unit> From<Albumin<U>> for LabResult
+where
+    Albumin<GL>: From<Albumin<U>>,
+{
+    fn from(value: Albumin<U>) -> Self {
+        LabResult::Albumin(Albumin::from(value))
+    }
+}
+impl<U: Unit> From<Alt<U>> for LabResult
+where
+    Alt<UkatL>: From<Alt<U>>,
+{
+    fn from(value: Alt<U>) -> Self {
+        LabResult::Alt(Alt::from(value))
+    }
+}
+impl<U: Unit> From<Ast<U>> for LabResult
+where
+    Ast<UkatL>: From<Ast<U>>,
+{
+    fn from(value: Ast<U>) -> Self {
+        LabResult::Ast(Ast::from(value))
+    }
+}
+impl<U: Unit> From<Bicarbonate<U>> for LabResult
+where
+    Bicarbonate<MmolL>: From<Bicarbonate<U>>,
+{
+    fn from(value: Bicarbonate<U>) -> Self {
+        LabResult::Bicarbonate(Bicarbonate::from(value))
+    }
+}
+impl<U: Unit> From<Bilirubin<U>> for LabResult
+where
+    Bilirubin<UmolL>: From<Bilirubin<U>>,
+{
+    fn from(value: Bilirubin<U>) -> Self {
+        LabResult::Bilirubin(Bilirubin::from(value))
+    }
+}
+impl<U: Unit> From<Bun<U>> for LabResult
+where
+    Bun<MmolL>: From<Bun<U>>,
+{
+    fn from(value: Bun<U>) -> Self {
+        LabResult::Bun(Bun::from(value))
+    }
+}
+impl<U: Unit> From<Calcium<U>> for LabResult
+where
+    Calcium<MmolL>: From<Calcium<U>>,
+{
+    fn from(value: Calcium<U>) -> Self {
+        LabResult::Calcium(Calcium::from(value))
+    }
+}
+impl<U: Unit> From<Chloride<U>> for LabResult
+where
+    Chloride<MmolL>: From<Chloride<U>>,
+{
+    fn from(value: Chloride<U>) -> Self {
+        LabResult::Chloride(Chloride::from(value))
+    }
+}
+impl<U: Unit> From<Cortisol<U>> for LabResult
+where
+    Cortisol<NmolL>: From<Cortisol<U>>,
+{
+    fn from(value: Cortisol<U>) -> Self {
+        LabResult::Cortisol(Cortisol::from(value))
+    }
+}
+impl<U: Unit> From<Creatinine<U>> for LabResult
+where
+    Creatinine<UmolL>: From<Creatinine<U>>,
+{
+    fn from(value: Creatinine<U>) -> Self {
+        LabResult::Creatinine(Creatinine::from(value))
+    }
+}
+impl<U: Unit> From<Glucose<U>> for LabResult
+where
+    Glucose<MmolL>: From<Glucose<U>>,
+{
+    fn from(value: Glucose<U>) -> Self {
+        LabResult::Glucose(Glucose::from(value))
+    }
+}
+impl From<Osmolality<MosmKg>> for LabResult {
+    fn from(value: Osmolality<MosmKg>) -> Self {
+        LabResult::Osmolality(value)
+    }
+}
+impl<U: Unit> From<Potassium<U>> for LabResult
+where
+    Potassium<MmolL>: From<Potassium<U>>,
+{
+    fn from(value: Potassium<U>) -> Self {
+        LabResult::Potassium(Potassium::from(value))
+    }
+}
+impl<U: Unit> From<Sodium<U>> for LabResult
+where
+    Sodium<MmolL>: From<Sodium<U>>,
+{
+    fn from(value: Sodium<U>) -> Self {
+        LabResult::Sodium(Sodium::from(value))
+    }
+}
+impl From<WhiteBloodCells<PerNanoLiter>> for LabResult {
+    fn from(value: WhiteBloodCells<PerNanoLiter>) -> Self {
+        LabResult::WhiteBloodCells(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lab::blood::{
+        creatinine::CreatinineExt, glucose::SerumGlucoseExt, potassium::SerumPotassiumExt,
+        sodium::SerumSodiumExt, white_blood_cells::WhiteBloodCellsExt,
+    };
+
+    #[test]
+    fn select_range_boundaries_belong_to_the_range_nearer_normal() {
+        let thresholds = RangeThreshold {
+            crit_low: 1.0,
+            low_norm: 2.0,
+            norm_hi: 3.0,
+            hi_crit: 4.0,
+        };
+        assert_eq!(select_range(0.9, &thresholds), ResultRange::CriticalLow);
+        assert_eq!(select_range(1.0, &thresholds), ResultRange::Low);
+        assert_eq!(select_range(2.0, &thresholds), ResultRange::Normal);
+        assert_eq!(select_range(3.0, &thresholds), ResultRange::Normal);
+        assert_eq!(select_range(4.0, &thresholds), ResultRange::High);
+        assert_eq!(select_range(4.1, &thresholds), ResultRange::CriticalHigh);
+    }
+
+    #[test]
+    fn result_range_displays_clinician_friendly_labels() {
+        assert_eq!(ResultRange::CriticalLow.to_string(), "Critical Low");
+        assert_eq!(ResultRange::Normal.to_string(), "Normal");
+        assert_eq!(ResultRange::CriticalHigh.to_string(), "Critical High");
+    }
+
+    #[test]
+    fn fmt_with_range_appends_the_range_flag() {
+        assert_eq!(
+            250.0.glu_serum_mg_dl().fmt_with_range(),
+            "Glucose (250.0 mg/dL) [Critical High]"
+        );
+        assert_eq!(
+            140.0.na_serum_mmol().fmt_with_range(),
+            "Na (140 mmol/L) [Normal]"
+        );
+        assert!(3.1.k_serum_meq().fmt_with_range().ends_with("[Low]"));
+    }
+
+    #[test]
+    fn severity_codes_are_signed_by_direction() {
+        assert_eq!(ResultRange::CriticalLow.severity_code(), -2);
+        assert_eq!(ResultRange::Low.severity_code(), -1);
+        assert_eq!(ResultRange::Normal.severity_code(), 0);
+        assert_eq!(ResultRange::High.severity_code(), 1);
+        assert_eq!(ResultRange::CriticalHigh.severity_code(), 2);
+    }
+
+    #[test]
+    fn severity_ranks_distance_from_normal() {
+        use ResultRange::*;
+        assert!(CriticalHigh.severity() > High.severity());
+        assert!(CriticalLow.severity() > Low.severity());
+        assert_eq!(Low.severity(), High.severity());
+        let all = [CriticalLow, Low, Normal, High, CriticalHigh];
+        let least = all.iter().min_by_key(|r| r.severity()).unwrap();
+        assert_eq!(*least, Normal);
+        assert!(all.iter().all(|r| r.is_critical() == (r.severity() == 2)));
+    }
+
+    #[test]
+    fn trend_arrow_follows_significant_changes() {
+        let prior = 4.0.k_serum_meq();
+        assert_eq!(5.0.k_serum_meq().trend_from(&prior, 0.3), TrendArrow::Up);
+        assert_eq!(3.2.k_serum_meq().trend_from(&prior, 0.3), TrendArrow::Down);
+        assert_eq!(4.2.k_serum_meq().trend_from(&prior, 0.3), TrendArrow::Flat);
+    }
+
+    #[test]
+    fn trend_arrow_displays_as_arrows() {
+        assert_eq!(TrendArrow::Up.to_string(), "↑");
+        assert_eq!(TrendArrow::Down.to_string(), "↓");
+        assert_eq!(TrendArrow::Flat.to_string(), "→");
+    }
+
+    #[test]
+    fn results_sort_by_severity() {
+        let mut ranges = vec![
+            ResultRange::High,
+            ResultRange::Normal,
+            ResultRange::CriticalLow,
+            ResultRange::Low,
+        ];
+        ranges.sort_by_key(|r| std::cmp::Reverse(r.severity()));
+        assert_eq!(
+            ranges,
+            vec![
+                ResultRange::CriticalLow,
+                ResultRange::High,
+                ResultRange::Low,
+                ResultRange::Normal
+            ]
+        );
+    }
+
+    #[test]
+    fn mixed_lab_results_classify_through_the_enum() {
+        let results: Vec<LabResult> = vec![
+            128.0.na_serum_meq().into(),
+            6.4.k_serum_mmol().into(),
+            250.0.glu_serum_mg_dl().into(),
+            1.1.cr_serum_mg_dl().into(),
+            7.0.wbc_x10e9_l().into(),
+        ];
+
+        let classified: Vec<(&str, ResultRange)> =
+            results.iter().map(|r| (r.label(), r.range())).collect();
+        assert_eq!(
+            classified,
+            vec![
+                ("Sodium", ResultRange::CriticalLow),
+                ("Potassium", ResultRange::CriticalHigh),
+                ("Glucose", ResultRange::CriticalHigh),
+                ("Creatinine", ResultRange::Normal),
+                ("WBC", ResultRange::Normal),
+            ]
+        );
+    }
+
+    #[test]
+    fn conventional_units_are_stored_as_si() {
+        match LabResult::from(18.0.glu_serum_mg_dl()) {
+            LabResult::Glucose(glu) => assert!((glu.value() - 1.0).abs() < 1e-9),
+            other => panic!("unexpected variant {:?}", other),
+        }
+    }
+}
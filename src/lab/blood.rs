@@ -1,5 +1,29 @@
+pub mod albumin;
+pub mod alt;
+pub mod anion_gap;
+pub mod ast;
+pub mod bicarbonate;
 pub mod bilirubin;
+pub mod bun;
+pub mod calcium;
+pub mod chloride;
+pub mod cholesterol;
+pub mod cortisol;
 pub mod creatinine;
+pub mod cystatin_c;
+pub mod differential;
 pub mod glucose;
+pub mod hba1c;
+pub mod hematocrit;
+pub mod hemoglobin;
 pub mod inr;
+pub mod lactate;
+pub mod magnesium;
+pub mod natriuretic;
+pub mod osmolality;
+pub mod phosphate;
+pub mod platelets;
+pub mod potassium;
 pub mod sodium;
+pub mod troponin;
+pub mod white_blood_cells;
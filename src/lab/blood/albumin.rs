@@ -0,0 +1,151 @@
+//! Serum albumin module
+//!
+//! Conventional units = g/dL, SI units = g/L (1 g/dL = 10 g/L).
+
+use std::marker::PhantomData;
+
+use crate::{
+    constants::{ALB_GDL_TO_GL, ALB_GL_TO_GDL},
+    lab::{concentration::Concentration, select_range, NumericRanged, RangeThreshold, ResultRange},
+    units::{analyte, format_value, GdL, Unit, GL},
+};
+
+/// Default thresholds for lab alert ranges for serum albumin, in g/dL.
+pub(crate) const ALB_SERUM_THRESHOLDS_G_DL: RangeThreshold = RangeThreshold {
+    crit_low: 1.5,
+    low_norm: 3.5,
+    norm_hi: 5.0,
+    hi_crit: 6.0,
+};
+
+/// Default thresholds for lab alert ranges for serum albumin, in g/L.
+pub(crate) const ALB_SERUM_THRESHOLDS_G_L: RangeThreshold = RangeThreshold {
+    crit_low: ALB_SERUM_THRESHOLDS_G_DL.crit_low * ALB_GDL_TO_GL,
+    low_norm: ALB_SERUM_THRESHOLDS_G_DL.low_norm * ALB_GDL_TO_GL,
+    norm_hi: ALB_SERUM_THRESHOLDS_G_DL.norm_hi * ALB_GDL_TO_GL,
+    hi_crit: ALB_SERUM_THRESHOLDS_G_DL.hi_crit * ALB_GDL_TO_GL,
+};
+
+/*
+ *               Type and inherent methods
+ */
+
+/// A serum albumin measurement.
+pub type Albumin<U> = Concentration<analyte::Albumin, U>;
+#[cfg(feature = "serde")]
+crate::serialize::impl_serde_measurement!(Albumin, _ghost);
+impl<U: Unit> std::fmt::Display for Albumin<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Albumin ({} {})", format_value(self.value, 1), U::ABBR)
+    }
+}
+
+/*
+ *         Convenience constructors from f64 values
+ */
+
+/// Defines convenience constructors for serum albumin measurements from f64 values.
+pub trait SerumAlbuminExt {
+    fn alb_serum_g_dl(self) -> Albumin<GdL>;
+    fn alb_serum_g_l(self) -> Albumin<GL>;
+}
+impl SerumAlbuminExt for f64 {
+    fn alb_serum_g_dl(self) -> Albumin<GdL> {
+        Albumin::from(self)
+    }
+
+    fn alb_serum_g_l(self) -> Albumin<GL> {
+        Albumin::from(self)
+    }
+}
+
+/*
+ *          FROM impls
+ */
+
+// creation from f64
+impl From<f64> for Albumin<GdL> {
+    fn from(value: f64) -> Self {
+        Albumin {
+            value,
+            _ghost: PhantomData,
+        }
+    }
+}
+impl From<f64> for Albumin<GL> {
+    fn from(value: f64) -> Self {
+        Albumin {
+            value,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+// conversion between g/dL and g/L types
+impl From<Albumin<GL>> for Albumin<GdL> {
+    fn from(other: Albumin<GL>) -> Self {
+        Albumin {
+            value: other.value * ALB_GL_TO_GDL,
+            _ghost: PhantomData,
+        }
+    }
+}
+impl From<Albumin<GdL>> for Albumin<GL> {
+    fn from(other: Albumin<GdL>) -> Self {
+        Albumin {
+            value: other.value * ALB_GDL_TO_GL,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+/*
+ *      NumericRanged impls
+ */
+
+impl NumericRanged<GdL> for Albumin<GdL> {
+    fn value(&self) -> f64 {
+        self.value()
+    }
+
+    fn range(&self) -> ResultRange {
+        select_range(self.value, &ALB_SERUM_THRESHOLDS_G_DL)
+    }
+}
+impl NumericRanged<GL> for Albumin<GL> {
+    fn value(&self) -> f64 {
+        self.value()
+    }
+
+    fn range(&self) -> ResultRange {
+        select_range(self.value, &ALB_SERUM_THRESHOLDS_G_L)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-6, "{} !~= {}", a, b);
+    }
+
+    #[test]
+    fn albumin_unit_conversions_round_trip() {
+        let g_dl = 3.2.alb_serum_g_dl();
+        let as_g_l: Albumin<GL> = Albumin::from(g_dl);
+        approx_eq(as_g_l.value(), 32.0);
+
+        let back: Albumin<GdL> = Albumin::from(as_g_l);
+        approx_eq(back.value(), 3.2);
+    }
+
+    #[test]
+    fn albumin_ranges_are_selected_correctly() {
+        assert_eq!(1.2.alb_serum_g_dl().range(), ResultRange::CriticalLow);
+        assert_eq!(2.8.alb_serum_g_dl().range(), ResultRange::Low);
+        assert_eq!(4.2.alb_serum_g_dl().range(), ResultRange::Normal);
+        assert_eq!(52.0.alb_serum_g_l().range(), ResultRange::High);
+        assert_eq!(28.0.alb_serum_g_l().range(), ResultRange::Low);
+    }
+}
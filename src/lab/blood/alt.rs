@@ -0,0 +1,161 @@
+//! Alanine aminotransferase (ALT) module
+//!
+//! Enzyme activity is conventionally reported in U/L; the SI unit is µkat/L, where
+//! 1 µkat/L = 60 U/L.
+
+use std::marker::PhantomData;
+
+use crate::{
+    constants::{ENZ_UKATL_TO_UL, ENZ_UL_TO_UKATL},
+    lab::{select_range, NumericRanged, RangeThreshold, ResultRange},
+    units::{format_value, UkatL, Unit, UL},
+};
+
+/// Default thresholds for lab alert ranges for ALT, in U/L.
+pub(crate) const ALT_THRESHOLDS_U_L: RangeThreshold = RangeThreshold {
+    crit_low: 0.0,
+    low_norm: 7.0,
+    norm_hi: 56.0,
+    hi_crit: 1000.0,
+};
+
+/// Default thresholds for lab alert ranges for ALT, in µkat/L.
+pub(crate) const ALT_THRESHOLDS_UKAT_L: RangeThreshold = RangeThreshold {
+    crit_low: ALT_THRESHOLDS_U_L.crit_low * ENZ_UL_TO_UKATL,
+    low_norm: ALT_THRESHOLDS_U_L.low_norm * ENZ_UL_TO_UKATL,
+    norm_hi: ALT_THRESHOLDS_U_L.norm_hi * ENZ_UL_TO_UKATL,
+    hi_crit: ALT_THRESHOLDS_U_L.hi_crit * ENZ_UL_TO_UKATL,
+};
+
+/*
+ *               Type and inherent methods
+ */
+
+/// An ALT measurement.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Alt<U: Unit> {
+    value: f64,
+    _ghost: PhantomData<U>,
+}
+#[cfg(feature = "serde")]
+crate::serialize::impl_serde_measurement!(Alt, _ghost);
+impl<U: Unit> Alt<U> {
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+impl<U: Unit> std::fmt::Display for Alt<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ALT ({} {})", format_value(self.value, 0), U::ABBR)
+    }
+}
+
+/*
+ *         Convenience constructors from f64 values
+ */
+
+/// Defines convenience constructors for ALT measurements from f64 values.
+pub trait AltExt {
+    fn alt_u_l(self) -> Alt<UL>;
+    fn alt_ukat_l(self) -> Alt<UkatL>;
+}
+impl AltExt for f64 {
+    fn alt_u_l(self) -> Alt<UL> {
+        Alt::from(self)
+    }
+
+    fn alt_ukat_l(self) -> Alt<UkatL> {
+        Alt::from(self)
+    }
+}
+
+/*
+ *          FROM impls
+ */
+
+// creation from f64
+impl From<f64> for Alt<UL> {
+    fn from(value: f64) -> Self {
+        Alt {
+            value,
+            _ghost: PhantomData,
+        }
+    }
+}
+impl From<f64> for Alt<UkatL> {
+    fn from(value: f64) -> Self {
+        Alt {
+            value,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+// conversion between U/L and µkat/L types
+impl From<Alt<UkatL>> for Alt<UL> {
+    fn from(other: Alt<UkatL>) -> Self {
+        Alt {
+            value: other.value * ENZ_UKATL_TO_UL,
+            _ghost: PhantomData,
+        }
+    }
+}
+impl From<Alt<UL>> for Alt<UkatL> {
+    fn from(other: Alt<UL>) -> Self {
+        Alt {
+            value: other.value * ENZ_UL_TO_UKATL,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+/*
+ *      NumericRanged impls
+ */
+
+impl NumericRanged<UL> for Alt<UL> {
+    fn value(&self) -> f64 {
+        self.value()
+    }
+
+    fn range(&self) -> ResultRange {
+        select_range(self.value, &ALT_THRESHOLDS_U_L)
+    }
+}
+impl NumericRanged<UkatL> for Alt<UkatL> {
+    fn value(&self) -> f64 {
+        self.value()
+    }
+
+    fn range(&self) -> ResultRange {
+        select_range(self.value, &ALT_THRESHOLDS_UKAT_L)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-6, "{} !~= {}", a, b);
+    }
+
+    #[test]
+    fn alt_unit_conversions_round_trip() {
+        let u_l = 120.0.alt_u_l();
+        let as_si: Alt<UkatL> = Alt::from(u_l);
+        approx_eq(as_si.value(), 2.0);
+
+        let back: Alt<UL> = Alt::from(as_si);
+        approx_eq(back.value(), 120.0);
+    }
+
+    #[test]
+    fn alt_ranges_are_selected_correctly() {
+        assert_eq!(5.0.alt_u_l().range(), ResultRange::Low);
+        assert_eq!(25.0.alt_u_l().range(), ResultRange::Normal);
+        assert_eq!(0.5.alt_ukat_l().range(), ResultRange::Normal);
+        assert_eq!(90.0.alt_u_l().range(), ResultRange::High);
+        assert_eq!(3000.0.alt_u_l().range(), ResultRange::CriticalHigh);
+    }
+}
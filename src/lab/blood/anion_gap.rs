@@ -0,0 +1,72 @@
+//! Anion gap module
+//!
+//! The anion gap is a derived value (Na - Cl - HCO₃, optionally + K), reported in
+//! mEq/L. The default thresholds assume the gap was calculated *without* potassium;
+//! gaps that include K run roughly 4 mEq/L higher.
+
+use std::marker::PhantomData;
+
+use crate::{
+    lab::{select_range, NumericRanged, RangeThreshold, ResultRange},
+    units::{format_value, MeqL, Unit},
+};
+
+pub(crate) const ANION_GAP_THRESHOLDS: RangeThreshold = RangeThreshold {
+    crit_low: 0.0,
+    low_norm: 3.0,
+    norm_hi: 12.0,
+    hi_crit: 20.0,
+};
+
+/// The result of an anion gap calculation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnionGap<U: Unit> {
+    value: f64,
+    _ghost: PhantomData<U>,
+}
+#[cfg(feature = "serde")]
+crate::serialize::impl_serde_measurement!(AnionGap, _ghost);
+
+impl<U: Unit> AnionGap<U> {
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+impl<U: Unit> std::fmt::Display for AnionGap<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Anion gap ({} {})", format_value(self.value, 0), U::ABBR)
+    }
+}
+
+impl From<f64> for AnionGap<MeqL> {
+    fn from(value: f64) -> Self {
+        AnionGap {
+            value,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+impl NumericRanged<MeqL> for AnionGap<MeqL> {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn range(&self) -> ResultRange {
+        select_range(self.value, &ANION_GAP_THRESHOLDS)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn anion_gap_above_12_is_elevated() {
+        assert_eq!(AnionGap::from(10.0).range(), ResultRange::Normal);
+        assert_eq!(AnionGap::from(12.0).range(), ResultRange::Normal);
+        assert_eq!(AnionGap::from(16.0).range(), ResultRange::High);
+        assert_eq!(AnionGap::from(26.0).range(), ResultRange::CriticalHigh);
+    }
+}
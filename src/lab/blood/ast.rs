@@ -0,0 +1,161 @@
+//! Aspartate aminotransferase (AST) module
+//!
+//! Enzyme activity is conventionally reported in U/L; the SI unit is µkat/L, where
+//! 1 µkat/L = 60 U/L.
+
+use std::marker::PhantomData;
+
+use crate::{
+    constants::{ENZ_UKATL_TO_UL, ENZ_UL_TO_UKATL},
+    lab::{select_range, NumericRanged, RangeThreshold, ResultRange},
+    units::{format_value, UkatL, Unit, UL},
+};
+
+/// Default thresholds for lab alert ranges for AST, in U/L.
+pub(crate) const AST_THRESHOLDS_U_L: RangeThreshold = RangeThreshold {
+    crit_low: 0.0,
+    low_norm: 10.0,
+    norm_hi: 40.0,
+    hi_crit: 1000.0,
+};
+
+/// Default thresholds for lab alert ranges for AST, in µkat/L.
+pub(crate) const AST_THRESHOLDS_UKAT_L: RangeThreshold = RangeThreshold {
+    crit_low: AST_THRESHOLDS_U_L.crit_low * ENZ_UL_TO_UKATL,
+    low_norm: AST_THRESHOLDS_U_L.low_norm * ENZ_UL_TO_UKATL,
+    norm_hi: AST_THRESHOLDS_U_L.norm_hi * ENZ_UL_TO_UKATL,
+    hi_crit: AST_THRESHOLDS_U_L.hi_crit * ENZ_UL_TO_UKATL,
+};
+
+/*
+ *               Type and inherent methods
+ */
+
+/// An AST measurement.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ast<U: Unit> {
+    value: f64,
+    _ghost: PhantomData<U>,
+}
+#[cfg(feature = "serde")]
+crate::serialize::impl_serde_measurement!(Ast, _ghost);
+impl<U: Unit> Ast<U> {
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+impl<U: Unit> std::fmt::Display for Ast<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AST ({} {})", format_value(self.value, 0), U::ABBR)
+    }
+}
+
+/*
+ *         Convenience constructors from f64 values
+ */
+
+/// Defines convenience constructors for AST measurements from f64 values.
+pub trait AstExt {
+    fn ast_u_l(self) -> Ast<UL>;
+    fn ast_ukat_l(self) -> Ast<UkatL>;
+}
+impl AstExt for f64 {
+    fn ast_u_l(self) -> Ast<UL> {
+        Ast::from(self)
+    }
+
+    fn ast_ukat_l(self) -> Ast<UkatL> {
+        Ast::from(self)
+    }
+}
+
+/*
+ *          FROM impls
+ */
+
+// creation from f64
+impl From<f64> for Ast<UL> {
+    fn from(value: f64) -> Self {
+        Ast {
+            value,
+            _ghost: PhantomData,
+        }
+    }
+}
+impl From<f64> for Ast<UkatL> {
+    fn from(value: f64) -> Self {
+        Ast {
+            value,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+// conversion between U/L and µkat/L types
+impl From<Ast<UkatL>> for Ast<UL> {
+    fn from(other: Ast<UkatL>) -> Self {
+        Ast {
+            value: other.value * ENZ_UKATL_TO_UL,
+            _ghost: PhantomData,
+        }
+    }
+}
+impl From<Ast<UL>> for Ast<UkatL> {
+    fn from(other: Ast<UL>) -> Self {
+        Ast {
+            value: other.value * ENZ_UL_TO_UKATL,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+/*
+ *      NumericRanged impls
+ */
+
+impl NumericRanged<UL> for Ast<UL> {
+    fn value(&self) -> f64 {
+        self.value()
+    }
+
+    fn range(&self) -> ResultRange {
+        select_range(self.value, &AST_THRESHOLDS_U_L)
+    }
+}
+impl NumericRanged<UkatL> for Ast<UkatL> {
+    fn value(&self) -> f64 {
+        self.value()
+    }
+
+    fn range(&self) -> ResultRange {
+        select_range(self.value, &AST_THRESHOLDS_UKAT_L)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-6, "{} !~= {}", a, b);
+    }
+
+    #[test]
+    fn ast_unit_conversions_round_trip() {
+        let u_l = 120.0.ast_u_l();
+        let as_si: Ast<UkatL> = Ast::from(u_l);
+        approx_eq(as_si.value(), 2.0);
+
+        let back: Ast<UL> = Ast::from(as_si);
+        approx_eq(back.value(), 120.0);
+    }
+
+    #[test]
+    fn ast_ranges_are_selected_correctly() {
+        assert_eq!(8.0.ast_u_l().range(), ResultRange::Low);
+        assert_eq!(25.0.ast_u_l().range(), ResultRange::Normal);
+        assert_eq!(0.5.ast_ukat_l().range(), ResultRange::Normal);
+        assert_eq!(85.0.ast_u_l().range(), ResultRange::High);
+        assert_eq!(3000.0.ast_u_l().range(), ResultRange::CriticalHigh);
+    }
+}
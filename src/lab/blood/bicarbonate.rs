@@ -0,0 +1,123 @@
+//! Bicarbonate (serum) module
+//!
+//! Usually reported as "total CO₂" on a basic metabolic panel. Bicarbonate (HCO₃⁻)
+//! is monovalent, so mEq/L and mmol/L are numerically identical.
+
+use std::marker::PhantomData;
+
+use crate::{
+    lab::{concentration::Concentration, select_range, NumericRanged, RangeThreshold, ResultRange},
+    units::{analyte, format_value, MeqL, MmolL, Unit},
+};
+
+pub(crate) const HCO3_SERUM_THRESHOLDS: RangeThreshold = RangeThreshold {
+    crit_low: 10.0,
+    low_norm: 22.0,
+    norm_hi: 29.0,
+    hi_crit: 40.0,
+};
+
+/// A serum bicarbonate measurement.
+pub type Bicarbonate<U> = Concentration<analyte::Bicarbonate, U>;
+#[cfg(feature = "serde")]
+crate::serialize::impl_serde_measurement!(Bicarbonate, _ghost);
+
+impl<U: Unit> std::fmt::Display for Bicarbonate<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "HCO₃ ({} {})", format_value(self.value, 0), U::ABBR)
+    }
+}
+
+pub trait SerumBicarbonateExt {
+    fn hco3_serum_meq(self) -> Bicarbonate<MeqL>;
+    fn hco3_serum_mmol(self) -> Bicarbonate<MmolL>;
+}
+
+impl SerumBicarbonateExt for f64 {
+    fn hco3_serum_meq(self) -> Bicarbonate<MeqL> {
+        Bicarbonate::from(self)
+    }
+    fn hco3_serum_mmol(self) -> Bicarbonate<MmolL> {
+        Bicarbonate::from(self)
+    }
+}
+
+// Conventional Units (mEq / L)
+
+impl NumericRanged<MeqL> for Bicarbonate<MeqL> {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn range(&self) -> ResultRange {
+        select_range(self.value, &HCO3_SERUM_THRESHOLDS)
+    }
+}
+
+impl From<f64> for Bicarbonate<MeqL> {
+    fn from(value: f64) -> Self {
+        Bicarbonate {
+            value,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+impl From<Bicarbonate<MmolL>> for Bicarbonate<MeqL> {
+    fn from(hco3: Bicarbonate<MmolL>) -> Self {
+        Self {
+            value: hco3.value(),
+            _ghost: PhantomData,
+        }
+    }
+}
+
+// SI units
+
+impl NumericRanged<MmolL> for Bicarbonate<MmolL> {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn range(&self) -> ResultRange {
+        select_range(self.value, &HCO3_SERUM_THRESHOLDS)
+    }
+}
+
+impl From<f64> for Bicarbonate<MmolL> {
+    fn from(value: f64) -> Self {
+        Bicarbonate {
+            value,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+impl From<Bicarbonate<MeqL>> for Bicarbonate<MmolL> {
+    fn from(hco3: Bicarbonate<MeqL>) -> Self {
+        Self {
+            value: hco3.value(),
+            _ghost: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serum_hco3_ranges_are_correct() {
+        assert_eq!(8.0.hco3_serum_meq().range(), ResultRange::CriticalLow);
+        assert_eq!(18.0.hco3_serum_mmol().range(), ResultRange::Low);
+        assert_eq!(24.0.hco3_serum_meq().range(), ResultRange::Normal);
+        assert_eq!(33.0.hco3_serum_mmol().range(), ResultRange::High);
+        assert_eq!(45.0.hco3_serum_meq().range(), ResultRange::CriticalHigh);
+    }
+
+    #[test]
+    fn meq_and_mmol_convert_one_to_one() {
+        let as_mmol: Bicarbonate<MmolL> = Bicarbonate::from(24.0.hco3_serum_meq());
+        assert_eq!(as_mmol.value(), 24.0);
+    }
+}
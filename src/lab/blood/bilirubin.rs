@@ -3,8 +3,10 @@
 use std::marker::PhantomData;
 
 use crate::constants::{SBILI_MGDL_TO_UMOLL, SBILI_UMOLL_TO_MGDL};
-use crate::lab::{select_range, NumericRanged, RangeThreshold, ResultRange};
-use crate::units::{MgdL, UmolL, Unit};
+use crate::lab::{
+    concentration::Concentration, select_range, NumericRanged, RangeThreshold, ResultRange,
+};
+use crate::units::{analyte, bilirubin::BilirubinUnit, MgdL, UmolL, Unit};
 
 pub const SERUM_BILI_RANGES_MGDL: RangeThreshold = RangeThreshold {
     crit_low: 0.2,
@@ -21,22 +23,14 @@ pub const SERUM_BILI_RANGES_UMOLL: RangeThreshold = RangeThreshold {
 };
 
 /// A serum bilirubin measurement.
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct Bilirubin<U: Unit> {
-    value: f64,
-    _unit: PhantomData<U>,
-}
-impl<U: Unit> Bilirubin<U> {
-    pub fn value(&self) -> f64 {
-        self.value
-    }
-}
-
+pub type Bilirubin<U> = Concentration<analyte::Bilirubin, U>;
+#[cfg(feature = "serde")]
+crate::serialize::impl_serde_measurement!(Bilirubin, _ghost);
 impl From<Bilirubin<MgdL>> for Bilirubin<UmolL> {
     fn from(bili_mgdl: Bilirubin<MgdL>) -> Self {
         Bilirubin {
             value: bili_mgdl.value * SBILI_MGDL_TO_UMOLL,
-            _unit: PhantomData,
+            _ghost: PhantomData,
         }
     }
 }
@@ -44,11 +38,26 @@ impl From<Bilirubin<UmolL>> for Bilirubin<MgdL> {
     fn from(bili_umoll: Bilirubin<UmolL>) -> Self {
         Bilirubin {
             value: bili_umoll.value * SBILI_UMOLL_TO_MGDL,
-            _unit: PhantomData,
+            _ghost: PhantomData,
         }
     }
 }
 
+impl<U: BilirubinUnit> Bilirubin<U> {
+    /// The same bilirubin measurement in unit `T`, e.g. `.convert::<UmolL>()`. Returns the
+    /// value unchanged when `T` is already the current unit.
+    pub fn convert<T: BilirubinUnit>(&self) -> Bilirubin<T> {
+        let value = if T::ABBR == U::ABBR {
+            self.value
+        } else {
+            T::from_umoll(U::to_umoll(self.value))
+        };
+        Bilirubin {
+            value,
+            _ghost: PhantomData,
+        }
+    }
+}
 impl<U: Unit> std::fmt::Display for Bilirubin<U> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "Bilirubin ({} {})", self.value, U::ABBR)
@@ -63,14 +72,14 @@ impl BilirubinExt for f64 {
     fn serum_bili_umoll(self) -> Bilirubin<UmolL> {
         Bilirubin {
             value: self,
-            _unit: PhantomData,
+            _ghost: PhantomData,
         }
     }
 
     fn serum_bili_mgdl(self) -> Bilirubin<MgdL> {
         Bilirubin {
             value: self,
-            _unit: PhantomData,
+            _ghost: PhantomData,
         }
     }
 }
@@ -215,4 +224,18 @@ mod tests {
         approx_eq(umoll_val, 17.1);
         approx_eq(umoll_val * SBILI_UMOLL_TO_MGDL, mgdl_val);
     }
+
+    #[test]
+    fn custom_thresholds_reclassify_bilirubin() {
+        // neonatal ranges run much higher
+        let neonatal = RangeThreshold {
+            crit_low: 0.0,
+            low_norm: 0.0,
+            norm_hi: 12.0,
+            hi_crit: 20.0,
+        };
+        let bili = 8.0.serum_bili_mgdl();
+        assert_eq!(bili.range(), ResultRange::High);
+        assert_eq!(bili.range_with(&neonatal), ResultRange::Normal);
+    }
 }
@@ -0,0 +1,161 @@
+//! Blood urea nitrogen (BUN) module
+//!
+//! Conventional units report the *nitrogen* content of urea in mg/dL, whereas SI
+//! units report the whole urea molecule in mmol/L. Each urea molecule carries two
+//! nitrogen atoms (28 g/mol of N), so 1 mg/dL BUN = 0.357 mmol/L urea. The `MmolL`
+//! form of this type is therefore *urea*, not urea nitrogen.
+
+use std::marker::PhantomData;
+
+use crate::{
+    constants::{BUN_MGDL_TO_UREA_MMOLL, UREA_MMOLL_TO_BUN_MGDL},
+    lab::{concentration::Concentration, select_range, NumericRanged, RangeThreshold, ResultRange},
+    units::{analyte, format_value, MgdL, MmolL, Unit},
+};
+
+/// Default thresholds for lab alert ranges for blood urea nitrogen, in mg/dL.
+pub(crate) const BUN_THRESHOLDS_MG_DL: RangeThreshold = RangeThreshold {
+    crit_low: 2.0,
+    low_norm: 7.0,
+    norm_hi: 20.0,
+    hi_crit: 100.0,
+};
+
+/// Default thresholds for lab alert ranges for blood urea nitrogen, in mmol/L.
+pub(crate) const BUN_THRESHOLDS_MMOL_L: RangeThreshold = RangeThreshold {
+    crit_low: BUN_THRESHOLDS_MG_DL.crit_low * BUN_MGDL_TO_UREA_MMOLL,
+    low_norm: BUN_THRESHOLDS_MG_DL.low_norm * BUN_MGDL_TO_UREA_MMOLL,
+    norm_hi: BUN_THRESHOLDS_MG_DL.norm_hi * BUN_MGDL_TO_UREA_MMOLL,
+    hi_crit: BUN_THRESHOLDS_MG_DL.hi_crit * BUN_MGDL_TO_UREA_MMOLL,
+};
+
+/*
+ *               Type and inherent methods
+ */
+
+/// A blood urea nitrogen measurement.
+pub type Bun<U> = Concentration<analyte::Bun, U>;
+#[cfg(feature = "serde")]
+crate::serialize::impl_serde_measurement!(Bun, _ghost);
+impl<U: Unit> std::fmt::Display for Bun<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BUN ({} {})", format_value(self.value, 0), U::ABBR)
+    }
+}
+
+/*
+ *         Convenience constructors from f64 values
+ */
+
+/// Defines convenience constructors for blood urea nitrogen measurements from f64 values.
+pub trait BunExt {
+    fn bun_mg_dl(self) -> Bun<MgdL>;
+    fn urea_mmol_l(self) -> Bun<MmolL>;
+}
+impl BunExt for f64 {
+    fn bun_mg_dl(self) -> Bun<MgdL> {
+        Bun::from(self)
+    }
+
+    fn urea_mmol_l(self) -> Bun<MmolL> {
+        Bun::from(self)
+    }
+}
+
+/*
+ *          FROM impls
+ */
+
+// creation from f64
+impl From<f64> for Bun<MgdL> {
+    fn from(value: f64) -> Self {
+        Bun {
+            value,
+            _ghost: PhantomData,
+        }
+    }
+}
+impl From<f64> for Bun<MmolL> {
+    fn from(value: f64) -> Self {
+        Bun {
+            value,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+// conversion between mg/dL and mmol/L types
+impl From<Bun<MmolL>> for Bun<MgdL> {
+    fn from(other: Bun<MmolL>) -> Self {
+        Bun {
+            value: other.value * UREA_MMOLL_TO_BUN_MGDL,
+            _ghost: PhantomData,
+        }
+    }
+}
+impl From<Bun<MgdL>> for Bun<MmolL> {
+    fn from(other: Bun<MgdL>) -> Self {
+        Bun {
+            value: other.value * BUN_MGDL_TO_UREA_MMOLL,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+/*
+ *      NumericRanged impls
+ */
+
+impl NumericRanged<MgdL> for Bun<MgdL> {
+    fn value(&self) -> f64 {
+        self.value()
+    }
+
+    fn range(&self) -> ResultRange {
+        select_range(self.value, &BUN_THRESHOLDS_MG_DL)
+    }
+}
+impl NumericRanged<MmolL> for Bun<MmolL> {
+    fn value(&self) -> f64 {
+        self.value()
+    }
+
+    fn range(&self) -> ResultRange {
+        select_range(self.value, &BUN_THRESHOLDS_MMOL_L)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-6, "{} !~= {}", a, b);
+    }
+
+    #[test]
+    fn bun_converts_to_urea_mmol_l() {
+        let bun = 28.0.bun_mg_dl();
+        let urea: Bun<MmolL> = Bun::from(bun);
+        approx_eq(urea.value(), 28.0 * 0.357);
+
+        let back: Bun<MgdL> = Bun::from(urea);
+        approx_eq(back.value(), 28.0);
+    }
+
+    #[test]
+    fn bun_ranges_in_mg_dl_are_selected_correctly() {
+        assert_eq!(1.0.bun_mg_dl().range(), ResultRange::CriticalLow);
+        assert_eq!(5.0.bun_mg_dl().range(), ResultRange::Low);
+        assert_eq!(14.0.bun_mg_dl().range(), ResultRange::Normal);
+        assert_eq!(45.0.bun_mg_dl().range(), ResultRange::High);
+        assert_eq!(120.0.bun_mg_dl().range(), ResultRange::CriticalHigh);
+    }
+
+    #[test]
+    fn urea_ranges_in_mmol_l_match_thresholds() {
+        assert_eq!(2.0.urea_mmol_l().range(), ResultRange::Low);
+        assert_eq!(5.0.urea_mmol_l().range(), ResultRange::Normal);
+        assert_eq!(10.0.urea_mmol_l().range(), ResultRange::High);
+    }
+}
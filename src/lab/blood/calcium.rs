@@ -0,0 +1,248 @@
+//! Serum calcium module
+//!
+//! Total calcium; roughly 40% is albumin-bound, so interpret low values alongside
+//! albumin (see `calculators::corrected_calcium`).
+//! 1 mmol/L calcium = 4.008 mg/dL = 2 mEq/L (calcium is divalent)
+
+use std::marker::PhantomData;
+
+use crate::{
+    constants::{CA_MGDL_TO_MMOLL, CA_MMOLL_TO_MGDL},
+    lab::{concentration::Concentration, select_range, NumericRanged, RangeThreshold, ResultRange},
+    units::{
+        analyte,
+        calcium::{CalciumUnit, CA_VALENCE},
+        format_value, MeqL, MgdL, MmolL, Unit,
+    },
+};
+
+/// Default thresholds for lab alert ranges for serum calcium, in mg/dL.
+pub(crate) const CA_SERUM_THRESHOLDS_MG_DL: RangeThreshold = RangeThreshold {
+    crit_low: 6.5,
+    low_norm: 8.5,
+    norm_hi: 10.5,
+    hi_crit: 13.0,
+};
+
+/// Default thresholds for lab alert ranges for serum calcium, in mmol/L.
+pub(crate) const CA_SERUM_THRESHOLDS_MMOL_L: RangeThreshold = RangeThreshold {
+    crit_low: CA_SERUM_THRESHOLDS_MG_DL.crit_low * CA_MGDL_TO_MMOLL,
+    low_norm: CA_SERUM_THRESHOLDS_MG_DL.low_norm * CA_MGDL_TO_MMOLL,
+    norm_hi: CA_SERUM_THRESHOLDS_MG_DL.norm_hi * CA_MGDL_TO_MMOLL,
+    hi_crit: CA_SERUM_THRESHOLDS_MG_DL.hi_crit * CA_MGDL_TO_MMOLL,
+};
+
+/// Default thresholds for lab alert ranges for serum calcium, in mEq/L.
+pub(crate) const CA_SERUM_THRESHOLDS_MEQ_L: RangeThreshold = RangeThreshold {
+    crit_low: CA_SERUM_THRESHOLDS_MMOL_L.crit_low * CA_VALENCE as f64,
+    low_norm: CA_SERUM_THRESHOLDS_MMOL_L.low_norm * CA_VALENCE as f64,
+    norm_hi: CA_SERUM_THRESHOLDS_MMOL_L.norm_hi * CA_VALENCE as f64,
+    hi_crit: CA_SERUM_THRESHOLDS_MMOL_L.hi_crit * CA_VALENCE as f64,
+};
+
+/*
+ *               Type and inherent methods
+ */
+
+/// A serum calcium measurement.
+pub type Calcium<U> = Concentration<analyte::Calcium, U>;
+#[cfg(feature = "serde")]
+crate::serialize::impl_serde_measurement!(Calcium, _ghost);
+impl<U: Unit> std::fmt::Display for Calcium<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Calcium ({} {})", format_value(self.value, 1), U::ABBR)
+    }
+}
+
+/*
+ *         Convenience constructors from f64 values
+ */
+
+/// Defines convenience constructors for serum calcium measurements from f64 values.
+pub trait SerumCalciumExt {
+    fn ca_serum_mg_dl(self) -> Calcium<MgdL>;
+    fn ca_serum_mmol_l(self) -> Calcium<MmolL>;
+    fn ca_serum_meq_l(self) -> Calcium<MeqL>;
+}
+impl SerumCalciumExt for f64 {
+    fn ca_serum_mg_dl(self) -> Calcium<MgdL> {
+        Calcium::from(self)
+    }
+
+    fn ca_serum_mmol_l(self) -> Calcium<MmolL> {
+        Calcium::from(self)
+    }
+
+    fn ca_serum_meq_l(self) -> Calcium<MeqL> {
+        Calcium::from(self)
+    }
+}
+
+/*
+ *          FROM impls
+ */
+
+// creation from f64
+impl From<f64> for Calcium<MgdL> {
+    fn from(value: f64) -> Self {
+        Calcium {
+            value,
+            _ghost: PhantomData,
+        }
+    }
+}
+impl From<f64> for Calcium<MmolL> {
+    fn from(value: f64) -> Self {
+        Calcium {
+            value,
+            _ghost: PhantomData,
+        }
+    }
+}
+impl From<f64> for Calcium<MeqL> {
+    fn from(value: f64) -> Self {
+        Calcium {
+            value,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+// conversion between mg/dL and mmol/L types
+impl From<Calcium<MmolL>> for Calcium<MgdL> {
+    fn from(other: Calcium<MmolL>) -> Self {
+        Calcium {
+            value: other.value * CA_MMOLL_TO_MGDL,
+            _ghost: PhantomData,
+        }
+    }
+}
+impl From<Calcium<MgdL>> for Calcium<MmolL> {
+    fn from(other: Calcium<MgdL>) -> Self {
+        Calcium {
+            value: other.value * CA_MGDL_TO_MMOLL,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+// conversion to and from mEq/L, via mmol/L
+impl From<Calcium<MeqL>> for Calcium<MmolL> {
+    fn from(other: Calcium<MeqL>) -> Self {
+        Calcium {
+            value: MeqL::to_calcium_mmol_l(other.value),
+            _ghost: PhantomData,
+        }
+    }
+}
+impl From<Calcium<MmolL>> for Calcium<MeqL> {
+    fn from(other: Calcium<MmolL>) -> Self {
+        Calcium {
+            value: MeqL::from_calcium_mmol_l(other.value),
+            _ghost: PhantomData,
+        }
+    }
+}
+impl From<Calcium<MeqL>> for Calcium<MgdL> {
+    fn from(other: Calcium<MeqL>) -> Self {
+        Calcium {
+            value: MgdL::from_calcium_mmol_l(MeqL::to_calcium_mmol_l(other.value)),
+            _ghost: PhantomData,
+        }
+    }
+}
+impl From<Calcium<MgdL>> for Calcium<MeqL> {
+    fn from(other: Calcium<MgdL>) -> Self {
+        Calcium {
+            value: MeqL::from_calcium_mmol_l(MgdL::to_calcium_mmol_l(other.value)),
+            _ghost: PhantomData,
+        }
+    }
+}
+
+/*
+ *      NumericRanged impls
+ */
+
+impl NumericRanged<MgdL> for Calcium<MgdL> {
+    fn value(&self) -> f64 {
+        self.value()
+    }
+
+    fn range(&self) -> ResultRange {
+        select_range(self.value, &CA_SERUM_THRESHOLDS_MG_DL)
+    }
+}
+impl NumericRanged<MmolL> for Calcium<MmolL> {
+    fn value(&self) -> f64 {
+        self.value()
+    }
+
+    fn range(&self) -> ResultRange {
+        select_range(self.value, &CA_SERUM_THRESHOLDS_MMOL_L)
+    }
+}
+impl NumericRanged<MeqL> for Calcium<MeqL> {
+    fn value(&self) -> f64 {
+        self.value()
+    }
+
+    fn range(&self) -> ResultRange {
+        select_range(self.value, &CA_SERUM_THRESHOLDS_MEQ_L)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-6, "{} !~= {}", a, b);
+    }
+
+    #[test]
+    fn two_meq_l_calcium_is_one_mmol_l() {
+        let as_mmol: Calcium<MmolL> = 2.0.ca_serum_meq_l().into();
+        approx_eq(as_mmol.value(), 1.0);
+        let as_mg_dl: Calcium<MgdL> = 2.0.ca_serum_meq_l().into();
+        approx_eq(as_mg_dl.value(), 4.008);
+        let back: Calcium<MeqL> = as_mg_dl.into();
+        approx_eq(back.value(), 2.0);
+    }
+
+    #[test]
+    fn meq_l_calcium_classifies_like_mmol_l() {
+        let mmol = 2.3.ca_serum_mmol_l();
+        let meq: Calcium<MeqL> = mmol.into();
+        assert_eq!(meq.range(), mmol.range());
+        assert_eq!(1.2.ca_serum_meq_l().range(), ResultRange::CriticalLow);
+    }
+
+    #[test]
+    fn calcium_unit_conversions_round_trip() {
+        let mg_dl = 10.0.ca_serum_mg_dl();
+        let as_mmol: Calcium<MmolL> = Calcium::from(mg_dl);
+        approx_eq(as_mmol.value(), 10.0 / 4.008);
+
+        let back: Calcium<MgdL> = Calcium::from(as_mmol);
+        approx_eq(back.value(), 10.0);
+    }
+
+    #[test]
+    fn calcium_ranges_in_mg_dl_are_selected_correctly() {
+        assert_eq!(6.0.ca_serum_mg_dl().range(), ResultRange::CriticalLow);
+        assert_eq!(7.8.ca_serum_mg_dl().range(), ResultRange::Low);
+        assert_eq!(9.4.ca_serum_mg_dl().range(), ResultRange::Normal);
+        assert_eq!(11.5.ca_serum_mg_dl().range(), ResultRange::High);
+        assert_eq!(14.0.ca_serum_mg_dl().range(), ResultRange::CriticalHigh);
+    }
+
+    #[test]
+    fn calcium_ranges_in_mmol_l_match_thresholds() {
+        assert_eq!(1.5.ca_serum_mmol_l().range(), ResultRange::CriticalLow);
+        assert_eq!(2.0.ca_serum_mmol_l().range(), ResultRange::Low);
+        assert_eq!(2.35.ca_serum_mmol_l().range(), ResultRange::Normal);
+        assert_eq!(2.9.ca_serum_mmol_l().range(), ResultRange::High);
+        assert_eq!(3.5.ca_serum_mmol_l().range(), ResultRange::CriticalHigh);
+    }
+}
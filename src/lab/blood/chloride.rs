@@ -0,0 +1,124 @@
+//! Chloride (serum) module
+//!
+//! The main extracellular anion, reported on a basic metabolic panel and used
+//! with sodium and bicarbonate to calculate the anion gap. Chloride (Cl⁻) is
+//! monovalent, so mEq/L and mmol/L are numerically identical.
+
+use std::marker::PhantomData;
+
+use crate::{
+    lab::{concentration::Concentration, select_range, NumericRanged, RangeThreshold, ResultRange},
+    units::{analyte, format_value, MeqL, MmolL, Unit},
+};
+
+pub(crate) const CL_SERUM_THRESHOLDS: RangeThreshold = RangeThreshold {
+    crit_low: 80.0,
+    low_norm: 98.0,
+    norm_hi: 107.0,
+    hi_crit: 120.0,
+};
+
+/// A serum chloride measurement.
+pub type Chloride<U> = Concentration<analyte::Chloride, U>;
+#[cfg(feature = "serde")]
+crate::serialize::impl_serde_measurement!(Chloride, _ghost);
+
+impl<U: Unit> std::fmt::Display for Chloride<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Cl ({} {})", format_value(self.value, 0), U::ABBR)
+    }
+}
+
+pub trait SerumChlorideExt {
+    fn cl_serum_meq(self) -> Chloride<MeqL>;
+    fn cl_serum_mmol(self) -> Chloride<MmolL>;
+}
+
+impl SerumChlorideExt for f64 {
+    fn cl_serum_meq(self) -> Chloride<MeqL> {
+        Chloride::from(self)
+    }
+    fn cl_serum_mmol(self) -> Chloride<MmolL> {
+        Chloride::from(self)
+    }
+}
+
+// Conventional Units (mEq / L)
+
+impl NumericRanged<MeqL> for Chloride<MeqL> {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn range(&self) -> ResultRange {
+        select_range(self.value, &CL_SERUM_THRESHOLDS)
+    }
+}
+
+impl From<f64> for Chloride<MeqL> {
+    fn from(value: f64) -> Self {
+        Chloride {
+            value,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+impl From<Chloride<MmolL>> for Chloride<MeqL> {
+    fn from(chloride: Chloride<MmolL>) -> Self {
+        Self {
+            value: chloride.value(),
+            _ghost: PhantomData,
+        }
+    }
+}
+
+// SI units
+
+impl NumericRanged<MmolL> for Chloride<MmolL> {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn range(&self) -> ResultRange {
+        select_range(self.value, &CL_SERUM_THRESHOLDS)
+    }
+}
+
+impl From<f64> for Chloride<MmolL> {
+    fn from(value: f64) -> Self {
+        Chloride {
+            value,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+impl From<Chloride<MeqL>> for Chloride<MmolL> {
+    fn from(chloride: Chloride<MeqL>) -> Self {
+        Self {
+            value: chloride.value(),
+            _ghost: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serum_chloride_ranges_are_correct() {
+        assert_eq!(75.0.cl_serum_meq().range(), ResultRange::CriticalLow);
+        assert_eq!(92.0.cl_serum_mmol().range(), ResultRange::Low);
+        assert_eq!(102.0.cl_serum_meq().range(), ResultRange::Normal);
+        assert_eq!(112.0.cl_serum_mmol().range(), ResultRange::High);
+        assert_eq!(125.0.cl_serum_meq().range(), ResultRange::CriticalHigh);
+    }
+
+    #[test]
+    fn meq_and_mmol_convert_one_to_one() {
+        let as_mmol: Chloride<MmolL> = Chloride::from(102.0.cl_serum_meq());
+        assert_eq!(as_mmol.value(), 102.0);
+    }
+}
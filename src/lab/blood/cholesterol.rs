@@ -0,0 +1,143 @@
+//! Cholesterol module
+//!
+//! Total and HDL cholesterol, as used by cardiovascular risk calculators. Both are
+//! reported in mg/dL (conventional) or mmol/L (SI): 1 mg/dL = 0.0259 mmol/L.
+//!
+//! The ranges follow NCEP ATP III: total cholesterol above 200 mg/dL is high
+//! (borderline to 239), and HDL below 40 mg/dL is low. Neither has a critical
+//! value.
+
+use std::marker::PhantomData;
+
+use crate::{
+    constants::{CHOL_MGDL_TO_MMOLL, CHOL_MMOLL_TO_MGDL},
+    lab::{concentration::define_lab_value, RangeThreshold},
+    units::{analyte, MgdL, MmolL},
+};
+
+/// Default thresholds for lab alert ranges for total cholesterol, in mg/dL.
+pub(crate) const CHOL_TOTAL_THRESHOLDS_MG_DL: RangeThreshold = RangeThreshold {
+    crit_low: 0.0,
+    low_norm: 0.0,
+    norm_hi: 200.0,
+    hi_crit: f64::INFINITY,
+};
+
+/// Default thresholds for lab alert ranges for total cholesterol, in mmol/L.
+pub(crate) const CHOL_TOTAL_THRESHOLDS_MMOL_L: RangeThreshold = RangeThreshold {
+    crit_low: CHOL_TOTAL_THRESHOLDS_MG_DL.crit_low * CHOL_MGDL_TO_MMOLL,
+    low_norm: CHOL_TOTAL_THRESHOLDS_MG_DL.low_norm * CHOL_MGDL_TO_MMOLL,
+    norm_hi: CHOL_TOTAL_THRESHOLDS_MG_DL.norm_hi * CHOL_MGDL_TO_MMOLL,
+    hi_crit: CHOL_TOTAL_THRESHOLDS_MG_DL.hi_crit * CHOL_MGDL_TO_MMOLL,
+};
+
+/// Default thresholds for lab alert ranges for HDL cholesterol, in mg/dL.
+pub(crate) const HDL_THRESHOLDS_MG_DL: RangeThreshold = RangeThreshold {
+    crit_low: 0.0,
+    low_norm: 40.0,
+    norm_hi: f64::INFINITY,
+    hi_crit: f64::INFINITY,
+};
+
+/// Default thresholds for lab alert ranges for HDL cholesterol, in mmol/L.
+pub(crate) const HDL_THRESHOLDS_MMOL_L: RangeThreshold = RangeThreshold {
+    crit_low: HDL_THRESHOLDS_MG_DL.crit_low * CHOL_MGDL_TO_MMOLL,
+    low_norm: HDL_THRESHOLDS_MG_DL.low_norm * CHOL_MGDL_TO_MMOLL,
+    norm_hi: HDL_THRESHOLDS_MG_DL.norm_hi * CHOL_MGDL_TO_MMOLL,
+    hi_crit: HDL_THRESHOLDS_MG_DL.hi_crit * CHOL_MGDL_TO_MMOLL,
+};
+
+//
+// Types and inherent methods
+//
+
+define_lab_value! {
+    /// A total cholesterol measurement.
+    Cholesterol: analyte::Cholesterol, display "Total cholesterol" with 0 decimals;
+    /// Defines methods for creating `Cholesterol<U>` directly from f64 values.
+    pub trait CholesterolExt {
+        chol_total_mg_dl => MgdL: CHOL_TOTAL_THRESHOLDS_MG_DL,
+        chol_total_mmol_l => MmolL: CHOL_TOTAL_THRESHOLDS_MMOL_L,
+    }
+}
+
+define_lab_value! {
+    /// An HDL cholesterol measurement.
+    Hdl: analyte::Hdl, display "HDL" with 0 decimals;
+    /// Defines methods for creating `Hdl<U>` directly from f64 values.
+    pub trait HdlExt {
+        hdl_mg_dl => MgdL: HDL_THRESHOLDS_MG_DL,
+        hdl_mmol_l => MmolL: HDL_THRESHOLDS_MMOL_L,
+    }
+}
+
+//
+// From impls
+//
+
+impl From<Cholesterol<MmolL>> for Cholesterol<MgdL> {
+    fn from(other: Cholesterol<MmolL>) -> Self {
+        Cholesterol {
+            value: other.value * CHOL_MMOLL_TO_MGDL,
+            _ghost: PhantomData,
+        }
+    }
+}
+impl From<Cholesterol<MgdL>> for Cholesterol<MmolL> {
+    fn from(other: Cholesterol<MgdL>) -> Self {
+        Cholesterol {
+            value: other.value * CHOL_MGDL_TO_MMOLL,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+impl From<Hdl<MmolL>> for Hdl<MgdL> {
+    fn from(other: Hdl<MmolL>) -> Self {
+        Hdl {
+            value: other.value * CHOL_MMOLL_TO_MGDL,
+            _ghost: PhantomData,
+        }
+    }
+}
+impl From<Hdl<MgdL>> for Hdl<MmolL> {
+    fn from(other: Hdl<MgdL>) -> Self {
+        Hdl {
+            value: other.value * CHOL_MGDL_TO_MMOLL,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lab::{NumericRanged, ResultRange};
+
+    fn approx_eq(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-6, "{} !~= {}", a, b);
+    }
+
+    #[test]
+    fn cholesterol_unit_conversions_round_trip() {
+        let tc: Cholesterol<MmolL> = Cholesterol::from(200.0.chol_total_mg_dl());
+        approx_eq(tc.value(), 5.18);
+        let back: Cholesterol<MgdL> = Cholesterol::from(tc);
+        approx_eq(back.value(), 200.0);
+
+        let hdl: Hdl<MgdL> = Hdl::from(1.295.hdl_mmol_l());
+        approx_eq(hdl.value(), 50.0);
+    }
+
+    #[test]
+    fn lipid_ranges_follow_atp_iii() {
+        assert_eq!(180.0.chol_total_mg_dl().range(), ResultRange::Normal);
+        assert_eq!(200.0.chol_total_mg_dl().range(), ResultRange::Normal);
+        assert_eq!(245.0.chol_total_mg_dl().range(), ResultRange::High);
+        assert_eq!(6.5.chol_total_mmol_l().range(), ResultRange::High);
+
+        assert_eq!(35.0.hdl_mg_dl().range(), ResultRange::Low);
+        assert_eq!(40.0.hdl_mg_dl().range(), ResultRange::Normal);
+        assert_eq!(2.0.hdl_mmol_l().range(), ResultRange::Normal);
+    }
+}
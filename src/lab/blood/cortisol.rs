@@ -0,0 +1,163 @@
+//! Serum cortisol module
+//!
+//! Cortisol follows a diurnal rhythm, peaking in the early morning, so the expected
+//! range depends on when the sample was drawn. `range()` assumes a morning draw;
+//! use `range_for` to pick the afternoon/evening range explicitly.
+
+use std::marker::PhantomData;
+
+use crate::constants::{CORTISOL_NMOLL_TO_UGDL, CORTISOL_UGDL_TO_NMOLL};
+use crate::lab::{
+    concentration::Concentration, select_range, NumericRanged, RangeThreshold, ResultRange,
+};
+use crate::units::{analyte, format_value, NmolL, UgdL, Unit};
+
+pub const CORTISOL_AM_RANGES_UGDL: RangeThreshold = RangeThreshold {
+    crit_low: 3.0,
+    low_norm: 5.0,
+    norm_hi: 23.0,
+    hi_crit: 50.0,
+};
+
+pub const CORTISOL_PM_RANGES_UGDL: RangeThreshold = RangeThreshold {
+    crit_low: 1.0,
+    low_norm: 3.0,
+    norm_hi: 16.0,
+    hi_crit: 50.0,
+};
+
+/// When a cortisol sample was drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrawTime {
+    /// Roughly 6-10 AM, near the diurnal peak.
+    Morning,
+    /// Roughly 4-8 PM, on the diurnal decline.
+    Afternoon,
+}
+
+/// A serum cortisol measurement.
+pub type Cortisol<U> = Concentration<analyte::Cortisol, U>;
+#[cfg(feature = "serde")]
+crate::serialize::impl_serde_measurement!(Cortisol, _ghost);
+impl Cortisol<UgdL> {
+    /// Classify against the reference range for the given draw time.
+    pub fn range_for(&self, draw: DrawTime) -> ResultRange {
+        match draw {
+            DrawTime::Morning => select_range(self.value, &CORTISOL_AM_RANGES_UGDL),
+            DrawTime::Afternoon => select_range(self.value, &CORTISOL_PM_RANGES_UGDL),
+        }
+    }
+}
+impl Cortisol<NmolL> {
+    /// Classify against the reference range for the given draw time.
+    pub fn range_for(&self, draw: DrawTime) -> ResultRange {
+        Cortisol::<UgdL>::from(*self).range_for(draw)
+    }
+}
+
+impl From<Cortisol<UgdL>> for Cortisol<NmolL> {
+    fn from(cortisol: Cortisol<UgdL>) -> Self {
+        Cortisol {
+            value: cortisol.value * CORTISOL_UGDL_TO_NMOLL,
+            _ghost: PhantomData,
+        }
+    }
+}
+impl From<Cortisol<NmolL>> for Cortisol<UgdL> {
+    fn from(cortisol: Cortisol<NmolL>) -> Self {
+        Cortisol {
+            value: cortisol.value * CORTISOL_NMOLL_TO_UGDL,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+impl<U: Unit> std::fmt::Display for Cortisol<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Cortisol ({} {})", format_value(self.value, 1), U::ABBR)
+    }
+}
+
+pub trait CortisolExt {
+    fn cortisol_ug_dl(self) -> Cortisol<UgdL>;
+    fn cortisol_nmol_l(self) -> Cortisol<NmolL>;
+}
+impl CortisolExt for f64 {
+    fn cortisol_ug_dl(self) -> Cortisol<UgdL> {
+        Cortisol {
+            value: self,
+            _ghost: PhantomData,
+        }
+    }
+
+    fn cortisol_nmol_l(self) -> Cortisol<NmolL> {
+        Cortisol {
+            value: self,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+impl NumericRanged<UgdL> for Cortisol<UgdL> {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn range(&self) -> ResultRange {
+        self.range_for(DrawTime::Morning)
+    }
+}
+
+impl NumericRanged<NmolL> for Cortisol<NmolL> {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn range(&self) -> ResultRange {
+        self.range_for(DrawTime::Morning)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-6, "{} !~= {}", a, b);
+    }
+
+    #[test]
+    fn tiny_cortisol_does_not_display_as_zero() {
+        assert_eq!(
+            0.004.cortisol_ug_dl().to_string(),
+            "Cortisol (0.0040 µg/dL)"
+        );
+        assert_eq!(12.34.cortisol_ug_dl().to_string(), "Cortisol (12.3 µg/dL)");
+    }
+
+    #[test]
+    fn cortisol_unit_conversions() {
+        let ugdl = 10.0.cortisol_ug_dl();
+        let nmol: Cortisol<NmolL> = Cortisol::from(ugdl);
+        approx_eq(nmol.value(), 275.9);
+
+        let back: Cortisol<UgdL> = Cortisol::from(nmol);
+        approx_eq(back.value(), 10.0);
+    }
+
+    #[test]
+    fn cortisol_range_depends_on_draw_time() {
+        let cortisol = 20.0.cortisol_ug_dl();
+        assert_eq!(cortisol.range_for(DrawTime::Morning), ResultRange::Normal);
+        assert_eq!(cortisol.range_for(DrawTime::Afternoon), ResultRange::High);
+        assert_eq!(cortisol.range(), ResultRange::Normal);
+    }
+
+    #[test]
+    fn cortisol_nmol_range_matches_ugdl() {
+        let ugdl = 4.0.cortisol_ug_dl();
+        let as_nmol: Cortisol<NmolL> = Cortisol::from(ugdl);
+        assert_eq!(as_nmol.range_for(DrawTime::Morning), ResultRange::Low);
+        assert_eq!(as_nmol.range_for(DrawTime::Afternoon), ResultRange::Normal);
+    }
+}
@@ -5,12 +5,13 @@ use std::marker::PhantomData;
 
 use crate::{
     constants::{SCR_MGDL_TO_UMOLL, SCR_UMOLL_TO_MGDL},
-    lab::{NumericRanged, RangeThreshold, ResultRange, select_range},
-    units::{MgdL, UmolL, Unit},
+    error::MeasurementError,
+    lab::{concentration::Concentration, select_range, NumericRanged, RangeThreshold, ResultRange},
+    units::{analyte, creatinine::CreatinineUnit, format_value, MgdL, UmolL, Unit},
 };
 
 /// Default thresholds for lab alert ranges for serum creatinine, in mg/dL.
-const SCR_THRESHOLDS_MG_DL: RangeThreshold = RangeThreshold {
+pub(crate) const SCR_THRESHOLDS_MG_DL: RangeThreshold = RangeThreshold {
     crit_low: 0.6,
     low_norm: 0.9,
     norm_hi: 1.4,
@@ -18,31 +19,47 @@ const SCR_THRESHOLDS_MG_DL: RangeThreshold = RangeThreshold {
 };
 
 /// Default thresholds for lab alert ranges for serum creatinine, in µmol/L
-const SCR_THRESHOLDS_UMOL_L: RangeThreshold = RangeThreshold {
+pub(crate) const SCR_THRESHOLDS_UMOL_L: RangeThreshold = RangeThreshold {
     crit_low: SCR_THRESHOLDS_MG_DL.crit_low * SCR_MGDL_TO_UMOLL,
     low_norm: SCR_THRESHOLDS_MG_DL.low_norm * SCR_MGDL_TO_UMOLL,
     norm_hi: SCR_THRESHOLDS_MG_DL.norm_hi * SCR_MGDL_TO_UMOLL,
     hi_crit: SCR_THRESHOLDS_MG_DL.hi_crit * SCR_MGDL_TO_UMOLL,
 };
 
+/// Highest creatinine (mg/dL) accepted by the `try_*` constructors.
+pub const SCR_PLAUSIBLE_MAX_MG_DL: f64 = 40.0;
+
 /*
  *               Type and inherent methods
  */
 
 /// A serum creatinine measurement.
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct Creatinine<U: Unit> {
-    value: f64,
-    _ghost: PhantomData<U>,
-}
-impl<U: Unit> Creatinine<U> {
-    pub fn value(&self) -> f64 {
-        self.value
+pub type Creatinine<U> = Concentration<analyte::Creatinine, U>;
+#[cfg(feature = "serde")]
+crate::serialize::impl_serde_measurement!(Creatinine, _ghost);
+impl<U: CreatinineUnit> Creatinine<U> {
+    /// The same creatinine measurement in unit `T`, e.g. `.convert::<UmolL>()`. Returns the
+    /// value unchanged when `T` is already the current unit.
+    pub fn convert<T: CreatinineUnit>(&self) -> Creatinine<T> {
+        let value = if T::ABBR == U::ABBR {
+            self.value
+        } else {
+            T::from_umol_l(U::to_umol_l(self.value))
+        };
+        Creatinine {
+            value,
+            _ghost: PhantomData,
+        }
     }
 }
 impl<U: Unit> std::fmt::Display for Creatinine<U> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Creatinine ({:.1} {})", self.value, U::ABBR)
+        write!(
+            f,
+            "Creatinine ({} {})",
+            format_value(self.value, 1),
+            U::ABBR
+        )
     }
 }
 
@@ -54,6 +71,8 @@ impl<U: Unit> std::fmt::Display for Creatinine<U> {
 pub trait CreatinineExt {
     fn cr_serum_mg_dl(self) -> Creatinine<MgdL>;
     fn cr_serum_umol_l(self) -> Creatinine<UmolL>;
+    fn try_cr_serum_mg_dl(self) -> Result<Creatinine<MgdL>, MeasurementError>;
+    fn try_cr_serum_umol_l(self) -> Result<Creatinine<UmolL>, MeasurementError>;
 }
 impl CreatinineExt for f64 {
     fn cr_serum_mg_dl(self) -> Creatinine<MgdL> {
@@ -63,6 +82,14 @@ impl CreatinineExt for f64 {
     fn cr_serum_umol_l(self) -> Creatinine<UmolL> {
         Creatinine::from(self)
     }
+
+    fn try_cr_serum_mg_dl(self) -> Result<Creatinine<MgdL>, MeasurementError> {
+        Creatinine::try_with_ceiling(self, SCR_PLAUSIBLE_MAX_MG_DL)
+    }
+
+    fn try_cr_serum_umol_l(self) -> Result<Creatinine<UmolL>, MeasurementError> {
+        Creatinine::try_with_ceiling(self, SCR_PLAUSIBLE_MAX_MG_DL * SCR_MGDL_TO_UMOLL)
+    }
 }
 
 /*
@@ -185,4 +212,32 @@ mod tests {
             ResultRange::CriticalHigh
         );
     }
+
+    #[test]
+    fn creatinine_values_compare_within_a_unit() {
+        assert!(1.4.cr_serum_mg_dl() > 0.9.cr_serum_mg_dl());
+        assert!(60.0.cr_serum_umol_l() < 110.0.cr_serum_umol_l());
+    }
+
+    #[test]
+    fn try_constructors_reject_non_physiologic_creatinine() {
+        assert!(f64::INFINITY.try_cr_serum_mg_dl().is_err());
+        assert!((-0.1).try_cr_serum_umol_l().is_err());
+        assert!(50.0.try_cr_serum_mg_dl().is_err());
+        assert_eq!(1.2.try_cr_serum_mg_dl().map(|scr| scr.value()), Ok(1.2));
+    }
+
+    #[test]
+    fn custom_thresholds_reclassify_creatinine() {
+        // a creatinine of 0.8 mg/dL is high for a young child
+        let pediatric = RangeThreshold {
+            crit_low: 0.1,
+            low_norm: 0.2,
+            norm_hi: 0.5,
+            hi_crit: 1.5,
+        };
+        let scr = 0.8.cr_serum_mg_dl();
+        assert_eq!(scr.range(), ResultRange::Low);
+        assert_eq!(scr.range_with(&pediatric), ResultRange::High);
+    }
 }
@@ -0,0 +1,45 @@
+//! Cystatin C (serum) module
+//!
+//! Cystatin C is filtered freely at the glomerulus like creatinine, but its level
+//! depends far less on muscle mass, so it gives a better GFR estimate in patients
+//! with unusually low or high muscle mass. It's reported in mg/L.
+
+use std::marker::PhantomData;
+
+use crate::{
+    lab::concentration::Concentration,
+    units::{analyte, format_value, MgL, Unit},
+};
+
+/// A serum cystatin C measurement.
+pub type CystatinC<U> = Concentration<analyte::CystatinC, U>;
+#[cfg(feature = "serde")]
+crate::serialize::impl_serde_measurement!(CystatinC, _ghost);
+impl<U: Unit> std::fmt::Display for CystatinC<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Cystatin C ({} {})",
+            format_value(self.value, 2),
+            U::ABBR
+        )
+    }
+}
+
+pub trait CystatinCExt {
+    fn cys_c_mg_l(self) -> CystatinC<MgL>;
+}
+impl CystatinCExt for f64 {
+    fn cys_c_mg_l(self) -> CystatinC<MgL> {
+        CystatinC::from(self)
+    }
+}
+
+impl From<f64> for CystatinC<MgL> {
+    fn from(value: f64) -> Self {
+        CystatinC {
+            value,
+            _ghost: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,177 @@
+//! CBC differential module
+//!
+//! A differential reports each white cell type as a percentage of the total WBC
+//! count. Multiplying by the total gives the absolute count for each type, which is
+//! what most clinical thresholds (e.g. neutropenia) are defined on.
+
+use crate::{lab::blood::white_blood_cells::WhiteBloodCells, units::PerNanoLiter};
+
+/// How far the five percentages may stray from 100% in total, allowing for
+/// rounding in reported values.
+pub const DIFFERENTIAL_SUM_TOLERANCE: f64 = 2.0;
+
+/// Problems with the percentages making up a differential.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DifferentialError {
+    /// A percentage was negative or not a number.
+    InvalidPercentage(f64),
+    /// The percentages didn't add up to ~100%.
+    SumNot100 { total: f64 },
+}
+impl std::fmt::Display for DifferentialError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DifferentialError::InvalidPercentage(pct) => {
+                write!(f, "invalid differential percentage {pct}")
+            }
+            DifferentialError::SumNot100 { total } => {
+                write!(
+                    f,
+                    "differential percentages sum to {total}%, expected ~100%"
+                )
+            }
+        }
+    }
+}
+impl std::error::Error for DifferentialError {}
+
+/// A five-part WBC differential, as percentages of the total count.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Differential {
+    neutrophils: f64,
+    lymphocytes: f64,
+    monocytes: f64,
+    eosinophils: f64,
+    basophils: f64,
+}
+impl Differential {
+    /// Validates that each percentage is non-negative and that together they sum to
+    /// 100% within `DIFFERENTIAL_SUM_TOLERANCE`.
+    pub fn new(
+        neutrophils: f64,
+        lymphocytes: f64,
+        monocytes: f64,
+        eosinophils: f64,
+        basophils: f64,
+    ) -> Result<Self, DifferentialError> {
+        let pcts = [neutrophils, lymphocytes, monocytes, eosinophils, basophils];
+        if let Some(&bad) = pcts.iter().find(|pct| pct.is_nan() || **pct < 0.0) {
+            return Err(DifferentialError::InvalidPercentage(bad));
+        }
+        let total: f64 = pcts.iter().sum();
+        if (total - 100.0).abs() > DIFFERENTIAL_SUM_TOLERANCE {
+            return Err(DifferentialError::SumNot100 { total });
+        }
+        Ok(Self {
+            neutrophils,
+            lymphocytes,
+            monocytes,
+            eosinophils,
+            basophils,
+        })
+    }
+}
+
+impl Differential /* getters */ {
+    pub fn neutrophils_pct(&self) -> f64 {
+        self.neutrophils
+    }
+    pub fn lymphocytes_pct(&self) -> f64 {
+        self.lymphocytes
+    }
+    pub fn monocytes_pct(&self) -> f64 {
+        self.monocytes
+    }
+    pub fn eosinophils_pct(&self) -> f64 {
+        self.eosinophils
+    }
+    pub fn basophils_pct(&self) -> f64 {
+        self.basophils
+    }
+}
+
+impl Differential /* absolute counts */ {
+    /// Absolute neutrophil count (ANC).
+    pub fn absolute_neutrophils(
+        &self,
+        total: WhiteBloodCells<PerNanoLiter>,
+    ) -> WhiteBloodCells<PerNanoLiter> {
+        absolute(self.neutrophils, total)
+    }
+    pub fn absolute_lymphocytes(
+        &self,
+        total: WhiteBloodCells<PerNanoLiter>,
+    ) -> WhiteBloodCells<PerNanoLiter> {
+        absolute(self.lymphocytes, total)
+    }
+    pub fn absolute_monocytes(
+        &self,
+        total: WhiteBloodCells<PerNanoLiter>,
+    ) -> WhiteBloodCells<PerNanoLiter> {
+        absolute(self.monocytes, total)
+    }
+    pub fn absolute_eosinophils(
+        &self,
+        total: WhiteBloodCells<PerNanoLiter>,
+    ) -> WhiteBloodCells<PerNanoLiter> {
+        absolute(self.eosinophils, total)
+    }
+    pub fn absolute_basophils(
+        &self,
+        total: WhiteBloodCells<PerNanoLiter>,
+    ) -> WhiteBloodCells<PerNanoLiter> {
+        absolute(self.basophils, total)
+    }
+
+    /// Neutrophil-to-lymphocyte ratio, a nonspecific marker of systemic
+    /// inflammation (roughly 1-3 in healthy adults).
+    ///
+    /// The ratio is the same whether taken from percentages or absolute counts, so
+    /// the total WBC isn't needed. Returns `None` if there are no lymphocytes.
+    pub fn neutrophil_lymphocyte_ratio(&self) -> Option<f64> {
+        (self.lymphocytes > 0.0).then(|| self.neutrophils / self.lymphocytes)
+    }
+}
+
+fn absolute(pct: f64, total: WhiteBloodCells<PerNanoLiter>) -> WhiteBloodCells<PerNanoLiter> {
+    WhiteBloodCells::from(total.value() * pct / 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lab::blood::white_blood_cells::WhiteBloodCellsExt;
+
+    #[test]
+    fn differential_must_sum_to_about_100() {
+        assert!(Differential::new(60.0, 30.0, 6.0, 3.0, 1.0).is_ok());
+        // rounding slack
+        assert!(Differential::new(60.0, 31.0, 6.0, 3.0, 1.0).is_ok());
+        assert_eq!(
+            Differential::new(60.0, 20.0, 6.0, 3.0, 1.0),
+            Err(DifferentialError::SumNot100 { total: 90.0 })
+        );
+        assert_eq!(
+            Differential::new(-5.0, 95.0, 6.0, 3.0, 1.0),
+            Err(DifferentialError::InvalidPercentage(-5.0))
+        );
+    }
+
+    #[test]
+    fn absolute_counts_come_from_total_wbc() {
+        let diff = Differential::new(70.0, 20.0, 6.0, 3.0, 1.0).unwrap();
+        let wbc = 10.0.wbc_x10e9_l();
+        assert!((diff.absolute_neutrophils(wbc).value() - 7.0).abs() < 1e-9);
+        assert!((diff.absolute_lymphocytes(wbc).value() - 2.0).abs() < 1e-9);
+        assert!((diff.absolute_basophils(wbc).value() - 0.1).abs() < 1e-9);
+    }
+
+    #[test]
+    fn neutrophil_lymphocyte_ratio() {
+        let diff = Differential::new(80.0, 10.0, 6.0, 3.0, 1.0).unwrap();
+        assert_eq!(diff.neutrophil_lymphocyte_ratio(), Some(8.0));
+
+        let no_lymphs = Differential::new(90.0, 0.0, 6.0, 3.0, 1.0).unwrap();
+        assert_eq!(no_lymphs.neutrophil_lymphocyte_ratio(), None);
+    }
+}
@@ -8,123 +8,102 @@ use std::marker::PhantomData;
 
 use crate::{
     constants::{GLU_MGDL_TO_MMOLL, GLU_MMOLL_TO_MGDL},
-    lab::{NumericRanged, RangeThreshold, ResultRange},
-    units::{MgdL, MmolL, Unit},
+    lab::{concentration::define_lab_value, RangeThreshold},
+    units::{analyte, glucose::GlucoseUnit, MgdL, MmolL},
 };
 
-const GLU_SERUM_THRESHOLDS_MGDL: RangeThreshold = RangeThreshold {
+pub(crate) const GLU_SERUM_THRESHOLDS_MGDL: RangeThreshold = RangeThreshold {
     crit_low: 60.0,
     low_norm: 85.0,
     norm_hi: 125.0,
     hi_crit: 200.0,
 };
 
-const GLU_SERUM_THRESHOLDS_MMOLL: RangeThreshold = RangeThreshold {
+pub(crate) const GLU_SERUM_THRESHOLDS_MMOLL: RangeThreshold = RangeThreshold {
     crit_low: GLU_SERUM_THRESHOLDS_MGDL.crit_low * GLU_MGDL_TO_MMOLL,
     low_norm: GLU_SERUM_THRESHOLDS_MGDL.low_norm * GLU_MGDL_TO_MMOLL,
     norm_hi: GLU_SERUM_THRESHOLDS_MGDL.norm_hi * GLU_MGDL_TO_MMOLL,
     hi_crit: GLU_SERUM_THRESHOLDS_MGDL.hi_crit * GLU_MGDL_TO_MMOLL,
 };
 
+/// Highest glucose (mg/dL) accepted by the `try_*` constructors; reported survivable
+/// values top out a little below this.
+pub const GLU_PLAUSIBLE_MAX_MG_DL: f64 = 3000.0;
+
 //
 // Type and inherent methods
 //
 
-/// A serum glucose measurement.
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct Glucose<U: Unit> {
-    value: f64,
-    _ghost: PhantomData<U>,
-}
-impl<U: Unit> Glucose<U> {
-    pub fn value(&self) -> f64 {
-        self.value
+define_lab_value! {
+    /// A serum glucose measurement.
+    Glucose: analyte::Glucose, display "Glucose" with 1 decimals;
+    /// Defines methods for creating `Glucose<U>` directly from f64 values.
+    pub trait SerumGlucoseExt {
+        glu_serum_mg_dl / try_glu_serum_mg_dl(GLU_PLAUSIBLE_MAX_MG_DL) => MgdL: GLU_SERUM_THRESHOLDS_MGDL,
+        glu_serum_mmol_l / try_glu_serum_mmol_l(GLU_PLAUSIBLE_MAX_MG_DL * GLU_MGDL_TO_MMOLL)
+            => MmolL: GLU_SERUM_THRESHOLDS_MMOLL,
     }
 }
-impl<U: Unit> std::fmt::Display for Glucose<U> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Glucose ({:.1} {})", self.value(), U::ABBR)
+impl<U: GlucoseUnit> Glucose<U> {
+    /// The same glucose measurement in unit `T`, e.g. `.convert::<MmolL>()`. Returns the
+    /// value unchanged when `T` is already the current unit.
+    pub fn convert<T: GlucoseUnit>(&self) -> Glucose<T> {
+        let value = if T::ABBR == U::ABBR {
+            self.value
+        } else {
+            T::from_mmol_l(U::to_mmol_l(self.value))
+        };
+        Glucose {
+            value,
+            _ghost: PhantomData,
+        }
     }
-}
-
-//
-// Convenience constructors from f64 values
-//
-
-/// Defines methods for creating `Glucose<U>` directly from f64 values.
-pub trait SerumGlucoseExt {
-    fn glu_serum_mg_dl(self) -> Glucose<MgdL>;
-    fn glu_serum_mmol_l(self) -> Glucose<MmolL>;
-}
 
-impl SerumGlucoseExt for f64 {
-    fn glu_serum_mg_dl(self) -> Glucose<MgdL> {
-        Glucose::from(self)
-    }
-    fn glu_serum_mmol_l(self) -> Glucose<MmolL> {
-        Glucose::from(self)
+    /// Apply the ADA diabetes diagnostic cutoffs appropriate to when the sample was
+    /// drawn.
+    ///
+    /// * Fasting: < 100 mg/dL normal, 100-125 prediabetes, ≥ 126 diabetes
+    /// * 2 h post-prandial (OGTT): < 140 normal, 140-199 prediabetes, ≥ 200 diabetes
+    /// * Random: ≥ 200 diabetes (only diagnostic with classic symptoms); there is no
+    ///   prediabetes range, so anything lower is `Normal`
+    pub fn interpret(&self, context: GlucoseContext) -> GlucoseInterpretation {
+        let mg_dl = MgdL::from_mmol_l(U::to_mmol_l(self.value));
+        let (prediabetes, diabetes) = match context {
+            GlucoseContext::Fasting => (100.0, 126.0),
+            GlucoseContext::PostPrandial2h => (140.0, 200.0),
+            GlucoseContext::Random => (200.0, 200.0),
+        };
+        match mg_dl {
+            val if val >= diabetes => GlucoseInterpretation::Diabetes,
+            val if val >= prediabetes => GlucoseInterpretation::Prediabetes,
+            _ => GlucoseInterpretation::Normal,
+        }
     }
 }
 
-//
-// NumericRanged<U> impls
-//
-
-// conventional units
-impl NumericRanged<MgdL> for Glucose<MgdL> {
-    fn value(&self) -> f64 {
-        self.value
-    }
-
-    fn range(&self) -> ResultRange {
-        match self.value {
-            val if val < GLU_SERUM_THRESHOLDS_MGDL.crit_low => ResultRange::CriticalLow,
-            val if val < GLU_SERUM_THRESHOLDS_MGDL.low_norm => ResultRange::Low,
-            val if val < GLU_SERUM_THRESHOLDS_MGDL.norm_hi => ResultRange::Normal,
-            val if val < GLU_SERUM_THRESHOLDS_MGDL.hi_crit => ResultRange::High,
-            _ => ResultRange::CriticalHigh,
-        }
-    }
+/// When a glucose sample was drawn relative to eating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GlucoseContext {
+    /// After at least 8 hours without caloric intake
+    Fasting,
+    /// Any time of day, without regard to meals
+    Random,
+    /// 2 hours into a 75 g oral glucose tolerance test
+    PostPrandial2h,
 }
-// SI units
-impl NumericRanged<MmolL> for Glucose<MmolL> {
-    fn value(&self) -> f64 {
-        self.value
-    }
-
-    fn range(&self) -> ResultRange {
-        match self.value {
-            val if val < GLU_SERUM_THRESHOLDS_MMOLL.crit_low => ResultRange::CriticalLow,
-            val if val < GLU_SERUM_THRESHOLDS_MMOLL.low_norm => ResultRange::Low,
-            val if val < GLU_SERUM_THRESHOLDS_MMOLL.norm_hi => ResultRange::Normal,
-            val if val < GLU_SERUM_THRESHOLDS_MMOLL.hi_crit => ResultRange::High,
-            _ => ResultRange::CriticalHigh,
-        }
-    }
+
+/// Diabetes diagnostic category for a glucose value in context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GlucoseInterpretation {
+    Normal,
+    Prediabetes,
+    Diabetes,
 }
 
 //
 // From impls
 //
 
-// construction from f64s
-impl From<f64> for Glucose<MgdL> {
-    fn from(value: f64) -> Self {
-        Glucose {
-            value,
-            _ghost: PhantomData,
-        }
-    }
-}
-impl From<f64> for Glucose<MmolL> {
-    fn from(value: f64) -> Self {
-        Glucose {
-            value,
-            _ghost: PhantomData,
-        }
-    }
-}
-
 // conversions from one unit type to another
 impl From<Glucose<MmolL>> for Glucose<MgdL> {
     fn from(glucose: Glucose<MmolL>) -> Self {
@@ -146,6 +125,36 @@ impl From<Glucose<MgdL>> for Glucose<MmolL> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        error::MeasurementError,
+        lab::{NumericRanged, ResultRange},
+    };
+
+    #[test]
+    fn try_constructors_reject_nan_and_negative_glucose() {
+        assert_eq!(
+            f64::NAN.try_glu_serum_mg_dl(),
+            Err(MeasurementError::NotANumber)
+        );
+        assert_eq!(
+            (-5.0).try_glu_serum_mmol_l(),
+            Err(MeasurementError::Negative(-5.0))
+        );
+        assert!(matches!(
+            5000.0.try_glu_serum_mg_dl(),
+            Err(MeasurementError::Implausible { .. })
+        ));
+        assert_eq!(110.0.try_glu_serum_mg_dl(), Ok(110.0.glu_serum_mg_dl()));
+    }
+
+    #[test]
+    fn ceiling_is_configurable() {
+        assert!(Glucose::<MgdL>::try_with_ceiling(800.0, 600.0).is_err());
+        assert_eq!(
+            Glucose::<MgdL>::try_with_ceiling(500.0, 600.0).map(|g| g.value()),
+            Ok(500.0)
+        );
+    }
 
     #[test]
     fn glucose_unit_conversions_are_correct() {
@@ -176,7 +185,83 @@ mod tests {
         assert_eq!(50.0.glu_serum_mg_dl().range(), ResultRange::CriticalLow);
         assert_eq!(75.0.glu_serum_mg_dl().range(), ResultRange::Low);
         assert_eq!(100.0.glu_serum_mg_dl().range(), ResultRange::Normal);
-        assert_eq!(125.0.glu_serum_mg_dl().range(), ResultRange::High);
+        assert_eq!(126.0.glu_serum_mg_dl().range(), ResultRange::High);
         assert_eq!(300.0.glu_serum_mg_dl().range(), ResultRange::CriticalHigh);
     }
+
+    #[test]
+    fn fasting_glucose_interpretation() {
+        use GlucoseInterpretation::*;
+        assert_eq!(
+            95.0.glu_serum_mg_dl().interpret(GlucoseContext::Fasting),
+            Normal
+        );
+        assert_eq!(
+            110.0.glu_serum_mg_dl().interpret(GlucoseContext::Fasting),
+            Prediabetes
+        );
+        assert_eq!(
+            126.0.glu_serum_mg_dl().interpret(GlucoseContext::Fasting),
+            Diabetes
+        );
+        assert_eq!(
+            7.5.glu_serum_mmol_l().interpret(GlucoseContext::Fasting),
+            Diabetes
+        );
+    }
+
+    #[test]
+    fn random_glucose_interpretation() {
+        use GlucoseInterpretation::*;
+        assert_eq!(
+            180.0.glu_serum_mg_dl().interpret(GlucoseContext::Random),
+            Normal
+        );
+        assert_eq!(
+            210.0.glu_serum_mg_dl().interpret(GlucoseContext::Random),
+            Diabetes
+        );
+    }
+
+    #[test]
+    fn post_prandial_glucose_interpretation() {
+        use GlucoseInterpretation::*;
+        let context = GlucoseContext::PostPrandial2h;
+        assert_eq!(130.0.glu_serum_mg_dl().interpret(context), Normal);
+        assert_eq!(9.0.glu_serum_mmol_l().interpret(context), Prediabetes);
+        assert_eq!(200.0.glu_serum_mg_dl().interpret(context), Diabetes);
+    }
+
+    #[test]
+    fn glucose_values_compare_within_a_unit() {
+        assert!(100.0.glu_serum_mg_dl() > 80.0.glu_serum_mg_dl());
+        assert!(4.5.glu_serum_mmol_l() <= 4.5.glu_serum_mmol_l());
+    }
+
+    #[test]
+    fn custom_thresholds_reclassify_glucose() {
+        // gestational targets: fasting glucose above 95 mg/dL is high in pregnancy
+        let pregnancy = RangeThreshold {
+            crit_low: 50.0,
+            low_norm: 60.0,
+            norm_hi: 95.0,
+            hi_crit: 200.0,
+        };
+        let glu = 110.0.glu_serum_mg_dl();
+        assert_eq!(glu.range(), ResultRange::Normal);
+        assert_eq!(glu.range_with(&pregnancy), ResultRange::High);
+    }
+
+    #[test]
+    fn glucose_boundaries_belong_to_the_range_nearer_normal() {
+        // thresholds 60 / 85 / 125 / 200 mg/dL
+        assert_eq!(59.9.glu_serum_mg_dl().range(), ResultRange::CriticalLow);
+        assert_eq!(60.0.glu_serum_mg_dl().range(), ResultRange::Low);
+        assert_eq!(84.9.glu_serum_mg_dl().range(), ResultRange::Low);
+        assert_eq!(85.0.glu_serum_mg_dl().range(), ResultRange::Normal);
+        assert_eq!(125.0.glu_serum_mg_dl().range(), ResultRange::Normal);
+        assert_eq!(125.1.glu_serum_mg_dl().range(), ResultRange::High);
+        assert_eq!(200.0.glu_serum_mg_dl().range(), ResultRange::High);
+        assert_eq!(200.1.glu_serum_mg_dl().range(), ResultRange::CriticalHigh);
+    }
 }
@@ -0,0 +1,47 @@
+//! Hemoglobin A1c module
+//!
+//! HbA1c is reported as a percentage of total hemoglobin, in NGSP (DCCT-aligned)
+//! units. It reflects average glycemia over the preceding 2-3 months.
+
+/// A hemoglobin A1c measurement, in NGSP percent.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hba1c {
+    value: f64,
+}
+impl Hba1c {
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+impl std::fmt::Display for Hba1c {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "HbA1c ({:.1}%)", self.value)
+    }
+}
+
+pub trait Hba1cExt {
+    fn a1c_pct(self) -> Hba1c;
+}
+impl Hba1cExt for f64 {
+    fn a1c_pct(self) -> Hba1c {
+        Hba1c { value: self }
+    }
+}
+
+impl From<f64> for Hba1c {
+    fn from(value: f64) -> Self {
+        Hba1c { value }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hba1c_construction_and_display() {
+        let a1c = 6.5.a1c_pct();
+        assert_eq!(a1c.value(), 6.5);
+        assert_eq!(format!("{}", a1c), "HbA1c (6.5%)");
+    }
+}
@@ -0,0 +1,87 @@
+//! Hematocrit module
+//!
+//! The fraction of blood volume taken up by red cells, reported as a percentage.
+//! Like hemoglobin, the normal range depends on sex; see `range_for`.
+
+use crate::{
+    history::SexAtBirth,
+    lab::{select_range, RangeThreshold, ResultRange},
+};
+
+/// Adult male thresholds for hematocrit, in percent.
+pub const HCT_MALE_THRESHOLDS_PCT: RangeThreshold = RangeThreshold {
+    crit_low: 20.0,
+    low_norm: 41.0,
+    norm_hi: 50.0,
+    hi_crit: 60.0,
+};
+
+/// Adult female thresholds for hematocrit, in percent.
+pub const HCT_FEMALE_THRESHOLDS_PCT: RangeThreshold = RangeThreshold {
+    crit_low: 20.0,
+    low_norm: 36.0,
+    norm_hi: 44.0,
+    hi_crit: 60.0,
+};
+
+/// A hematocrit measurement, in percent.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Hematocrit {
+    value: f64,
+}
+impl Hematocrit {
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+    /// Classify against the adult male or female thresholds.
+    pub fn range_for(&self, sex: SexAtBirth) -> ResultRange {
+        let thresholds = match sex {
+            SexAtBirth::Male => HCT_MALE_THRESHOLDS_PCT,
+            SexAtBirth::Female => HCT_FEMALE_THRESHOLDS_PCT,
+        };
+        select_range(self.value, &thresholds)
+    }
+}
+impl std::fmt::Display for Hematocrit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Hct ({:.1}%)", self.value)
+    }
+}
+
+pub trait HematocritExt {
+    fn hct_pct(self) -> Hematocrit;
+}
+impl HematocritExt for f64 {
+    fn hct_pct(self) -> Hematocrit {
+        Hematocrit { value: self }
+    }
+}
+
+impl From<f64> for Hematocrit {
+    fn from(value: f64) -> Self {
+        Hematocrit { value }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hematocrit_construction_and_display() {
+        let hct = 41.5.hct_pct();
+        assert_eq!(hct.value(), 41.5);
+        assert_eq!(format!("{}", hct), "Hct (41.5%)");
+    }
+
+    #[test]
+    fn hematocrit_ranges_depend_on_sex() {
+        let hct = 38.0.hct_pct();
+        assert_eq!(hct.range_for(SexAtBirth::Male), ResultRange::Low);
+        assert_eq!(hct.range_for(SexAtBirth::Female), ResultRange::Normal);
+        assert_eq!(
+            18.0.hct_pct().range_for(SexAtBirth::Female),
+            ResultRange::CriticalLow
+        );
+    }
+}
@@ -0,0 +1,191 @@
+//! Hemoglobin module
+//!
+//! Conventional units = g/dL, SI units = g/L (1 g/dL = 10 g/L).
+//!
+//! Normal hemoglobin differs by sex, so there is no single `range()`; use
+//! `range_for` with the patient's sex.
+
+use std::marker::PhantomData;
+
+use crate::{
+    constants::{HGB_GDL_TO_GL, HGB_GL_TO_GDL},
+    history::{AgeGroup, SexAtBirth},
+    lab::{
+        blood::hematocrit::Hematocrit, concentration::Concentration, reference::AnalyteKind,
+        reference_ranges::thresholds_for, select_range, RangeThreshold, ResultRange,
+    },
+    units::{analyte, format_value, hemoglobin::HemoglobinUnit, GdL, Unit, GL},
+};
+
+/// Adult male thresholds for hemoglobin, in g/dL.
+pub const HGB_MALE_THRESHOLDS_G_DL: RangeThreshold = RangeThreshold {
+    crit_low: 7.0,
+    low_norm: 13.5,
+    norm_hi: 17.5,
+    hi_crit: 20.0,
+};
+
+/// Adult female thresholds for hemoglobin, in g/dL.
+pub const HGB_FEMALE_THRESHOLDS_G_DL: RangeThreshold = RangeThreshold {
+    crit_low: 7.0,
+    low_norm: 12.0,
+    norm_hi: 15.5,
+    hi_crit: 20.0,
+};
+
+/// Sex-neutral thresholds for hemoglobin, in g/dL, spanning both sexes' normal
+/// ranges; used only where the patient's sex isn't known.
+pub const HGB_THRESHOLDS_G_DL: RangeThreshold = RangeThreshold {
+    crit_low: 7.0,
+    low_norm: 12.0,
+    norm_hi: 17.5,
+    hi_crit: 20.0,
+};
+
+/// Scale g/dL hemoglobin thresholds to g/L.
+pub(crate) const fn hgb_thresholds_g_l(g_dl: RangeThreshold) -> RangeThreshold {
+    RangeThreshold {
+        crit_low: g_dl.crit_low * HGB_GDL_TO_GL,
+        low_norm: g_dl.low_norm * HGB_GDL_TO_GL,
+        norm_hi: g_dl.norm_hi * HGB_GDL_TO_GL,
+        hi_crit: g_dl.hi_crit * HGB_GDL_TO_GL,
+    }
+}
+
+/*
+ *               Type and inherent methods
+ */
+
+/// A blood hemoglobin measurement.
+pub type Hemoglobin<U> = Concentration<analyte::Hemoglobin, U>;
+#[cfg(feature = "serde")]
+crate::serialize::impl_serde_measurement!(Hemoglobin, _ghost);
+impl<U: Unit> std::fmt::Display for Hemoglobin<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Hgb ({} {})", format_value(self.value, 1), U::ABBR)
+    }
+}
+
+impl<U: HemoglobinUnit> Hemoglobin<U> {
+    /// Classify against the adult male or female thresholds from the
+    /// `reference_ranges` registry.
+    pub fn range_for(&self, sex: SexAtBirth) -> ResultRange {
+        let thresholds = thresholds_for(AnalyteKind::Hemoglobin, sex, AgeGroup::Adult, U::ABBR);
+        select_range(self.value, &thresholds)
+    }
+}
+
+/// Estimate hematocrit from hemoglobin by the rule of three: Hct (%) ≈ 3 × Hgb
+/// (g/dL). It holds for normocytic red cells and drifts with abnormal MCHC (e.g.
+/// microcytosis), so prefer a measured hematocrit.
+pub fn hgb_to_hct<U: HemoglobinUnit>(hgb: Hemoglobin<U>) -> Hematocrit {
+    Hematocrit::from(3.0 * GdL::from_g_l(U::to_g_l(hgb.value())))
+}
+
+/*
+ *         Convenience constructors from f64 values
+ */
+
+/// Defines convenience constructors for hemoglobin measurements from f64 values.
+pub trait SerumHemoglobinExt {
+    fn hgb_g_dl(self) -> Hemoglobin<GdL>;
+    fn hgb_g_l(self) -> Hemoglobin<GL>;
+}
+impl SerumHemoglobinExt for f64 {
+    fn hgb_g_dl(self) -> Hemoglobin<GdL> {
+        Hemoglobin::from(self)
+    }
+
+    fn hgb_g_l(self) -> Hemoglobin<GL> {
+        Hemoglobin::from(self)
+    }
+}
+
+/*
+ *          FROM impls
+ */
+
+// creation from f64
+impl From<f64> for Hemoglobin<GdL> {
+    fn from(value: f64) -> Self {
+        Hemoglobin {
+            value,
+            _ghost: PhantomData,
+        }
+    }
+}
+impl From<f64> for Hemoglobin<GL> {
+    fn from(value: f64) -> Self {
+        Hemoglobin {
+            value,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+// conversion between g/dL and g/L types
+impl From<Hemoglobin<GL>> for Hemoglobin<GdL> {
+    fn from(other: Hemoglobin<GL>) -> Self {
+        Hemoglobin {
+            value: other.value * HGB_GL_TO_GDL,
+            _ghost: PhantomData,
+        }
+    }
+}
+impl From<Hemoglobin<GdL>> for Hemoglobin<GL> {
+    fn from(other: Hemoglobin<GdL>) -> Self {
+        Hemoglobin {
+            value: other.value * HGB_GDL_TO_GL,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hemoglobin_unit_conversions_round_trip() {
+        let g_dl = 13.2.hgb_g_dl();
+        let as_g_l: Hemoglobin<GL> = Hemoglobin::from(g_dl);
+        assert!((as_g_l.value() - 132.0).abs() < 1e-9);
+        assert_eq!(format!("{}", as_g_l), "Hgb (132.0 g/L)");
+
+        let back: Hemoglobin<GdL> = Hemoglobin::from(as_g_l);
+        assert!((back.value() - 13.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn thirteen_is_low_for_a_man_but_normal_for_a_woman() {
+        let hgb = 13.0.hgb_g_dl();
+        assert_eq!(hgb.range_for(SexAtBirth::Male), ResultRange::Low);
+        assert_eq!(hgb.range_for(SexAtBirth::Female), ResultRange::Normal);
+        assert_eq!(
+            130.0.hgb_g_l().range_for(SexAtBirth::Male),
+            ResultRange::Low
+        );
+    }
+
+    #[test]
+    fn sex_specific_ranges_share_critical_limits() {
+        for sex in [SexAtBirth::Male, SexAtBirth::Female] {
+            assert_eq!(6.5.hgb_g_dl().range_for(sex), ResultRange::CriticalLow);
+            assert_eq!(21.0.hgb_g_dl().range_for(sex), ResultRange::CriticalHigh);
+        }
+        assert_eq!(
+            16.5.hgb_g_dl().range_for(SexAtBirth::Female),
+            ResultRange::High
+        );
+        assert_eq!(
+            16.5.hgb_g_dl().range_for(SexAtBirth::Male),
+            ResultRange::Normal
+        );
+    }
+
+    #[test]
+    fn hematocrit_is_about_three_times_hemoglobin() {
+        assert!((hgb_to_hct(14.0.hgb_g_dl()).value() - 42.0).abs() < 1e-9);
+        assert!((hgb_to_hct(100.0.hgb_g_l()).value() - 30.0).abs() < 1e-9);
+    }
+}
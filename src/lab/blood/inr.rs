@@ -9,11 +9,13 @@ use std::marker::PhantomData;
 use crate::units::{InrUnit, Unit};
 
 /// An INR (international normalized ration for prothrombin time) measurement.
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
 pub struct Inr<U: Unit> {
     value: f64,
     _unit: PhantomData<U>,
 }
+#[cfg(feature = "serde")]
+crate::serialize::impl_serde_measurement!(Inr, _unit);
 
 //
 //      Getter / Display impls
@@ -118,6 +120,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn inr_copy_clone() {
         let inr1 = 2.5.inr();
         let inr2 = inr1; // Copy
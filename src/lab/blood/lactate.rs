@@ -0,0 +1,127 @@
+//! Lactate Module
+//!
+//! SI units = mmol/L
+//! Conventional units = mg/dL
+//! 9.0 mg/dL lactate = 1 mmol/L lactate
+//!
+//! Lactate above 2 mmol/L is elevated; above 4 mmol/L is the sepsis resuscitation
+//! trigger (Surviving Sepsis Campaign) and is treated as critical here.
+
+use std::marker::PhantomData;
+
+use crate::{
+    constants::{LAC_MGDL_TO_MMOLL, LAC_MMOLL_TO_MGDL},
+    lab::{concentration::define_lab_value, NumericRanged, RangeThreshold},
+    units::{analyte, lactate::LactateUnit, MgdL, MmolL, Unit},
+};
+
+/// Default thresholds for lab alert ranges for serum lactate, in mmol/L. There is
+/// no low range.
+pub(crate) const LAC_SERUM_THRESHOLDS_MMOLL: RangeThreshold = RangeThreshold {
+    crit_low: 0.0,
+    low_norm: 0.0,
+    norm_hi: 2.0,
+    hi_crit: 4.0,
+};
+
+/// Default thresholds for lab alert ranges for serum lactate, in mg/dL.
+pub(crate) const LAC_SERUM_THRESHOLDS_MGDL: RangeThreshold = RangeThreshold {
+    crit_low: LAC_SERUM_THRESHOLDS_MMOLL.crit_low * LAC_MMOLL_TO_MGDL,
+    low_norm: LAC_SERUM_THRESHOLDS_MMOLL.low_norm * LAC_MMOLL_TO_MGDL,
+    norm_hi: LAC_SERUM_THRESHOLDS_MMOLL.norm_hi * LAC_MMOLL_TO_MGDL,
+    hi_crit: LAC_SERUM_THRESHOLDS_MMOLL.hi_crit * LAC_MMOLL_TO_MGDL,
+};
+
+//
+// Type and inherent methods
+//
+
+define_lab_value! {
+    /// A serum lactate measurement.
+    Lactate: analyte::Lactate, display "Lactate" with 1 decimals;
+    /// Defines methods for creating `Lactate<U>` directly from f64 values.
+    pub trait SerumLactateExt {
+        lac_serum_mmol_l => MmolL: LAC_SERUM_THRESHOLDS_MMOLL,
+        lac_serum_mg_dl => MgdL: LAC_SERUM_THRESHOLDS_MGDL,
+    }
+}
+impl<U: LactateUnit> Lactate<U> {
+    /// The same lactate measurement in unit `T`, e.g. `.convert::<MmolL>()`. Returns the
+    /// value unchanged when `T` is already the current unit.
+    pub fn convert<T: LactateUnit>(&self) -> Lactate<T> {
+        let value = if T::ABBR == U::ABBR {
+            self.value
+        } else {
+            T::from_lactate_mmol_l(U::to_lactate_mmol_l(self.value))
+        };
+        Lactate {
+            value,
+            _ghost: PhantomData,
+        }
+    }
+}
+impl<U: Unit> Lactate<U>
+where
+    Self: NumericRanged<U>,
+{
+    /// Whether lactate is above 4 mmol/L (36 mg/dL), the threshold for aggressive
+    /// resuscitation in sepsis. Exactly 4 mmol/L is not critical.
+    pub fn is_critical(&self) -> bool {
+        self.range().is_critical()
+    }
+}
+
+//
+// From impls
+//
+
+// conversions from one unit type to another
+impl From<Lactate<MmolL>> for Lactate<MgdL> {
+    fn from(lactate: Lactate<MmolL>) -> Self {
+        Lactate {
+            value: lactate.value() * LAC_MMOLL_TO_MGDL,
+            _ghost: PhantomData,
+        }
+    }
+}
+impl From<Lactate<MgdL>> for Lactate<MmolL> {
+    fn from(lactate: Lactate<MgdL>) -> Self {
+        Lactate {
+            value: lactate.value() * LAC_MGDL_TO_MMOLL,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lab::ResultRange;
+
+    #[test]
+    fn lactate_ranges_are_correct() {
+        assert_eq!(1.0.lac_serum_mmol_l().range(), ResultRange::Normal);
+        assert_eq!(2.5.lac_serum_mmol_l().range(), ResultRange::High);
+        assert_eq!(5.0.lac_serum_mmol_l().range(), ResultRange::CriticalHigh);
+        assert_eq!(45.0.lac_serum_mg_dl().range(), ResultRange::CriticalHigh);
+    }
+
+    #[test]
+    fn is_critical_only_above_four() {
+        assert!(!1.0.lac_serum_mmol_l().is_critical());
+        assert!(!2.5.lac_serum_mmol_l().is_critical());
+        assert!(!4.0.lac_serum_mmol_l().is_critical());
+        assert!(4.1.lac_serum_mmol_l().is_critical());
+        assert!(5.0.lac_serum_mmol_l().is_critical());
+
+        assert!(!36.0.lac_serum_mg_dl().is_critical());
+        assert!(37.0.lac_serum_mg_dl().is_critical());
+    }
+
+    #[test]
+    fn lactate_converts_between_units() {
+        let mg_dl: Lactate<MgdL> = Lactate::from(2.0.lac_serum_mmol_l());
+        assert_eq!(mg_dl.value(), 18.0);
+        assert!((mg_dl.convert::<MmolL>().value() - 2.0).abs() < 1e-9);
+    }
+}
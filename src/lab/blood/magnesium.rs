@@ -0,0 +1,155 @@
+//! Serum magnesium module
+//!
+//! Low magnesium prolongs the QT interval and predisposes to torsades de pointes,
+//! and makes hypokalemia refractory to replacement.
+//! 1 mg/dL magnesium = 0.411 mmol/L; 1 mmol/L = 2 mEq/L (magnesium is divalent)
+
+use std::marker::PhantomData;
+
+use crate::{
+    constants::{MG_MGDL_TO_MMOLL, MG_MMOLL_TO_MGDL},
+    lab::{concentration::define_lab_value, RangeThreshold},
+    units::{
+        analyte,
+        magnesium::{MagnesiumUnit, MG_VALENCE},
+        MeqL, MgdL, MmolL,
+    },
+};
+
+/// Default thresholds for lab alert ranges for serum magnesium, in mg/dL.
+pub(crate) const MG_SERUM_THRESHOLDS_MG_DL: RangeThreshold = RangeThreshold {
+    crit_low: 1.0,
+    low_norm: 1.7,
+    norm_hi: 2.2,
+    hi_crit: 4.8,
+};
+
+/// Default thresholds for lab alert ranges for serum magnesium, in mmol/L.
+pub(crate) const MG_SERUM_THRESHOLDS_MMOL_L: RangeThreshold = RangeThreshold {
+    crit_low: MG_SERUM_THRESHOLDS_MG_DL.crit_low * MG_MGDL_TO_MMOLL,
+    low_norm: MG_SERUM_THRESHOLDS_MG_DL.low_norm * MG_MGDL_TO_MMOLL,
+    norm_hi: MG_SERUM_THRESHOLDS_MG_DL.norm_hi * MG_MGDL_TO_MMOLL,
+    hi_crit: MG_SERUM_THRESHOLDS_MG_DL.hi_crit * MG_MGDL_TO_MMOLL,
+};
+
+/// Default thresholds for lab alert ranges for serum magnesium, in mEq/L.
+pub(crate) const MG_SERUM_THRESHOLDS_MEQ_L: RangeThreshold = RangeThreshold {
+    crit_low: MG_SERUM_THRESHOLDS_MMOL_L.crit_low * MG_VALENCE as f64,
+    low_norm: MG_SERUM_THRESHOLDS_MMOL_L.low_norm * MG_VALENCE as f64,
+    norm_hi: MG_SERUM_THRESHOLDS_MMOL_L.norm_hi * MG_VALENCE as f64,
+    hi_crit: MG_SERUM_THRESHOLDS_MMOL_L.hi_crit * MG_VALENCE as f64,
+};
+
+//
+// Type and inherent methods
+//
+
+define_lab_value! {
+    /// A serum magnesium measurement.
+    Magnesium: analyte::Magnesium, display "Mg" with 1 decimals;
+    /// Defines methods for creating `Magnesium<U>` directly from f64 values.
+    pub trait SerumMagnesiumExt {
+        mg_serum_mg_dl => MgdL: MG_SERUM_THRESHOLDS_MG_DL,
+        mg_serum_mmol_l => MmolL: MG_SERUM_THRESHOLDS_MMOL_L,
+        mg_serum_meq_l => MeqL: MG_SERUM_THRESHOLDS_MEQ_L,
+    }
+}
+
+//
+// From impls
+//
+
+// conversion between mg/dL and mmol/L
+impl From<Magnesium<MmolL>> for Magnesium<MgdL> {
+    fn from(other: Magnesium<MmolL>) -> Self {
+        Magnesium {
+            value: other.value * MG_MMOLL_TO_MGDL,
+            _ghost: PhantomData,
+        }
+    }
+}
+impl From<Magnesium<MgdL>> for Magnesium<MmolL> {
+    fn from(other: Magnesium<MgdL>) -> Self {
+        Magnesium {
+            value: other.value * MG_MGDL_TO_MMOLL,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+// conversion to and from mEq/L, via mmol/L
+impl From<Magnesium<MeqL>> for Magnesium<MmolL> {
+    fn from(other: Magnesium<MeqL>) -> Self {
+        Magnesium {
+            value: MeqL::to_magnesium_mmol_l(other.value),
+            _ghost: PhantomData,
+        }
+    }
+}
+impl From<Magnesium<MmolL>> for Magnesium<MeqL> {
+    fn from(other: Magnesium<MmolL>) -> Self {
+        Magnesium {
+            value: MeqL::from_magnesium_mmol_l(other.value),
+            _ghost: PhantomData,
+        }
+    }
+}
+impl From<Magnesium<MeqL>> for Magnesium<MgdL> {
+    fn from(other: Magnesium<MeqL>) -> Self {
+        Magnesium {
+            value: MgdL::from_magnesium_mmol_l(MeqL::to_magnesium_mmol_l(other.value)),
+            _ghost: PhantomData,
+        }
+    }
+}
+impl From<Magnesium<MgdL>> for Magnesium<MeqL> {
+    fn from(other: Magnesium<MgdL>) -> Self {
+        Magnesium {
+            value: MeqL::from_magnesium_mmol_l(MgdL::to_magnesium_mmol_l(other.value)),
+            _ghost: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lab::{NumericRanged, ResultRange};
+
+    fn approx_eq(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-6, "{} !~= {}", a, b);
+    }
+
+    #[test]
+    fn magnesium_unit_conversions_round_trip() {
+        let mg_dl = 2.0.mg_serum_mg_dl();
+        let as_mmol: Magnesium<MmolL> = Magnesium::from(mg_dl);
+        approx_eq(as_mmol.value(), 0.822);
+
+        let as_meq: Magnesium<MeqL> = Magnesium::from(as_mmol);
+        approx_eq(as_meq.value(), 1.644);
+
+        let back: Magnesium<MgdL> = Magnesium::from(as_meq);
+        approx_eq(back.value(), 2.0);
+    }
+
+    #[test]
+    fn magnesium_range_boundaries_fall_toward_normal() {
+        assert_eq!(0.9.mg_serum_mg_dl().range(), ResultRange::CriticalLow);
+        assert_eq!(1.0.mg_serum_mg_dl().range(), ResultRange::Low);
+        assert_eq!(1.6.mg_serum_mg_dl().range(), ResultRange::Low);
+        assert_eq!(1.7.mg_serum_mg_dl().range(), ResultRange::Normal);
+        assert_eq!(2.2.mg_serum_mg_dl().range(), ResultRange::Normal);
+        assert_eq!(2.3.mg_serum_mg_dl().range(), ResultRange::High);
+        assert_eq!(4.8.mg_serum_mg_dl().range(), ResultRange::High);
+        assert_eq!(4.9.mg_serum_mg_dl().range(), ResultRange::CriticalHigh);
+    }
+
+    #[test]
+    fn si_and_meq_ranges_match_conventional() {
+        assert_eq!(0.5.mg_serum_mmol_l().range(), ResultRange::Low);
+        assert_eq!(0.85.mg_serum_mmol_l().range(), ResultRange::Normal);
+        assert_eq!(1.7.mg_serum_meq_l().range(), ResultRange::Normal);
+        assert_eq!(4.5.mg_serum_meq_l().range(), ResultRange::CriticalHigh);
+    }
+}
@@ -0,0 +1,111 @@
+//! Natriuretic peptide module
+//!
+//! BNP and NT-proBNP, both reported in pg/mL, used mainly to rule out heart
+//! failure in the breathless patient. Neither has a low or critical range.
+//!
+//! BNP above 100 pg/mL is elevated at any age. NT-proBNP rises with age, so its
+//! cutoff is age-stratified (ICON): 450 pg/mL under 50, 900 pg/mL from 50 to 75,
+//! and 1800 pg/mL over 75.
+
+use crate::{
+    history::Years,
+    lab::{concentration::define_lab_value, select_range, RangeThreshold, ResultRange},
+    units::{analyte, PgmL},
+};
+
+/// Default thresholds for lab alert ranges for BNP, in pg/mL.
+pub(crate) const BNP_THRESHOLDS_PG_ML: RangeThreshold = RangeThreshold {
+    crit_low: 0.0,
+    low_norm: 0.0,
+    norm_hi: 100.0,
+    hi_crit: f64::INFINITY,
+};
+
+/// NT-proBNP thresholds for patients under 50, in pg/mL. These are also what
+/// `range()` uses when age isn't given, as the most sensitive band.
+pub(crate) const NT_PROBNP_UNDER_50_THRESHOLDS_PG_ML: RangeThreshold = RangeThreshold {
+    crit_low: 0.0,
+    low_norm: 0.0,
+    norm_hi: 450.0,
+    hi_crit: f64::INFINITY,
+};
+
+/// NT-proBNP thresholds for patients aged 50 to 75, in pg/mL.
+pub(crate) const NT_PROBNP_50_TO_75_THRESHOLDS_PG_ML: RangeThreshold = RangeThreshold {
+    norm_hi: 900.0,
+    ..NT_PROBNP_UNDER_50_THRESHOLDS_PG_ML
+};
+
+/// NT-proBNP thresholds for patients over 75, in pg/mL.
+pub(crate) const NT_PROBNP_OVER_75_THRESHOLDS_PG_ML: RangeThreshold = RangeThreshold {
+    norm_hi: 1800.0,
+    ..NT_PROBNP_UNDER_50_THRESHOLDS_PG_ML
+};
+
+//
+// Types and inherent methods
+//
+
+define_lab_value! {
+    /// A B-type natriuretic peptide (BNP) measurement.
+    Bnp: analyte::Bnp, display "BNP" with 0 decimals;
+    /// Defines methods for creating `Bnp<U>` directly from f64 values.
+    pub trait BnpExt {
+        bnp_pg_ml => PgmL: BNP_THRESHOLDS_PG_ML,
+    }
+}
+
+define_lab_value! {
+    /// An N-terminal pro-BNP (NT-proBNP) measurement.
+    NtProBnp: analyte::NtProBnp, display "NT-proBNP" with 0 decimals;
+    /// Defines methods for creating `NtProBnp<U>` directly from f64 values.
+    pub trait NtProBnpExt {
+        nt_probnp_pg_ml => PgmL: NT_PROBNP_UNDER_50_THRESHOLDS_PG_ML,
+    }
+}
+impl NtProBnp<PgmL> {
+    /// Classify against the cutoff for the patient's age band. Ages of exactly 50
+    /// and 75 fall in the middle band.
+    pub fn range_for(&self, age: Years) -> ResultRange {
+        let thresholds = match age.0 {
+            a if a < 50.0 => NT_PROBNP_UNDER_50_THRESHOLDS_PG_ML,
+            a if a <= 75.0 => NT_PROBNP_50_TO_75_THRESHOLDS_PG_ML,
+            _ => NT_PROBNP_OVER_75_THRESHOLDS_PG_ML,
+        };
+        select_range(self.value, &thresholds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lab::NumericRanged;
+
+    #[test]
+    fn bnp_is_high_above_100() {
+        assert_eq!(100.0.bnp_pg_ml().range(), ResultRange::Normal);
+        assert_eq!(101.0.bnp_pg_ml().range(), ResultRange::High);
+        assert_eq!(format!("{}", 250.0.bnp_pg_ml()), "BNP (250 pg/mL)");
+    }
+
+    #[test]
+    fn nt_probnp_range_uses_the_youngest_band() {
+        assert_eq!(450.0.nt_probnp_pg_ml().range(), ResultRange::Normal);
+        assert_eq!(451.0.nt_probnp_pg_ml().range(), ResultRange::High);
+    }
+
+    #[test]
+    fn nt_probnp_bands_change_at_50_and_75() {
+        let value = 600.0.nt_probnp_pg_ml();
+        assert_eq!(value.range_for(Years(49.9)), ResultRange::High);
+        assert_eq!(value.range_for(Years(50.0)), ResultRange::Normal);
+
+        let value = 1200.0.nt_probnp_pg_ml();
+        assert_eq!(value.range_for(Years(75.0)), ResultRange::High);
+        assert_eq!(value.range_for(Years(75.5)), ResultRange::Normal);
+        assert_eq!(
+            1801.0.nt_probnp_pg_ml().range_for(Years(80.0)),
+            ResultRange::High
+        );
+    }
+}
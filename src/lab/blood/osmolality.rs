@@ -0,0 +1,81 @@
+//! Serum osmolality module
+//!
+//! Osmolality may be measured directly (freezing point depression) or estimated
+//! from its major contributors; see `calculators::calculated_osmolality`.
+
+use std::marker::PhantomData;
+
+use crate::{
+    lab::{select_range, NumericRanged, RangeThreshold, ResultRange},
+    units::{format_value, MosmKg, Unit},
+};
+
+/// Default thresholds for lab alert ranges for serum osmolality, in mOsm/kg.
+pub(crate) const OSM_SERUM_THRESHOLDS: RangeThreshold = RangeThreshold {
+    crit_low: 250.0,
+    low_norm: 275.0,
+    norm_hi: 295.0,
+    hi_crit: 320.0,
+};
+
+/// A serum osmolality measurement or estimate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Osmolality<U: Unit> {
+    value: f64,
+    _ghost: PhantomData<U>,
+}
+#[cfg(feature = "serde")]
+crate::serialize::impl_serde_measurement!(Osmolality, _ghost);
+impl<U: Unit> Osmolality<U> {
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+impl<U: Unit> std::fmt::Display for Osmolality<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Osm ({} {})", format_value(self.value, 0), U::ABBR)
+    }
+}
+
+/// Defines a convenience constructor for serum osmolality from f64 values.
+pub trait OsmolalityExt {
+    fn osm_serum(self) -> Osmolality<MosmKg>;
+}
+impl OsmolalityExt for f64 {
+    fn osm_serum(self) -> Osmolality<MosmKg> {
+        Osmolality::from(self)
+    }
+}
+
+impl From<f64> for Osmolality<MosmKg> {
+    fn from(value: f64) -> Self {
+        Osmolality {
+            value,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+impl NumericRanged<MosmKg> for Osmolality<MosmKg> {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn range(&self) -> ResultRange {
+        select_range(self.value, &OSM_SERUM_THRESHOLDS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn osmolality_ranges_are_selected_correctly() {
+        assert_eq!(240.0.osm_serum().range(), ResultRange::CriticalLow);
+        assert_eq!(265.0.osm_serum().range(), ResultRange::Low);
+        assert_eq!(288.0.osm_serum().range(), ResultRange::Normal);
+        assert_eq!(305.0.osm_serum().range(), ResultRange::High);
+        assert_eq!(335.0.osm_serum().range(), ResultRange::CriticalHigh);
+    }
+}
@@ -0,0 +1,147 @@
+//! Serum phosphate module
+//!
+//! Reported as elemental phosphorus: 1 mg/dL = 0.323 mmol/L.
+//!
+//! Both ends of the range matter. Severe hypophosphatemia (< 1.0 mg/dL) is the
+//! hallmark of refeeding syndrome and can cause respiratory muscle weakness,
+//! rhabdomyolysis and hemolysis. Hyperphosphatemia is mostly a sign of renal
+//! failure; above ~9 mg/dL it's usually driven by tumor lysis or rhabdomyolysis and
+//! brings a risk of acute calcium-phosphate precipitation.
+
+use std::marker::PhantomData;
+
+use crate::{
+    constants::{PHOS_MGDL_TO_MMOLL, PHOS_MMOLL_TO_MGDL},
+    lab::{concentration::define_lab_value, RangeThreshold},
+    units::{analyte, phosphate::PhosphateUnit, MgdL, MmolL},
+};
+
+/// Default thresholds for lab alert ranges for serum phosphate, in mg/dL. The adult
+/// reference interval is 2.5-4.5 mg/dL; children run higher.
+pub const SERUM_PHOS_RANGES_MGDL: RangeThreshold = RangeThreshold {
+    crit_low: 1.0,
+    low_norm: 2.5,
+    norm_hi: 4.5,
+    hi_crit: 9.0,
+};
+
+/// Default thresholds for lab alert ranges for serum phosphate, in mmol/L.
+pub const SERUM_PHOS_RANGES_MMOLL: RangeThreshold = RangeThreshold {
+    crit_low: SERUM_PHOS_RANGES_MGDL.crit_low * PHOS_MGDL_TO_MMOLL,
+    low_norm: SERUM_PHOS_RANGES_MGDL.low_norm * PHOS_MGDL_TO_MMOLL,
+    norm_hi: SERUM_PHOS_RANGES_MGDL.norm_hi * PHOS_MGDL_TO_MMOLL,
+    hi_crit: SERUM_PHOS_RANGES_MGDL.hi_crit * PHOS_MGDL_TO_MMOLL,
+};
+
+define_lab_value! {
+    /// A serum phosphate measurement.
+    Phosphate: analyte::Phosphate, display "Phosphate" with 1 decimals;
+    /// Defines methods for creating `Phosphate<U>` directly from f64 values.
+    pub trait SerumPhosphateExt {
+        phos_serum_mg_dl => MgdL: SERUM_PHOS_RANGES_MGDL,
+        phos_serum_mmol_l => MmolL: SERUM_PHOS_RANGES_MMOLL,
+    }
+}
+
+impl From<Phosphate<MgdL>> for Phosphate<MmolL> {
+    fn from(phos_mgdl: Phosphate<MgdL>) -> Self {
+        Phosphate {
+            value: phos_mgdl.value * PHOS_MGDL_TO_MMOLL,
+            _ghost: PhantomData,
+        }
+    }
+}
+impl From<Phosphate<MmolL>> for Phosphate<MgdL> {
+    fn from(phos_mmoll: Phosphate<MmolL>) -> Self {
+        Phosphate {
+            value: phos_mmoll.value * PHOS_MMOLL_TO_MGDL,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+impl<U: PhosphateUnit> Phosphate<U> {
+    /// The same phosphate measurement in unit `T`, e.g. `.convert::<MmolL>()`. Returns
+    /// the value unchanged when `T` is already the current unit.
+    pub fn convert<T: PhosphateUnit>(&self) -> Phosphate<T> {
+        let value = if T::ABBR == U::ABBR {
+            self.value
+        } else {
+            T::from_phosphate_mmol_l(U::to_phosphate_mmol_l(self.value))
+        };
+        Phosphate {
+            value,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lab::{NumericRanged, ResultRange};
+
+    fn approx_eq(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-6, "{} !~= {}", a, b);
+    }
+
+    #[test]
+    fn phosphate_unit_conversions() {
+        let phos_mgdl = 1.0.phos_serum_mg_dl();
+        let phos_mmoll: Phosphate<MmolL> = Phosphate::from(phos_mgdl);
+
+        // 1 mg/dL = 0.323 mmol/L
+        approx_eq(phos_mmoll.value(), 0.323);
+
+        let back_to_mgdl: Phosphate<MgdL> = Phosphate::from(phos_mmoll);
+        approx_eq(back_to_mgdl.value(), 1.0);
+        approx_eq(phos_mmoll.convert::<MgdL>().value(), 1.0);
+    }
+
+    #[test]
+    fn phosphate_ranges_mgdl() {
+        assert_eq!(0.8.phos_serum_mg_dl().range(), ResultRange::CriticalLow);
+        assert_eq!(1.8.phos_serum_mg_dl().range(), ResultRange::Low);
+        assert_eq!(3.5.phos_serum_mg_dl().range(), ResultRange::Normal);
+        assert_eq!(6.5.phos_serum_mg_dl().range(), ResultRange::High);
+        assert_eq!(10.0.phos_serum_mg_dl().range(), ResultRange::CriticalHigh);
+    }
+
+    #[test]
+    fn phosphate_ranges_mmoll() {
+        assert_eq!(
+            (0.8 * PHOS_MGDL_TO_MMOLL).phos_serum_mmol_l().range(),
+            ResultRange::CriticalLow
+        );
+        assert_eq!(0.6.phos_serum_mmol_l().range(), ResultRange::Low);
+        assert_eq!(1.1.phos_serum_mmol_l().range(), ResultRange::Normal);
+        assert_eq!(2.0.phos_serum_mmol_l().range(), ResultRange::High);
+        assert_eq!(3.2.phos_serum_mmol_l().range(), ResultRange::CriticalHigh);
+    }
+
+    #[test]
+    fn phosphate_range_boundaries() {
+        let just_below_crit_low = (SERUM_PHOS_RANGES_MGDL.crit_low - 0.01).phos_serum_mg_dl();
+        assert_eq!(just_below_crit_low.range(), ResultRange::CriticalLow);
+
+        let just_above_crit_low = (SERUM_PHOS_RANGES_MGDL.crit_low + 0.01).phos_serum_mg_dl();
+        assert_eq!(just_above_crit_low.range(), ResultRange::Low);
+
+        let just_above_low_norm = (SERUM_PHOS_RANGES_MGDL.low_norm + 0.01).phos_serum_mg_dl();
+        assert_eq!(just_above_low_norm.range(), ResultRange::Normal);
+
+        let just_above_norm_hi = (SERUM_PHOS_RANGES_MGDL.norm_hi + 0.01).phos_serum_mg_dl();
+        assert_eq!(just_above_norm_hi.range(), ResultRange::High);
+
+        let just_above_hi_crit = (SERUM_PHOS_RANGES_MGDL.hi_crit + 0.01).phos_serum_mg_dl();
+        assert_eq!(just_above_hi_crit.range(), ResultRange::CriticalHigh);
+    }
+
+    #[test]
+    fn phosphate_display_format() {
+        assert_eq!(
+            format!("{}", 4.3.phos_serum_mg_dl()),
+            "Phosphate (4.3 mg/dL)"
+        );
+    }
+}
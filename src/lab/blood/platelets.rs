@@ -0,0 +1,122 @@
+//! Platelet count module
+//!
+//! Reported as ×10⁹ cells/L, which is numerically the same as K/µL (thousands per
+//! microliter), so no conversion is needed between those conventions.
+
+use std::marker::PhantomData;
+
+use crate::{
+    lab::{select_range, NumericRanged, RangeThreshold, ResultRange},
+    units::{format_value, PerNanoLiter, Unit},
+};
+
+/// Default thresholds for lab alert ranges for platelet count, in ×10⁹/L. Below
+/// 20 the risk of spontaneous bleeding makes the count critical; above 1000 the
+/// thrombocytosis is extreme enough to warrant the same urgency.
+///
+/// Moderate thrombocytopenia (below `PLT_PROCEDURE_MIN_X10E9_L`) has no band of its
+/// own here, so 45 and 149 both classify as `Low`; it is exposed only through
+/// `Platelets::is_safe_for_procedure`.
+pub(crate) const PLT_THRESHOLDS: RangeThreshold = RangeThreshold {
+    crit_low: 20.0,
+    low_norm: 150.0,
+    norm_hi: 450.0,
+    hi_crit: 1000.0,
+};
+
+/// The usual minimum platelet count (×10⁹/L) for most invasive procedures, such
+/// as central line placement or lumbar puncture.
+pub const PLT_PROCEDURE_MIN_X10E9_L: f64 = 50.0;
+
+/// A platelet count.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Platelets<U: Unit> {
+    value: f64,
+    _unit: PhantomData<U>,
+}
+#[cfg(feature = "serde")]
+crate::serialize::impl_serde_measurement!(Platelets, _unit);
+impl<U: Unit> Platelets<U> {
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+impl Platelets<PerNanoLiter> {
+    /// True when the count is at or above `PLT_PROCEDURE_MIN_X10E9_L`; below it
+    /// (moderate thrombocytopenia or worse) consider transfusion first.
+    pub fn is_safe_for_procedure(&self) -> bool {
+        self.value >= PLT_PROCEDURE_MIN_X10E9_L
+    }
+}
+impl<U: Unit> std::fmt::Display for Platelets<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PLT ({} {})", format_value(self.value, 0), U::ABBR)
+    }
+}
+
+/// Defines a convenience constructor for platelet counts from f64 values.
+pub trait PlateletsExt {
+    fn plt_x10e9_l(self) -> Platelets<PerNanoLiter>;
+    /// Same as `plt_x10e9_l`; ×10⁹/L is the only platelet unit.
+    fn platelets(self) -> Platelets<PerNanoLiter>;
+}
+impl PlateletsExt for f64 {
+    fn plt_x10e9_l(self) -> Platelets<PerNanoLiter> {
+        Platelets::from(self)
+    }
+    fn platelets(self) -> Platelets<PerNanoLiter> {
+        Platelets::from(self)
+    }
+}
+
+impl From<f64> for Platelets<PerNanoLiter> {
+    fn from(value: f64) -> Self {
+        Platelets {
+            value,
+            _unit: PhantomData,
+        }
+    }
+}
+
+impl NumericRanged<PerNanoLiter> for Platelets<PerNanoLiter> {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn range(&self) -> ResultRange {
+        select_range(self.value, &PLT_THRESHOLDS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn platelets_display_format() {
+        assert_eq!(format!("{}", 245.0.plt_x10e9_l()), "PLT (245 ×10⁹/L)");
+    }
+
+    #[test]
+    fn platelet_ranges_are_selected_correctly() {
+        assert_eq!(19.0.plt_x10e9_l().range(), ResultRange::CriticalLow);
+        assert_eq!(20.0.plt_x10e9_l().range(), ResultRange::Low);
+        assert_eq!(45.0.plt_x10e9_l().range(), ResultRange::Low);
+        assert_eq!(150.0.plt_x10e9_l().range(), ResultRange::Normal);
+        assert_eq!(450.0.plt_x10e9_l().range(), ResultRange::Normal);
+        assert_eq!(451.0.plt_x10e9_l().range(), ResultRange::High);
+        assert_eq!(1200.0.plt_x10e9_l().range(), ResultRange::CriticalHigh);
+    }
+
+    #[test]
+    fn platelets_constructor_matches_plt_x10e9_l() {
+        assert_eq!(150.0.platelets(), 150.0.plt_x10e9_l());
+    }
+
+    #[test]
+    fn procedure_safety_starts_at_fifty() {
+        assert!(!49.0.plt_x10e9_l().is_safe_for_procedure());
+        assert!(50.0.plt_x10e9_l().is_safe_for_procedure());
+        assert!(250.0.plt_x10e9_l().is_safe_for_procedure());
+    }
+}
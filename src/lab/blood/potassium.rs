@@ -0,0 +1,137 @@
+//! Potassium (serum) module
+//!
+//! Like sodium, potassium (K+) is monovalent, so mEq/L and mmol/L are the same and
+//! no conversion factor is needed. The critical thresholds matter more here: severe
+//! hyperkalemia is an arrhythmia (code-blue) risk.
+
+use std::marker::PhantomData;
+
+use crate::{
+    error::MeasurementError,
+    lab::{concentration::Concentration, select_range, NumericRanged, RangeThreshold, ResultRange},
+    units::{analyte, format_value, MeqL, MmolL, Unit},
+};
+
+pub(crate) const K_SERUM_THRESHOLDS: RangeThreshold = RangeThreshold {
+    crit_low: 2.5,
+    low_norm: 3.5,
+    norm_hi: 5.0,
+    hi_crit: 6.0,
+};
+
+/// Highest potassium (mmol/L) accepted by the `try_*` constructors.
+pub const K_PLAUSIBLE_MAX_MMOL_L: f64 = 15.0;
+
+/// A serum potassium measurement.
+pub type Potassium<U> = Concentration<analyte::Potassium, U>;
+#[cfg(feature = "serde")]
+crate::serialize::impl_serde_measurement!(Potassium, _ghost);
+
+impl<U: Unit> std::fmt::Display for Potassium<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "K ({} {})", format_value(self.value, 1), U::ABBR)
+    }
+}
+
+pub trait SerumPotassiumExt {
+    fn k_serum_meq(self) -> Potassium<MeqL>;
+    fn k_serum_mmol(self) -> Potassium<MmolL>;
+    fn try_k_serum_meq(self) -> Result<Potassium<MeqL>, MeasurementError>;
+    fn try_k_serum_mmol(self) -> Result<Potassium<MmolL>, MeasurementError>;
+}
+
+impl SerumPotassiumExt for f64 {
+    fn k_serum_meq(self) -> Potassium<MeqL> {
+        Potassium::from(self)
+    }
+    fn k_serum_mmol(self) -> Potassium<MmolL> {
+        Potassium::from(self)
+    }
+    fn try_k_serum_meq(self) -> Result<Potassium<MeqL>, MeasurementError> {
+        Potassium::try_with_ceiling(self, K_PLAUSIBLE_MAX_MMOL_L)
+    }
+    fn try_k_serum_mmol(self) -> Result<Potassium<MmolL>, MeasurementError> {
+        Potassium::try_with_ceiling(self, K_PLAUSIBLE_MAX_MMOL_L)
+    }
+}
+
+// Conventional Units (mEq / L)
+
+impl NumericRanged<MeqL> for Potassium<MeqL> {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn range(&self) -> ResultRange {
+        select_range(self.value, &K_SERUM_THRESHOLDS)
+    }
+}
+
+impl From<f64> for Potassium<MeqL> {
+    fn from(value: f64) -> Self {
+        Potassium {
+            value,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+impl From<Potassium<MmolL>> for Potassium<MeqL> {
+    fn from(potassium: Potassium<MmolL>) -> Self {
+        Self {
+            value: potassium.value(),
+            _ghost: PhantomData,
+        }
+    }
+}
+
+// SI units
+
+impl NumericRanged<MmolL> for Potassium<MmolL> {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn range(&self) -> ResultRange {
+        select_range(self.value, &K_SERUM_THRESHOLDS)
+    }
+}
+
+impl From<f64> for Potassium<MmolL> {
+    fn from(value: f64) -> Self {
+        Potassium {
+            value,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+impl From<Potassium<MeqL>> for Potassium<MmolL> {
+    fn from(potassium: Potassium<MeqL>) -> Self {
+        Self {
+            value: potassium.value(),
+            _ghost: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serum_potassium_ranges_are_correct() {
+        assert_eq!(2.2.k_serum_meq().range(), ResultRange::CriticalLow);
+        assert_eq!(3.1.k_serum_mmol().range(), ResultRange::Low);
+        assert_eq!(4.2.k_serum_meq().range(), ResultRange::Normal);
+        assert_eq!(5.0.k_serum_mmol().range(), ResultRange::Normal);
+        assert_eq!(5.6.k_serum_meq().range(), ResultRange::High);
+        assert_eq!(6.5.k_serum_mmol().range(), ResultRange::CriticalHigh);
+    }
+
+    #[test]
+    fn meq_and_mmol_convert_one_to_one() {
+        let as_mmol: Potassium<MmolL> = Potassium::from(4.0.k_serum_meq());
+        assert_eq!(as_mmol.value(), 4.0);
+    }
+}
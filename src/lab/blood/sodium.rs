@@ -7,69 +7,38 @@
 use std::marker::PhantomData;
 
 use crate::{
-    lab::{NumericRanged, RangeThreshold, ResultRange},
-    units::{MeqL, MmolL, Unit},
+    lab::{concentration::define_lab_value, RangeThreshold},
+    units::{analyte, sodium::SodiumUnit, MeqL, MmolL},
 };
 
-const NA_SERUM_THRESHOLDS: RangeThreshold = RangeThreshold {
+pub(crate) const NA_SERUM_THRESHOLDS: RangeThreshold = RangeThreshold {
     crit_low: 130.0,
     low_norm: 135.0,
     norm_hi: 145.0,
     hi_crit: 150.0,
 };
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct Sodium<U: Unit> {
-    value: f64,
-    _ghost: PhantomData<U>,
-}
-
-impl<U: Unit> Sodium<U> {
-    pub fn value(&self) -> f64 {
-        self.value
-    }
-}
-
-impl<U: Unit> std::fmt::Display for Sodium<U> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Na ({:.0} {})", self.value, U::ABBR)
-    }
-}
-
-pub trait SerumSodiumExt {
-    fn na_serum_meq(self) -> Sodium<MeqL>;
-    fn na_serum_mmol(self) -> Sodium<MmolL>;
-}
-
-impl SerumSodiumExt for f64 {
-    fn na_serum_meq(self) -> Sodium<MeqL> {
-        Sodium::from(self)
-    }
-    fn na_serum_mmol(self) -> Sodium<MmolL> {
-        Sodium::from(self)
-    }
-}
-
-// Conventional Units (mEq / L)
+/// Highest sodium (mmol/L) accepted by the `try_*` constructors.
+pub const NA_PLAUSIBLE_MAX_MMOL_L: f64 = 250.0;
 
-impl NumericRanged<MeqL> for Sodium<MeqL> {
-    fn value(&self) -> f64 {
-        self.value
-    }
-
-    fn range(&self) -> ResultRange {
-        match self.value {
-            val if val < NA_SERUM_THRESHOLDS.crit_low => ResultRange::CriticalLow,
-            val if val < NA_SERUM_THRESHOLDS.low_norm => ResultRange::Low,
-            val if val < NA_SERUM_THRESHOLDS.norm_hi => ResultRange::Normal,
-            val if val < NA_SERUM_THRESHOLDS.hi_crit => ResultRange::High,
-            _ => ResultRange::CriticalHigh,
-        }
+define_lab_value! {
+    /// A serum sodium measurement.
+    Sodium: analyte::Sodium, display "Na" with 0 decimals;
+    pub trait SerumSodiumExt {
+        na_serum_meq / try_na_serum_meq(NA_PLAUSIBLE_MAX_MMOL_L) => MeqL: NA_SERUM_THRESHOLDS,
+        na_serum_mmol / try_na_serum_mmol(NA_PLAUSIBLE_MAX_MMOL_L) => MmolL: NA_SERUM_THRESHOLDS,
     }
 }
 
-impl From<f64> for Sodium<MeqL> {
-    fn from(value: f64) -> Self {
+impl<U: SodiumUnit> Sodium<U> {
+    /// The same sodium measurement in unit `T`, e.g. `.convert::<MeqL>()`. Returns the
+    /// value unchanged when `T` is already the current unit.
+    pub fn convert<T: SodiumUnit>(&self) -> Sodium<T> {
+        let value = if T::ABBR == U::ABBR {
+            self.value
+        } else {
+            T::from_mmol_l(U::to_mmol_l(self.value))
+        };
         Sodium {
             value,
             _ghost: PhantomData,
@@ -77,6 +46,8 @@ impl From<f64> for Sodium<MeqL> {
     }
 }
 
+// Conventional Units (mEq / L)
+
 impl From<Sodium<MmolL>> for Sodium<MeqL> {
     fn from(sodium: Sodium<MmolL>) -> Self {
         Self {
@@ -88,31 +59,6 @@ impl From<Sodium<MmolL>> for Sodium<MeqL> {
 
 // SI units
 
-impl NumericRanged<MmolL> for Sodium<MmolL> {
-    fn value(&self) -> f64 {
-        self.value
-    }
-
-    fn range(&self) -> ResultRange {
-        match self.value {
-            val if val < NA_SERUM_THRESHOLDS.crit_low => ResultRange::CriticalLow,
-            val if val < NA_SERUM_THRESHOLDS.low_norm => ResultRange::Low,
-            val if val < NA_SERUM_THRESHOLDS.norm_hi => ResultRange::Normal,
-            val if val < NA_SERUM_THRESHOLDS.hi_crit => ResultRange::High,
-            _ => ResultRange::CriticalHigh,
-        }
-    }
-}
-
-impl From<f64> for Sodium<MmolL> {
-    fn from(value: f64) -> Self {
-        Sodium {
-            value,
-            _ghost: PhantomData,
-        }
-    }
-}
-
 impl From<Sodium<MeqL>> for Sodium<MmolL> {
     fn from(sodium: Sodium<MeqL>) -> Self {
         Self {
@@ -125,6 +71,7 @@ impl From<Sodium<MeqL>> for Sodium<MmolL> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::lab::{NumericRanged, ResultRange};
 
     #[test]
     fn serum_na_ranges_are_correct() {
@@ -135,4 +82,29 @@ mod test {
         assert_eq!(148.0.na_serum_meq().range(), ResultRange::High);
         assert_eq!(155.0.na_serum_mmol().range(), ResultRange::CriticalHigh);
     }
+
+    #[test]
+    fn custom_thresholds_reclassify_sodium() {
+        let narrow = RangeThreshold {
+            crit_low: 125.0,
+            low_norm: 136.0,
+            norm_hi: 144.0,
+            hi_crit: 155.0,
+        };
+        let na = 135.5.na_serum_mmol();
+        assert_eq!(na.range(), ResultRange::Normal);
+        assert_eq!(na.range_with(&narrow), ResultRange::Low);
+    }
+
+    #[test]
+    fn sodium_boundaries_belong_to_the_range_nearer_normal() {
+        // thresholds 130 / 135 / 145 / 150 mmol/L
+        assert_eq!(129.9.na_serum_mmol().range(), ResultRange::CriticalLow);
+        assert_eq!(130.0.na_serum_mmol().range(), ResultRange::Low);
+        assert_eq!(135.0.na_serum_mmol().range(), ResultRange::Normal);
+        assert_eq!(145.0.na_serum_meq().range(), ResultRange::Normal);
+        assert_eq!(145.1.na_serum_meq().range(), ResultRange::High);
+        assert_eq!(150.0.na_serum_meq().range(), ResultRange::High);
+        assert_eq!(150.1.na_serum_meq().range(), ResultRange::CriticalHigh);
+    }
 }
@@ -0,0 +1,319 @@
+//! Cardiac troponin module
+//!
+//! High-sensitivity assays report in ng/L; older assays in ng/mL (1 ng/mL = 1000 ng/L).
+//! The decision threshold is the assay's sex-specific 99th-percentile upper reference
+//! limit (URL) rather than a single population range.
+
+use std::marker::PhantomData;
+
+use crate::{
+    constants::{TROP_NGL_TO_NGML, TROP_NGML_TO_NGL},
+    history::{AgeGroup, SexAtBirth},
+    lab::{
+        concentration::Concentration,
+        reference::AnalyteKind,
+        reference_ranges::{thresholds_for, troponin_thresholds},
+        select_range, NumericRanged, ResultRange,
+    },
+    units::{analyte, troponin::TroponinUnit, NgL, NgmL, Unit},
+};
+
+/// Default female 99th-percentile URL (ng/L), hs-cTnI (Abbott Architect).
+pub const HS_TNI_URL_FEMALE_NG_L: f64 = 16.0;
+
+/// Default male 99th-percentile URL (ng/L), hs-cTnI (Abbott Architect).
+pub const HS_TNI_URL_MALE_NG_L: f64 = 34.0;
+
+/// Sex-neutral 99th-percentile URL (ng/L), hs-cTnI (Abbott Architect).
+pub const HS_TNI_URL_OVERALL_NG_L: f64 = 26.0;
+
+/// ESC 0h/1h rule-in: a presentation hs-cTnI (Architect) at or above this (ng/L).
+pub const HS_TNI_0H_RULE_IN_NG_L: f64 = 64.0;
+
+/// ESC 0h/1h rule-in: an absolute 1-hour rise in hs-cTnI (Architect) at or above
+/// this (ng/L).
+pub const HS_TNI_1H_DELTA_RULE_IN_NG_L: f64 = 6.0;
+
+/// The sex-specific 99th-percentile URLs of a particular troponin assay, in ng/L.
+///
+/// URLs differ several-fold between manufacturers, so results should be judged
+/// against the cutoffs of the assay that produced them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TroponinAssay {
+    female_url_ng_l: f64,
+    male_url_ng_l: f64,
+}
+impl TroponinAssay {
+    /// Abbott Architect hs-cTnI; also the `Default`.
+    pub const ARCHITECT_HS_TNI: TroponinAssay = TroponinAssay {
+        female_url_ng_l: HS_TNI_URL_FEMALE_NG_L,
+        male_url_ng_l: HS_TNI_URL_MALE_NG_L,
+    };
+    /// Roche Elecsys hs-cTnT (ng/L).
+    pub const ELECSYS_HS_TNT: TroponinAssay = TroponinAssay {
+        female_url_ng_l: 9.0,
+        male_url_ng_l: 17.0,
+    };
+
+    /// An assay with the given female and male URLs, in ng/L.
+    pub fn new(female_url_ng_l: f64, male_url_ng_l: f64) -> Self {
+        TroponinAssay {
+            female_url_ng_l,
+            male_url_ng_l,
+        }
+    }
+    /// The 99th-percentile URL (ng/L) for the given sex.
+    pub fn url_ng_l(&self, sex: SexAtBirth) -> f64 {
+        match sex {
+            SexAtBirth::Female => self.female_url_ng_l,
+            SexAtBirth::Male => self.male_url_ng_l,
+        }
+    }
+}
+impl Default for TroponinAssay {
+    fn default() -> Self {
+        TroponinAssay::ARCHITECT_HS_TNI
+    }
+}
+
+/// A cardiac troponin measurement.
+pub type Troponin<U> = Concentration<analyte::Troponin, U>;
+#[cfg(feature = "serde")]
+crate::serialize::impl_serde_measurement!(Troponin, _ghost);
+impl<U: TroponinUnit> Troponin<U> {
+    /// How many times the default sex-specific URL this result is.
+    pub fn multiple_of_url(&self, sex: SexAtBirth) -> f64 {
+        let url = thresholds_for(AnalyteKind::Troponin, sex, AgeGroup::Adult, NgL::ABBR).norm_hi;
+        U::to_ng_l(self.value) / url
+    }
+    /// Classify against the given assay's URL for the patient's sex: High above
+    /// it, Normal otherwise.
+    pub fn range_for(&self, sex: SexAtBirth, assay: &TroponinAssay) -> ResultRange {
+        select_range(
+            U::to_ng_l(self.value),
+            &troponin_thresholds(assay.url_ng_l(sex)),
+        )
+    }
+}
+/// `range()` uses the sex-neutral URL; prefer `range_for` when sex is known.
+impl<U: TroponinUnit> NumericRanged<U> for Troponin<U> {
+    fn value(&self) -> f64 {
+        self.value
+    }
+    fn range(&self) -> ResultRange {
+        select_range(
+            U::to_ng_l(self.value),
+            &troponin_thresholds(HS_TNI_URL_OVERALL_NG_L),
+        )
+    }
+}
+/// The change between two serial troponin results.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TroponinDelta {
+    first_ng_l: f64,
+    second_ng_l: f64,
+    interval_hours: f64,
+}
+impl TroponinDelta {
+    /// The baseline result, in ng/L.
+    pub fn first_ng_l(&self) -> f64 {
+        self.first_ng_l
+    }
+    /// The follow-up result, in ng/L.
+    pub fn second_ng_l(&self) -> f64 {
+        self.second_ng_l
+    }
+    /// Hours between the two samples.
+    pub fn interval_hours(&self) -> f64 {
+        self.interval_hours
+    }
+    /// Signed change from the first result to the second, in ng/L.
+    pub fn absolute_ng_l(&self) -> f64 {
+        self.second_ng_l - self.first_ng_l
+    }
+    /// Signed change as a percentage of the first result, or `None` when the
+    /// baseline was undetectable (zero), where a percentage is meaningless.
+    pub fn percent(&self) -> Option<f64> {
+        if self.first_ng_l <= 0.0 {
+            None
+        } else {
+            Some(100.0 * self.absolute_ng_l() / self.first_ng_l)
+        }
+    }
+}
+
+/// Compare two serial troponin results, converting both to ng/L first.
+pub fn troponin_delta<A: TroponinUnit, B: TroponinUnit>(
+    first: Troponin<A>,
+    second: Troponin<B>,
+    interval_hours: f64,
+) -> TroponinDelta {
+    TroponinDelta {
+        first_ng_l: A::to_ng_l(first.value),
+        second_ng_l: B::to_ng_l(second.value),
+        interval_hours,
+    }
+}
+
+/// Whether a pair of serial hs-cTnI (Architect) results rules in MI.
+///
+/// Intervals under two hours follow the ESC 0h/1h algorithm: the first result at
+/// least `HS_TNI_0H_RULE_IN_NG_L`, or a rise of at least
+/// `HS_TNI_1H_DELTA_RULE_IN_NG_L`. Longer intervals follow the 0h/3h algorithm:
+/// the second result above the sex-neutral URL with a rise of at least half the
+/// URL (from a normal baseline) or 20% (from an already raised one). Either way a
+/// positive result still needs clinical correlation.
+pub fn rules_in_mi<A: TroponinUnit, B: TroponinUnit>(
+    first: Troponin<A>,
+    second: Troponin<B>,
+    interval_hours: f64,
+) -> bool {
+    let delta = troponin_delta(first, second, interval_hours);
+    if interval_hours < 2.0 {
+        delta.first_ng_l >= HS_TNI_0H_RULE_IN_NG_L
+            || delta.absolute_ng_l() >= HS_TNI_1H_DELTA_RULE_IN_NG_L
+    } else {
+        let url = HS_TNI_URL_OVERALL_NG_L;
+        let significant_rise = if delta.first_ng_l <= url {
+            delta.absolute_ng_l() >= 0.5 * url
+        } else {
+            delta.percent().is_some_and(|pct| pct >= 20.0)
+        };
+        delta.second_ng_l > url && significant_rise
+    }
+}
+
+impl<U: Unit> std::fmt::Display for Troponin<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Troponin ({} {})", self.value, U::ABBR)
+    }
+}
+
+pub trait TroponinExt {
+    fn trop_ng_l(self) -> Troponin<NgL>;
+    fn trop_ng_ml(self) -> Troponin<NgmL>;
+}
+impl TroponinExt for f64 {
+    fn trop_ng_l(self) -> Troponin<NgL> {
+        Troponin {
+            value: self,
+            _ghost: PhantomData,
+        }
+    }
+    fn trop_ng_ml(self) -> Troponin<NgmL> {
+        Troponin {
+            value: self,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+impl From<Troponin<NgmL>> for Troponin<NgL> {
+    fn from(trop: Troponin<NgmL>) -> Self {
+        Troponin {
+            value: trop.value * TROP_NGML_TO_NGL,
+            _ghost: PhantomData,
+        }
+    }
+}
+impl From<Troponin<NgL>> for Troponin<NgmL> {
+    fn from(trop: Troponin<NgL>) -> Self {
+        Troponin {
+            value: trop.value * TROP_NGL_TO_NGML,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} !~= {}", a, b);
+    }
+
+    #[test]
+    fn troponin_unit_conversions() {
+        let ng_l: Troponin<NgL> = Troponin::from(0.05.trop_ng_ml());
+        approx_eq(ng_l.value(), 50.0);
+
+        let back: Troponin<NgmL> = Troponin::from(ng_l);
+        approx_eq(back.value(), 0.05);
+    }
+
+    #[test]
+    fn multiple_of_url_is_sex_specific() {
+        let trop = 32.0.trop_ng_l();
+        approx_eq(trop.multiple_of_url(SexAtBirth::Female), 2.0);
+        approx_eq(trop.multiple_of_url(SexAtBirth::Male), 32.0 / 34.0);
+    }
+
+    #[test]
+    fn delta_converts_to_a_common_unit() {
+        let delta = troponin_delta(0.01.trop_ng_ml(), 25.0.trop_ng_l(), 1.0);
+        approx_eq(delta.absolute_ng_l(), 15.0);
+        approx_eq(delta.percent().unwrap(), 150.0);
+    }
+
+    #[test]
+    fn delta_percent_is_none_for_undetectable_baseline() {
+        let delta = troponin_delta(0.0.trop_ng_l(), 8.0.trop_ng_l(), 1.0);
+        approx_eq(delta.absolute_ng_l(), 8.0);
+        assert_eq!(delta.percent(), None);
+    }
+
+    #[test]
+    fn rising_pattern_rules_in() {
+        assert!(rules_in_mi(5.0.trop_ng_l(), 14.0.trop_ng_l(), 1.0));
+        assert!(rules_in_mi(70.0.trop_ng_l(), 71.0.trop_ng_l(), 1.0));
+        assert!(rules_in_mi(10.0.trop_ng_l(), 0.05.trop_ng_ml(), 3.0));
+        assert!(rules_in_mi(100.0.trop_ng_l(), 130.0.trop_ng_l(), 3.0));
+    }
+
+    #[test]
+    fn flat_pattern_rules_out() {
+        assert!(!rules_in_mi(4.0.trop_ng_l(), 5.0.trop_ng_l(), 1.0));
+        assert!(!rules_in_mi(4.0.trop_ng_l(), 5.0.trop_ng_l(), 3.0));
+        assert!(!rules_in_mi(100.0.trop_ng_l(), 105.0.trop_ng_l(), 3.0));
+    }
+
+    #[test]
+    fn range_flags_above_the_overall_url() {
+        assert_eq!(26.0.trop_ng_l().range(), ResultRange::Normal);
+        assert_eq!(27.0.trop_ng_l().range(), ResultRange::High);
+        assert_eq!(0.027.trop_ng_ml().range(), ResultRange::High);
+    }
+
+    #[test]
+    fn range_for_straddles_sex_specific_cutoffs() {
+        let assay = TroponinAssay::default();
+        let trop = 20.0.trop_ng_l();
+        assert_eq!(
+            trop.range_for(SexAtBirth::Female, &assay),
+            ResultRange::High
+        );
+        assert_eq!(
+            trop.range_for(SexAtBirth::Male, &assay),
+            ResultRange::Normal
+        );
+        assert_eq!(
+            35.0.trop_ng_l().range_for(SexAtBirth::Male, &assay),
+            ResultRange::High
+        );
+        assert_eq!(
+            0.016.trop_ng_ml().range_for(SexAtBirth::Female, &assay),
+            ResultRange::Normal
+        );
+    }
+
+    #[test]
+    fn range_for_uses_the_given_assay() {
+        let trop = 12.0.trop_ng_l();
+        let tnt = TroponinAssay::ELECSYS_HS_TNT;
+        assert_eq!(trop.range_for(SexAtBirth::Female, &tnt), ResultRange::High);
+        assert_eq!(trop.range_for(SexAtBirth::Male, &tnt), ResultRange::Normal);
+        let custom = TroponinAssay::new(5.0, 10.0);
+        assert_eq!(trop.range_for(SexAtBirth::Male, &custom), ResultRange::High);
+    }
+}
@@ -0,0 +1,120 @@
+//! White blood cell count module
+//!
+//! Reported as ×10⁹ cells/L, which is numerically the same as K/µL (thousands per
+//! microliter), so no conversion is needed between those conventions.
+
+use std::marker::PhantomData;
+
+use crate::{
+    constants::{CELLS_PER_L_TO_PER_NL, CELLS_PER_NL_TO_PER_L},
+    lab::{select_range, NumericRanged, RangeThreshold, ResultRange},
+    units::{format_value, PerLiter, PerNanoLiter, Unit},
+};
+
+/// Default thresholds for lab alert ranges for total WBC count, in ×10⁹/L.
+pub(crate) const WBC_THRESHOLDS: RangeThreshold = RangeThreshold {
+    crit_low: 2.0,
+    low_norm: 4.0,
+    norm_hi: 11.0,
+    hi_crit: 30.0,
+};
+
+/// A total white blood cell count.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WhiteBloodCells<U: Unit> {
+    value: f64,
+    _unit: PhantomData<U>,
+}
+#[cfg(feature = "serde")]
+crate::serialize::impl_serde_measurement!(WhiteBloodCells, _unit);
+impl<U: Unit> WhiteBloodCells<U> {
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+impl<U: Unit> std::fmt::Display for WhiteBloodCells<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "WBC ({} {})", format_value(self.value, 1), U::ABBR)
+    }
+}
+
+/// Defines convenience constructors for WBC counts from f64 values.
+pub trait WhiteBloodCellsExt {
+    fn wbc_x10e9_l(self) -> WhiteBloodCells<PerNanoLiter>;
+    fn wbc_per_l(self) -> WhiteBloodCells<PerLiter>;
+}
+impl WhiteBloodCellsExt for f64 {
+    fn wbc_x10e9_l(self) -> WhiteBloodCells<PerNanoLiter> {
+        WhiteBloodCells::from(self)
+    }
+    fn wbc_per_l(self) -> WhiteBloodCells<PerLiter> {
+        WhiteBloodCells::from(self)
+    }
+}
+
+impl From<f64> for WhiteBloodCells<PerNanoLiter> {
+    fn from(value: f64) -> Self {
+        WhiteBloodCells {
+            value,
+            _unit: PhantomData,
+        }
+    }
+}
+
+impl From<f64> for WhiteBloodCells<PerLiter> {
+    fn from(value: f64) -> Self {
+        WhiteBloodCells {
+            value,
+            _unit: PhantomData,
+        }
+    }
+}
+
+impl From<WhiteBloodCells<PerLiter>> for WhiteBloodCells<PerNanoLiter> {
+    fn from(wbc: WhiteBloodCells<PerLiter>) -> Self {
+        WhiteBloodCells::from(wbc.value * CELLS_PER_L_TO_PER_NL)
+    }
+}
+
+impl From<WhiteBloodCells<PerNanoLiter>> for WhiteBloodCells<PerLiter> {
+    fn from(wbc: WhiteBloodCells<PerNanoLiter>) -> Self {
+        WhiteBloodCells::from(wbc.value * CELLS_PER_NL_TO_PER_L)
+    }
+}
+
+impl NumericRanged<PerNanoLiter> for WhiteBloodCells<PerNanoLiter> {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn range(&self) -> ResultRange {
+        select_range(self.value, &WBC_THRESHOLDS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wbc_ranges_are_selected_correctly() {
+        assert_eq!(1.5.wbc_x10e9_l().range(), ResultRange::CriticalLow);
+        assert_eq!(3.2.wbc_x10e9_l().range(), ResultRange::Low);
+        assert_eq!(7.0.wbc_x10e9_l().range(), ResultRange::Normal);
+        assert_eq!(16.0.wbc_x10e9_l().range(), ResultRange::High);
+        assert_eq!(45.0.wbc_x10e9_l().range(), ResultRange::CriticalHigh);
+    }
+
+    #[test]
+    fn wbc_display_format() {
+        assert_eq!(format!("{}", 7.3.wbc_x10e9_l()), "WBC (7.3 ×10⁹/L)");
+    }
+
+    #[test]
+    fn wbc_converts_between_per_nl_and_per_l() {
+        let per_l: WhiteBloodCells<PerLiter> = 7.5.wbc_x10e9_l().into();
+        assert_eq!(per_l.value(), 7.5e9);
+        let per_nl: WhiteBloodCells<PerNanoLiter> = 4.2e9.wbc_per_l().into();
+        assert!((per_nl.value() - 4.2).abs() < 1e-12);
+    }
+}
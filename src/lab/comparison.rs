@@ -0,0 +1,93 @@
+//! Instrument comparison module
+//!
+//! Method-comparison (interlab QA) checks: the same specimen run on two analyzers
+//! should agree within an allowable bias, usually set as a percentage of the
+//! reference value.
+
+use crate::{lab::NumericRanged, units::Unit};
+
+/// Agreement between a reference and a comparison instrument from
+/// `compare_instruments`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InstrumentComparison {
+    absolute_bias: f64,
+    percent_bias: f64,
+    bias_limit_pct: f64,
+}
+impl InstrumentComparison {
+    /// Comparison minus reference, in the measurements' unit.
+    pub fn absolute_bias(&self) -> f64 {
+        self.absolute_bias
+    }
+    /// Absolute bias as a percentage of the reference value.
+    pub fn percent_bias(&self) -> f64 {
+        self.percent_bias
+    }
+    /// The allowable bias, in percent, the comparison was judged against.
+    pub fn bias_limit_pct(&self) -> f64 {
+        self.bias_limit_pct
+    }
+    /// Whether the bias, in either direction, is larger than the allowable limit.
+    pub fn exceeds_limit(&self) -> bool {
+        self.percent_bias.abs() > self.bias_limit_pct
+    }
+}
+
+/// Compare the same specimen measured on a `reference` and a `comparison`
+/// instrument. Both must be the same analyte in the same unit; `bias_limit_pct` is
+/// the allowable bias as a percentage of the reference value.
+///
+/// Returns `None` when the reference value is 0, since the percent bias is then
+/// undefined.
+pub fn compare_instruments<T, U>(
+    reference: &T,
+    comparison: &T,
+    bias_limit_pct: f64,
+) -> Option<InstrumentComparison>
+where
+    T: NumericRanged<U>,
+    U: Unit,
+{
+    if reference.value() == 0.0 {
+        return None;
+    }
+    let absolute_bias = comparison.value() - reference.value();
+    Some(InstrumentComparison {
+        absolute_bias,
+        percent_bias: 100.0 * absolute_bias / reference.value(),
+        bias_limit_pct,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lab::blood::creatinine::CreatinineExt;
+
+    fn approx_eq(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} !~= {}", a, b);
+    }
+
+    #[test]
+    fn small_creatinine_bias_is_acceptable() {
+        let cmp = compare_instruments(&1.00.cr_serum_mg_dl(), &1.05.cr_serum_mg_dl(), 7.5).unwrap();
+        approx_eq(cmp.absolute_bias(), 0.05);
+        approx_eq(cmp.percent_bias(), 5.0);
+        assert!(!cmp.exceeds_limit());
+    }
+
+    #[test]
+    fn large_negative_creatinine_bias_exceeds_limit() {
+        let cmp =
+            compare_instruments(&120.0.cr_serum_umol_l(), &105.0.cr_serum_umol_l(), 7.5).unwrap();
+        approx_eq(cmp.absolute_bias(), -15.0);
+        approx_eq(cmp.percent_bias(), -12.5);
+        assert!(cmp.exceeds_limit());
+    }
+
+    #[test]
+    fn zero_reference_has_no_comparison() {
+        let cmp = compare_instruments(&0.0.cr_serum_mg_dl(), &0.05.cr_serum_mg_dl(), 7.5);
+        assert!(cmp.is_none());
+    }
+}
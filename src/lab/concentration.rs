@@ -0,0 +1,241 @@
+//! Concentration module
+//!
+//! `Concentration<A, U>` is the shared representation of every serum concentration:
+//! an analyte marker `A` (from `units::analyte`) and a unit `U`. The named types in
+//! `lab::blood` (`Glucose<U>`, `Creatinine<U>`, ...) are aliases for it, so a
+//! glucose in mg/dL and a creatinine in mg/dL are distinct types and can't be
+//! mixed up or converted into one another:
+//!
+//! ```compile_fail
+//! use medicalc::lab::blood::{creatinine::Creatinine, glucose::SerumGlucoseExt};
+//! use medicalc::units::MgdL;
+//!
+//! let scr: Creatinine<MgdL> = 100.0.glu_serum_mg_dl();
+//! ```
+//!
+//! ```compile_fail
+//! use medicalc::lab::blood::{creatinine::Creatinine, glucose::SerumGlucoseExt};
+//! use medicalc::units::UmolL;
+//!
+//! let scr = Creatinine::<UmolL>::from(100.0.glu_serum_mg_dl());
+//! ```
+//!
+//! Converting between units of the same analyte works as before:
+//!
+//! ```
+//! use medicalc::lab::blood::glucose::{Glucose, SerumGlucoseExt};
+//! use medicalc::units::MmolL;
+//!
+//! let glu = Glucose::<MmolL>::from(180.0.glu_serum_mg_dl());
+//! assert!((glu.value() - 10.0).abs() < 1e-9);
+//! ```
+//!
+//! Per-analyte behavior (Display, ranges, conversions) is implemented on the
+//! aliases in each analyte's module.
+
+use std::marker::PhantomData;
+
+use crate::{
+    error::{validate_measurement, MeasurementError},
+    units::{analyte::Analyte, Unit},
+};
+
+/// A concentration of analyte `A` measured in unit `U`.
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+pub struct Concentration<A: Analyte, U: Unit> {
+    pub(crate) value: f64,
+    pub(crate) _ghost: PhantomData<(A, U)>,
+}
+impl<A: Analyte, U: Unit> Concentration<A, U> {
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Construct from `value` after rejecting NaN, infinite, negative and
+    /// above-`ceiling` inputs. `ceiling` is in unit `U`.
+    pub fn try_with_ceiling(value: f64, ceiling: f64) -> Result<Self, MeasurementError> {
+        Ok(Concentration {
+            value: validate_measurement(value, ceiling)?,
+            _ghost: PhantomData,
+        })
+    }
+}
+
+// Arithmetic between concentrations of the same analyte and unit, and scaling.
+// Written out here rather than with `impl_measurement_ops!`, which only covers
+// single-parameter types.
+impl<A: Analyte, U: Unit> std::ops::Add for Concentration<A, U> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Concentration {
+            value: self.value + rhs.value,
+            _ghost: PhantomData,
+        }
+    }
+}
+impl<A: Analyte, U: Unit> std::ops::Sub for Concentration<A, U> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Concentration {
+            value: self.value - rhs.value,
+            _ghost: PhantomData,
+        }
+    }
+}
+impl<A: Analyte, U: Unit> std::ops::Mul<f64> for Concentration<A, U> {
+    type Output = Self;
+    fn mul(self, factor: f64) -> Self {
+        Concentration {
+            value: self.value * factor,
+            _ghost: PhantomData,
+        }
+    }
+}
+impl<A: Analyte, U: Unit> std::ops::Div<f64> for Concentration<A, U> {
+    type Output = Self;
+    fn div(self, divisor: f64) -> Self {
+        Concentration {
+            value: self.value / divisor,
+            _ghost: PhantomData,
+        }
+    }
+}
+
+impl<A: Analyte, U: Unit> std::fmt::Debug for Concentration<A, U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(A::NAME)
+            .field("value", &self.value)
+            .field("unit", &U::ABBR)
+            .finish()
+    }
+}
+
+/// Defines a `Concentration` alias for a lab analyte along with its serde hook,
+/// `Display`, `*Ext` constructors (with optional `try_*` variants and their
+/// plausibility ceilings), `From<f64>` per unit and `NumericRanged` per unit.
+/// Conversions between units and analyte-specific methods are still written by
+/// hand next to the invocation.
+///
+/// ```ignore
+/// define_lab_value! {
+///     /// A serum sodium measurement.
+///     Sodium: analyte::Sodium, display "Na" with 0 decimals;
+///     pub trait SerumSodiumExt {
+///         na_serum_meq / try_na_serum_meq(NA_PLAUSIBLE_MAX_MMOL_L) => MeqL: NA_SERUM_THRESHOLDS,
+///         na_serum_mmol / try_na_serum_mmol(NA_PLAUSIBLE_MAX_MMOL_L) => MmolL: NA_SERUM_THRESHOLDS,
+///     }
+/// }
+/// ```
+macro_rules! define_lab_value {
+    (
+        $(#[$meta:meta])*
+        $name:ident: $analyte:ty, display $label:literal with $decimals:literal decimals;
+        $(#[$ext_meta:meta])*
+        pub trait $ext:ident {
+            $(
+                $ctor:ident $( / $try_ctor:ident($ceiling:expr) )? => $unit:ident: $thresholds:expr
+            ),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        pub type $name<U> = $crate::lab::concentration::Concentration<$analyte, U>;
+        #[cfg(feature = "serde")]
+        $crate::serialize::impl_serde_measurement!($name, _ghost);
+
+        impl<U: $crate::units::Unit> std::fmt::Display for $name<U> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(
+                    f,
+                    "{} ({} {})",
+                    $label,
+                    $crate::units::format_value(self.value, $decimals),
+                    <U as $crate::units::Unit>::ABBR
+                )
+            }
+        }
+
+        $(#[$ext_meta])*
+        pub trait $ext {
+            $(
+                fn $ctor(self) -> $name<$unit>;
+                $(
+                    fn $try_ctor(self)
+                        -> Result<$name<$unit>, $crate::error::MeasurementError>;
+                )?
+            )+
+        }
+        impl $ext for f64 {
+            $(
+                fn $ctor(self) -> $name<$unit> {
+                    $name::from(self)
+                }
+                $(
+                    fn $try_ctor(self)
+                        -> Result<$name<$unit>, $crate::error::MeasurementError> {
+                        $name::try_with_ceiling(self, $ceiling)
+                    }
+                )?
+            )+
+        }
+
+        $(
+            impl From<f64> for $name<$unit> {
+                fn from(value: f64) -> Self {
+                    $name {
+                        value,
+                        _ghost: std::marker::PhantomData,
+                    }
+                }
+            }
+
+            impl $crate::lab::NumericRanged<$unit> for $name<$unit> {
+                fn value(&self) -> f64 {
+                    self.value
+                }
+
+                fn range(&self) -> $crate::lab::ResultRange {
+                    $crate::lab::select_range(self.value, &$thresholds)
+                }
+            }
+        )+
+    };
+}
+pub(crate) use define_lab_value;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        lab::blood::{creatinine::CreatinineExt, glucose::SerumGlucoseExt},
+        units::{MgdL, UmolL},
+    };
+
+    #[test]
+    fn debug_names_the_analyte_and_unit() {
+        assert_eq!(
+            format!("{:?}", 100.0.glu_serum_mg_dl()),
+            "Glucose { value: 100.0, unit: \"mg/dL\" }"
+        );
+        assert_eq!(
+            format!("{:?}", 88.4.cr_serum_umol_l()),
+            "Creatinine { value: 88.4, unit: \"µmol/L\" }"
+        );
+    }
+
+    #[test]
+    fn same_analyte_converts_between_units() {
+        let scr = crate::lab::blood::creatinine::Creatinine::<UmolL>::from(1.0.cr_serum_mg_dl());
+        assert!((scr.value() - 88.4).abs() < 1e-9);
+        let back: crate::lab::blood::creatinine::Creatinine<MgdL> = scr.into();
+        assert!((back.value() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn same_unit_concentrations_add_and_scale() {
+        let total = 1.0.cr_serum_mg_dl() + 0.4.cr_serum_mg_dl();
+        assert!((total.value() - 1.4).abs() < 1e-9);
+        let rise = 1.4.cr_serum_mg_dl() - 1.0.cr_serum_mg_dl();
+        assert!((rise.value() - 0.4).abs() < 1e-9);
+        assert!(((100.0.glu_serum_mg_dl() * 1.5).value() - 150.0).abs() < 1e-9);
+        assert!(((100.0.glu_serum_mg_dl() / 4.0).value() - 25.0).abs() < 1e-9);
+    }
+}
@@ -1,9 +1,10 @@
 //! (e)GFR module
 //!
 //! GFR is pretty universally reported in mL/min/1.73 m² -- a unit used
-//! for nothing else.
+//! for nothing else. Creatinine clearance (e.g. Cockcroft-Gault) is *not* indexed
+//! to body surface area, so it gets its own `ClearanceRate` type in plain mL/min.
 
-use crate::units::{GfrUnit, Unit};
+use crate::units::{GfrUnit, MlMin, Unit};
 use std::marker::PhantomData;
 
 /*
@@ -11,16 +12,137 @@ use std::marker::PhantomData;
  */
 
 /// The result of a GFR calculation.
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
 pub struct Gfr<U: Unit> {
     value: f64,
     _ghost: PhantomData<U>,
 }
+#[cfg(feature = "serde")]
+crate::serialize::impl_serde_measurement!(Gfr, _ghost);
 impl<U: Unit> Gfr<U> {
     pub fn value(&self) -> f64 {
         self.value
     }
 }
+impl Gfr<GfrUnit> {
+    /// Map the GFR onto its KDIGO CKD "G" stage.
+    pub fn stage(&self) -> CkdStage {
+        match self.value {
+            val if val >= 90.0 => CkdStage::G1,
+            val if val >= 60.0 => CkdStage::G2,
+            val if val >= 45.0 => CkdStage::G3a,
+            val if val >= 30.0 => CkdStage::G3b,
+            val if val >= 15.0 => CkdStage::G4,
+            _ => CkdStage::G5,
+        }
+    }
+
+    /// Format the GFR the way a lab report would under the given convention,
+    /// e.g. ">90 mL/min/1.73m²" when capping at 90.
+    pub fn report(&self, convention: ReportingConvention) -> String {
+        let rounded = self.value.round();
+        let cap = match convention {
+            ReportingConvention::Exact => None,
+            ReportingConvention::CappedAt60 => Some(60.0),
+            ReportingConvention::CappedAt90 => Some(90.0),
+        };
+        match cap {
+            Some(cap) if rounded > cap => format!(">{cap:.0} {}", GfrUnit::ABBR),
+            _ => format!("{rounded:.0} {}", GfrUnit::ABBR),
+        }
+    }
+}
+
+/// How a lab reports eGFR values at the top of the range. Many labs don't report
+/// exact values above 60 or 90, where the estimate is least precise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportingConvention {
+    /// Report the rounded value as calculated.
+    Exact,
+    /// Report anything above 60 as ">60".
+    CappedAt60,
+    /// Report anything above 90 as ">90".
+    CappedAt90,
+}
+
+/// KDIGO GFR categories for chronic kidney disease.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CkdStage {
+    /// GFR ≥ 90: normal or high
+    G1,
+    /// GFR 60–89: mildly decreased
+    G2,
+    /// GFR 45–59: mildly to moderately decreased
+    G3a,
+    /// GFR 30–44: moderately to severely decreased
+    G3b,
+    /// GFR 15–29: severely decreased
+    G4,
+    /// GFR < 15: kidney failure
+    G5,
+}
+
+/// KDIGO rapid CKD progression: a sustained eGFR decline of more than this many
+/// mL/min/1.73 m² per year.
+pub const KDIGO_RAPID_DECLINE_PER_YEAR: f64 = 5.0;
+
+/// The change between two eGFR results taken some time apart.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GfrDecline {
+    earlier: f64,
+    later: f64,
+    years: f64,
+}
+impl GfrDecline {
+    /// Compare an earlier eGFR with a later one, `years` apart.
+    ///
+    /// Returns `None` unless `years` is positive and the earlier eGFR is above 0,
+    /// since the annual and percentage declines are otherwise undefined.
+    pub fn new(earlier: Gfr<GfrUnit>, later: Gfr<GfrUnit>, years: f64) -> Option<Self> {
+        if years.is_nan() || years <= 0.0 || earlier.value.is_nan() || earlier.value <= 0.0 {
+            return None;
+        }
+        Some(GfrDecline {
+            earlier: earlier.value,
+            later: later.value,
+            years,
+        })
+    }
+    /// Total fall in eGFR over the interval; negative if it rose.
+    pub fn absolute(&self) -> f64 {
+        self.earlier - self.later
+    }
+    /// Total fall as a percentage of the earlier eGFR; negative if it rose.
+    pub fn percent(&self) -> f64 {
+        100.0 * self.absolute() / self.earlier
+    }
+    /// Average fall per year, in mL/min/1.73 m²/year; negative if it rose.
+    pub fn per_year(&self) -> f64 {
+        self.absolute() / self.years
+    }
+    /// Whether the annual decline exceeds `KDIGO_RAPID_DECLINE_PER_YEAR`.
+    pub fn is_rapid(&self) -> bool {
+        self.per_year() > KDIGO_RAPID_DECLINE_PER_YEAR
+    }
+}
+
+/*
+ * Creatinine clearance type
+ */
+
+/// A creatinine clearance, in mL/min (not normalized to 1.73 m²).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ClearanceRate<U: Unit> {
+    value: f64,
+    _ghost: PhantomData<U>,
+}
+#[cfg(feature = "serde")]
+crate::serialize::impl_serde_measurement!(ClearanceRate, _ghost);
+impl<U: Unit> ClearanceRate<U> {
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
 
 /*
  *  Extension trait to construct from an f64
@@ -44,6 +166,12 @@ impl<U: Unit> std::fmt::Display for Gfr<U> {
     }
 }
 
+impl std::fmt::Display for CkdStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
 impl From<f64> for Gfr<GfrUnit> {
     fn from(value: f64) -> Self {
         Gfr {
@@ -53,6 +181,21 @@ impl From<f64> for Gfr<GfrUnit> {
     }
 }
 
+impl<U: Unit> std::fmt::Display for ClearanceRate<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CrCl ({:.0} {})", self.value.round(), U::ABBR)
+    }
+}
+
+impl From<f64> for ClearanceRate<MlMin> {
+    fn from(value: f64) -> Self {
+        ClearanceRate {
+            value,
+            _ghost: PhantomData,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -129,6 +272,56 @@ mod tests {
         assert!(gfr.value() < 15.0);
     }
 
+    #[test]
+    fn ckd_stage_boundaries() {
+        let stage = |val: f64| Gfr::<GfrUnit>::from(val).stage();
+        assert_eq!(stage(90.0), CkdStage::G1);
+        assert_eq!(stage(89.9), CkdStage::G2);
+        assert_eq!(stage(60.0), CkdStage::G2);
+        assert_eq!(stage(59.9), CkdStage::G3a);
+        assert_eq!(stage(45.0), CkdStage::G3a);
+        assert_eq!(stage(44.9), CkdStage::G3b);
+        assert_eq!(stage(30.0), CkdStage::G3b);
+        assert_eq!(stage(29.9), CkdStage::G4);
+        assert_eq!(stage(15.0), CkdStage::G4);
+        assert_eq!(stage(14.9), CkdStage::G5);
+    }
+
+    #[test]
+    fn stable_gfr_is_not_rapid_decline() {
+        let decline = GfrDecline::new(Gfr::from(62.0), Gfr::from(58.0), 2.0).unwrap();
+        approx_eq(decline.absolute(), 4.0);
+        approx_eq(decline.per_year(), 2.0);
+        assert!(!decline.is_rapid());
+    }
+
+    #[test]
+    fn rapid_gfr_decline_is_flagged() {
+        let decline = GfrDecline::new(Gfr::from(60.0), Gfr::from(45.0), 1.5).unwrap();
+        approx_eq(decline.per_year(), 10.0);
+        approx_eq(decline.percent(), 25.0);
+        assert!(decline.is_rapid());
+        // exactly 5/year is not rapid
+        assert!(!GfrDecline::new(Gfr::from(60.0), Gfr::from(55.0), 1.0)
+            .unwrap()
+            .is_rapid());
+    }
+
+    #[test]
+    fn rising_gfr_gives_negative_decline() {
+        let decline = GfrDecline::new(Gfr::from(40.0), Gfr::from(50.0), 1.0).unwrap();
+        approx_eq(decline.per_year(), -10.0);
+        approx_eq(decline.percent(), -25.0);
+        assert!(!decline.is_rapid());
+    }
+
+    #[test]
+    fn decline_rejects_non_positive_interval_or_baseline() {
+        assert!(GfrDecline::new(Gfr::from(60.0), Gfr::from(50.0), 0.0).is_none());
+        assert!(GfrDecline::new(Gfr::from(60.0), Gfr::from(50.0), -1.0).is_none());
+        assert!(GfrDecline::new(Gfr::from(0.0), Gfr::from(10.0), 1.0).is_none());
+    }
+
     #[test]
     fn gfr_fractional_values() {
         let gfr: Gfr<GfrUnit> = Gfr::from(67.89);
@@ -158,6 +351,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn gfr_clone_trait() {
         let gfr1: Gfr<GfrUnit> = Gfr::from(70.0);
         let gfr2 = gfr1.clone();
@@ -172,6 +366,39 @@ mod tests {
         assert!(debug_string.contains("Gfr"));
     }
 
+    #[test]
+    fn gfr_report_under_each_convention() {
+        let gfr: Gfr<GfrUnit> = Gfr::from(120.0);
+        assert_eq!(gfr.report(ReportingConvention::Exact), "120 mL/min/1.73m²");
+        assert_eq!(
+            gfr.report(ReportingConvention::CappedAt60),
+            ">60 mL/min/1.73m²"
+        );
+        assert_eq!(
+            gfr.report(ReportingConvention::CappedAt90),
+            ">90 mL/min/1.73m²"
+        );
+    }
+
+    #[test]
+    fn gfr_report_below_cap_is_exact() {
+        let gfr: Gfr<GfrUnit> = Gfr::from(59.6);
+        assert_eq!(
+            gfr.report(ReportingConvention::CappedAt60),
+            "60 mL/min/1.73m²"
+        );
+        assert_eq!(
+            gfr.report(ReportingConvention::CappedAt90),
+            "60 mL/min/1.73m²"
+        );
+    }
+
+    #[test]
+    fn clearance_rate_display_format() {
+        let crcl: ClearanceRate<MlMin> = ClearanceRate::from(63.6);
+        assert_eq!(format!("{}", crcl), "CrCl (64 mL/min)");
+    }
+
     #[test]
     fn gfr_equality() {
         let gfr1: Gfr<GfrUnit> = Gfr::from(65.0);
@@ -0,0 +1,152 @@
+//! Lab panel module
+//!
+//! A `LabPanel` collects one result per analyte (e.g. a basic metabolic panel) so
+//! the results can be assessed together. Results can carry how long ago they were
+//! drawn, so old values can be flagged rather than treated as current.
+
+use std::{mem::discriminant, time::Duration};
+
+use crate::lab::{reference::AnalyteKind, LabResult};
+
+/// A set of lab results, holding at most one result per analyte.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LabPanel {
+    entries: Vec<PanelEntry>,
+}
+
+/// A result in a `LabPanel` together with how long before now it was drawn.
+#[derive(Debug, Clone, PartialEq)]
+struct PanelEntry {
+    result: LabResult,
+    drawn_ago: Option<Duration>,
+}
+impl LabPanel /* builder / setters */ {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Add a result to the panel, replacing any earlier result for the same analyte.
+    pub fn with(self, result: impl Into<LabResult>) -> Self {
+        self.insert(result.into(), None)
+    }
+    /// Like `with`, recording that the result was drawn `drawn_ago` before now.
+    pub fn with_drawn(self, result: impl Into<LabResult>, drawn_ago: Duration) -> Self {
+        self.insert(result.into(), Some(drawn_ago))
+    }
+    fn insert(mut self, result: LabResult, drawn_ago: Option<Duration>) -> Self {
+        self.entries
+            .retain(|entry| discriminant(&entry.result) != discriminant(&result));
+        self.entries.push(PanelEntry { result, drawn_ago });
+        self
+    }
+}
+
+impl LabPanel /* getters */ {
+    pub fn results(&self) -> Vec<&LabResult> {
+        self.entries.iter().map(|entry| &entry.result).collect()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+    /// How long before now the result for `kind` was drawn, if the panel has one
+    /// and its draw time was recorded.
+    pub fn drawn_ago(&self, kind: AnalyteKind) -> Option<Duration> {
+        self.entries
+            .iter()
+            .find(|entry| entry.result.kind() == kind)
+            .and_then(|entry| entry.drawn_ago)
+    }
+}
+
+impl LabPanel /* calculations */ {
+    /// Composite measure of how deranged the panel is, for triage.
+    ///
+    /// Sums the magnitude of each result's severity code: 0 for normal, 1 for
+    /// low/high and 2 for critical values. An empty panel scores 0.
+    pub fn acuity_score(&self) -> u32 {
+        self.entries
+            .iter()
+            .map(|entry| entry.result.range().severity_code().unsigned_abs() as u32)
+            .sum()
+    }
+
+    /// Analytes whose results were drawn more than `max_age` ago. Results added
+    /// without a draw time are never reported as stale.
+    pub fn stale_values(&self, max_age: Duration) -> Vec<AnalyteKind> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.drawn_ago.is_some_and(|age| age > max_age))
+            .map(|entry| entry.result.kind())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lab::blood::{
+        bicarbonate::SerumBicarbonateExt, creatinine::CreatinineExt, glucose::SerumGlucoseExt,
+        potassium::SerumPotassiumExt, sodium::SerumSodiumExt,
+    };
+
+    #[test]
+    fn adding_same_analyte_replaces_earlier_result() {
+        let panel = LabPanel::new()
+            .with(140.0.na_serum_meq())
+            .with(4.0.k_serum_mmol())
+            .with(131.0.na_serum_mmol());
+        assert_eq!(panel.results().len(), 2);
+        assert_eq!(panel.results()[1].label(), "Sodium");
+    }
+
+    #[test]
+    fn normal_panel_scores_zero() {
+        let panel = LabPanel::new()
+            .with(140.0.na_serum_meq())
+            .with(4.2.k_serum_meq())
+            .with(1.1.cr_serum_mg_dl());
+        assert_eq!(panel.acuity_score(), 0);
+        assert_eq!(LabPanel::new().acuity_score(), 0);
+    }
+
+    #[test]
+    fn severely_deranged_panel_outscores_mildly_deranged_panel() {
+        // mild: slightly low Na and slightly high K
+        let mild = LabPanel::new()
+            .with(133.0.na_serum_meq())
+            .with(5.4.k_serum_meq())
+            .with(24.0.hco3_serum_meq())
+            .with(110.0.glu_serum_mg_dl());
+        // severe: critical Na, K, HCO3 and glucose
+        let severe = LabPanel::new()
+            .with(121.0.na_serum_meq())
+            .with(6.8.k_serum_meq())
+            .with(8.0.hco3_serum_meq())
+            .with(650.0.glu_serum_mg_dl());
+
+        assert_eq!(mild.acuity_score(), 2);
+        assert_eq!(severe.acuity_score(), 8);
+        assert!(severe.acuity_score() > mild.acuity_score());
+    }
+
+    const HOUR: Duration = Duration::from_secs(3600);
+
+    #[test]
+    fn old_results_are_flagged_stale() {
+        let panel = LabPanel::new()
+            .with_drawn(140.0.na_serum_meq(), 72 * HOUR)
+            .with_drawn(4.0.k_serum_meq(), 2 * HOUR)
+            .with(1.1.cr_serum_mg_dl());
+        assert_eq!(panel.stale_values(24 * HOUR), vec![AnalyteKind::Sodium]);
+        assert_eq!(panel.drawn_ago(AnalyteKind::Potassium), Some(2 * HOUR));
+        assert_eq!(panel.drawn_ago(AnalyteKind::Creatinine), None);
+    }
+
+    #[test]
+    fn replacing_a_result_replaces_its_draw_time() {
+        let panel = LabPanel::new()
+            .with_drawn(140.0.na_serum_meq(), 72 * HOUR)
+            .with_drawn(138.0.na_serum_meq(), HOUR);
+        assert!(panel.stale_values(24 * HOUR).is_empty());
+        assert_eq!(panel.drawn_ago(AnalyteKind::Sodium), Some(HOUR));
+    }
+}
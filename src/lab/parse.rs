@@ -0,0 +1,221 @@
+//! Parse module
+//!
+//! `FromStr` for measurements written with their units, e.g. `"1.2 mg/dL"`. The
+//! target type fixes the unit, so the suffix is only checked against it: parsing
+//! `"5 mmol/L"` as `Glucose<MgdL>` is an error, not a conversion.
+//!
+//! Vital signs, which aren't constructed via `From<f64>`, implement `FromStr` in
+//! the vitals module on top of `parse_measurement`.
+//!
+//! Unit matching ignores whitespace and case, and accepts `u` or Greek `μ` for the
+//! micro sign, so `"97umol/l"` parses as `Creatinine<UmolL>`.
+
+use std::str::FromStr;
+
+use crate::{
+    lab::{
+        blood::{
+            albumin::Albumin,
+            alt::Alt,
+            anion_gap::AnionGap,
+            ast::Ast,
+            bicarbonate::Bicarbonate,
+            bilirubin::Bilirubin,
+            bun::Bun,
+            calcium::Calcium,
+            chloride::Chloride,
+            cholesterol::{Cholesterol, Hdl},
+            cortisol::Cortisol,
+            creatinine::Creatinine,
+            cystatin_c::CystatinC,
+            glucose::Glucose,
+            hemoglobin::Hemoglobin,
+            inr::Inr,
+            lactate::Lactate,
+            magnesium::Magnesium,
+            natriuretic::{Bnp, NtProBnp},
+            osmolality::Osmolality,
+            phosphate::Phosphate,
+            platelets::Platelets,
+            potassium::Potassium,
+            sodium::Sodium,
+            troponin::Troponin,
+            white_blood_cells::WhiteBloodCells,
+        },
+        gfr::{ClearanceRate, Gfr},
+    },
+    units::Unit,
+};
+
+/// Problems parsing a measurement from a string.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseMeasurementError {
+    /// The string didn't start with a number.
+    InvalidNumber(String),
+    /// The number wasn't followed by a unit.
+    MissingUnit,
+    /// The unit didn't match the one the target type is measured in.
+    UnitMismatch {
+        expected: &'static str,
+        found: String,
+    },
+}
+impl std::fmt::Display for ParseMeasurementError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseMeasurementError::InvalidNumber(text) => write!(f, "invalid number \"{text}\""),
+            ParseMeasurementError::MissingUnit => write!(f, "missing unit"),
+            ParseMeasurementError::UnitMismatch { expected, found } => {
+                write!(f, "expected unit \"{expected}\", found \"{found}\"")
+            }
+        }
+    }
+}
+impl std::error::Error for ParseMeasurementError {}
+
+/// Parse `"<number> <unit>"`, returning the number if the unit matches `U`.
+pub fn parse_measurement<U: Unit>(s: &str) -> Result<f64, ParseMeasurementError> {
+    let s = s.trim();
+    let split = s
+        .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | 'e' | 'E')))
+        .unwrap_or(s.len());
+    let (number, unit) = s.split_at(split);
+
+    let value = number
+        .parse::<f64>()
+        .map_err(|_| ParseMeasurementError::InvalidNumber(number.to_string()))?;
+    let unit = unit.trim();
+    if unit.is_empty() {
+        return Err(ParseMeasurementError::MissingUnit);
+    }
+    if normalize_unit(unit) != normalize_unit(U::ABBR) {
+        return Err(ParseMeasurementError::UnitMismatch {
+            expected: U::ABBR,
+            found: unit.to_string(),
+        });
+    }
+    Ok(value)
+}
+
+/// Lowercase, strip whitespace and fold the ways of writing "micro" together.
+fn normalize_unit(unit: &str) -> String {
+    unit.chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .map(|c| match c {
+            'u' | 'μ' => 'µ',
+            c => c,
+        })
+        .collect()
+}
+
+macro_rules! impl_from_str {
+    ($($ty:ident),+ $(,)?) => {
+        $(
+            impl<U: Unit> FromStr for $ty<U>
+            where
+                $ty<U>: From<f64>,
+            {
+                type Err = ParseMeasurementError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    parse_measurement::<U>(s).map($ty::from)
+                }
+            }
+        )+
+    };
+}
+
+impl_from_str!(
+    Albumin,
+    Alt,
+    AnionGap,
+    Ast,
+    Bicarbonate,
+    Bilirubin,
+    Bnp,
+    Bun,
+    Calcium,
+    Chloride,
+    Cholesterol,
+    ClearanceRate,
+    Cortisol,
+    Creatinine,
+    CystatinC,
+    Gfr,
+    Glucose,
+    Hdl,
+    Hemoglobin,
+    Inr,
+    Lactate,
+    Magnesium,
+    NtProBnp,
+    Osmolality,
+    Phosphate,
+    Platelets,
+    Potassium,
+    Sodium,
+    Troponin,
+    WhiteBloodCells,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::{InrUnit, MgdL, MmolL, PerNanoLiter, UmolL};
+
+    #[test]
+    fn parses_value_with_matching_unit() {
+        let scr: Creatinine<MgdL> = "1.2 mg/dL".parse().unwrap();
+        assert_eq!(scr.value(), 1.2);
+
+        let glu: Glucose<MmolL> = "5.5 mmol/L".parse().unwrap();
+        assert_eq!(glu.value(), 5.5);
+
+        let plt: Platelets<PerNanoLiter> = "250 ×10⁹/L".parse().unwrap();
+        assert_eq!(plt.value(), 250.0);
+    }
+
+    #[test]
+    fn tolerates_whitespace_case_and_micro_spellings() {
+        let scr: Creatinine<UmolL> = "97umol/l".parse().unwrap();
+        assert_eq!(scr.value(), 97.0);
+
+        let scr: Creatinine<UmolL> = "  97 μmol / L ".parse().unwrap();
+        assert_eq!(scr.value(), 97.0);
+
+        let glu: Glucose<MgdL> = "100MG/DL".parse().unwrap();
+        assert_eq!(glu.value(), 100.0);
+    }
+
+    #[test]
+    fn rejects_mismatched_unit() {
+        let err = "5 mmol/L".parse::<Glucose<MgdL>>().unwrap_err();
+        assert_eq!(
+            err,
+            ParseMeasurementError::UnitMismatch {
+                expected: "mg/dL",
+                found: "mmol/L".to_string()
+            }
+        );
+        assert_eq!(err.to_string(), "expected unit \"mg/dL\", found \"mmol/L\"");
+    }
+
+    #[test]
+    fn rejects_bad_number_and_missing_unit() {
+        assert_eq!(
+            "high mg/dL".parse::<Glucose<MgdL>>(),
+            Err(ParseMeasurementError::InvalidNumber(String::new()))
+        );
+        assert_eq!(
+            "1..2 mg/dL".parse::<Glucose<MgdL>>(),
+            Err(ParseMeasurementError::InvalidNumber("1..2".to_string()))
+        );
+        assert_eq!(
+            "100".parse::<Glucose<MgdL>>(),
+            Err(ParseMeasurementError::MissingUnit)
+        );
+        let inr: Inr<InrUnit> = "2.5 INR".parse().unwrap();
+        assert_eq!(inr.value(), 2.5);
+    }
+}
@@ -0,0 +1,195 @@
+//! Reference range module
+//!
+//! The `NumericRanged` impls classify results against compiled-in thresholds. A
+//! `ReferenceRangeProvider` lets an institution supply its own thresholds per
+//! analyte instead, without touching the lab types themselves.
+
+use crate::{
+    constants::{CORTISOL_UGDL_TO_NMOLL, TROP_NGL_TO_NGML},
+    lab::{
+        blood::{
+            albumin::{ALB_SERUM_THRESHOLDS_G_DL, ALB_SERUM_THRESHOLDS_G_L},
+            alt::{ALT_THRESHOLDS_UKAT_L, ALT_THRESHOLDS_U_L},
+            anion_gap::ANION_GAP_THRESHOLDS,
+            ast::{AST_THRESHOLDS_UKAT_L, AST_THRESHOLDS_U_L},
+            bicarbonate::HCO3_SERUM_THRESHOLDS,
+            bilirubin::{SERUM_BILI_RANGES_MGDL, SERUM_BILI_RANGES_UMOLL},
+            bun::{BUN_THRESHOLDS_MG_DL, BUN_THRESHOLDS_MMOL_L},
+            calcium::{
+                CA_SERUM_THRESHOLDS_MEQ_L, CA_SERUM_THRESHOLDS_MG_DL, CA_SERUM_THRESHOLDS_MMOL_L,
+            },
+            chloride::CL_SERUM_THRESHOLDS,
+            cortisol::CORTISOL_AM_RANGES_UGDL,
+            creatinine::{SCR_THRESHOLDS_MG_DL, SCR_THRESHOLDS_UMOL_L},
+            glucose::{GLU_SERUM_THRESHOLDS_MGDL, GLU_SERUM_THRESHOLDS_MMOLL},
+            hemoglobin::{hgb_thresholds_g_l, HGB_THRESHOLDS_G_DL},
+            osmolality::OSM_SERUM_THRESHOLDS,
+            platelets::PLT_THRESHOLDS,
+            potassium::K_SERUM_THRESHOLDS,
+            sodium::NA_SERUM_THRESHOLDS,
+            troponin::HS_TNI_URL_OVERALL_NG_L,
+            white_blood_cells::WBC_THRESHOLDS,
+        },
+        select_range, LabResult, RangeThreshold, ResultRange,
+    },
+    units::{GdL, MeqL, MgdL, NgL, UgdL, Unit, UL},
+};
+
+/// The analytes that can be classified through a `ReferenceRangeProvider`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnalyteKind {
+    Albumin,
+    Alt,
+    AnionGap,
+    Ast,
+    Bicarbonate,
+    Bilirubin,
+    Bun,
+    Calcium,
+    Chloride,
+    Cortisol,
+    Creatinine,
+    Glucose,
+    Hemoglobin,
+    Osmolality,
+    Platelets,
+    Potassium,
+    Sodium,
+    Troponin,
+    WhiteBloodCells,
+}
+
+/// Supplies range thresholds for an analyte reported in the given units (matched
+/// against `Unit::ABBR`, e.g. "mg/dL").
+pub trait ReferenceRangeProvider {
+    fn thresholds(&self, analyte: AnalyteKind, unit: &str) -> RangeThreshold;
+}
+
+/// The thresholds compiled into each lab type's `NumericRanged` impl.
+///
+/// Analytes reported in two unit systems get their conventional thresholds when
+/// `unit` matches the conventional abbreviation and their SI thresholds otherwise.
+/// Cortisol uses the morning range, as its `range()` does. Troponin and hemoglobin
+/// use sex-neutral limits; see `reference_ranges` for the sex-specific ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DefaultRanges;
+impl ReferenceRangeProvider for DefaultRanges {
+    fn thresholds(&self, analyte: AnalyteKind, unit: &str) -> RangeThreshold {
+        match analyte {
+            AnalyteKind::Albumin if unit == GdL::ABBR => ALB_SERUM_THRESHOLDS_G_DL,
+            AnalyteKind::Albumin => ALB_SERUM_THRESHOLDS_G_L,
+            AnalyteKind::Alt if unit == UL::ABBR => ALT_THRESHOLDS_U_L,
+            AnalyteKind::Alt => ALT_THRESHOLDS_UKAT_L,
+            AnalyteKind::AnionGap => ANION_GAP_THRESHOLDS,
+            AnalyteKind::Ast if unit == UL::ABBR => AST_THRESHOLDS_U_L,
+            AnalyteKind::Ast => AST_THRESHOLDS_UKAT_L,
+            AnalyteKind::Bicarbonate => HCO3_SERUM_THRESHOLDS,
+            AnalyteKind::Bilirubin if unit == MgdL::ABBR => SERUM_BILI_RANGES_MGDL,
+            AnalyteKind::Bilirubin => SERUM_BILI_RANGES_UMOLL,
+            AnalyteKind::Bun if unit == MgdL::ABBR => BUN_THRESHOLDS_MG_DL,
+            AnalyteKind::Bun => BUN_THRESHOLDS_MMOL_L,
+            AnalyteKind::Calcium if unit == MgdL::ABBR => CA_SERUM_THRESHOLDS_MG_DL,
+            AnalyteKind::Calcium if unit == MeqL::ABBR => CA_SERUM_THRESHOLDS_MEQ_L,
+            AnalyteKind::Calcium => CA_SERUM_THRESHOLDS_MMOL_L,
+            AnalyteKind::Chloride => CL_SERUM_THRESHOLDS,
+            AnalyteKind::Cortisol if unit == UgdL::ABBR => CORTISOL_AM_RANGES_UGDL,
+            AnalyteKind::Cortisol => RangeThreshold {
+                crit_low: CORTISOL_AM_RANGES_UGDL.crit_low * CORTISOL_UGDL_TO_NMOLL,
+                low_norm: CORTISOL_AM_RANGES_UGDL.low_norm * CORTISOL_UGDL_TO_NMOLL,
+                norm_hi: CORTISOL_AM_RANGES_UGDL.norm_hi * CORTISOL_UGDL_TO_NMOLL,
+                hi_crit: CORTISOL_AM_RANGES_UGDL.hi_crit * CORTISOL_UGDL_TO_NMOLL,
+            },
+            AnalyteKind::Creatinine if unit == MgdL::ABBR => SCR_THRESHOLDS_MG_DL,
+            AnalyteKind::Creatinine => SCR_THRESHOLDS_UMOL_L,
+            AnalyteKind::Glucose if unit == MgdL::ABBR => GLU_SERUM_THRESHOLDS_MGDL,
+            AnalyteKind::Glucose => GLU_SERUM_THRESHOLDS_MMOLL,
+            AnalyteKind::Hemoglobin if unit == GdL::ABBR => HGB_THRESHOLDS_G_DL,
+            AnalyteKind::Hemoglobin => hgb_thresholds_g_l(HGB_THRESHOLDS_G_DL),
+            AnalyteKind::Osmolality => OSM_SERUM_THRESHOLDS,
+            AnalyteKind::Platelets => PLT_THRESHOLDS,
+            AnalyteKind::Potassium => K_SERUM_THRESHOLDS,
+            AnalyteKind::Sodium => NA_SERUM_THRESHOLDS,
+            AnalyteKind::Troponin => {
+                let url = if unit == NgL::ABBR {
+                    HS_TNI_URL_OVERALL_NG_L
+                } else {
+                    HS_TNI_URL_OVERALL_NG_L * TROP_NGL_TO_NGML
+                };
+                RangeThreshold {
+                    crit_low: 0.0,
+                    low_norm: 0.0,
+                    norm_hi: url,
+                    hi_crit: f64::INFINITY,
+                }
+            }
+            AnalyteKind::WhiteBloodCells => WBC_THRESHOLDS,
+        }
+    }
+}
+
+/// Classify a lab result against the thresholds from `provider`.
+pub fn classify_with<P: ReferenceRangeProvider>(provider: &P, result: &LabResult) -> ResultRange {
+    let thresholds = provider.thresholds(result.kind(), result.units());
+    select_range(result.value(), &thresholds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lab::blood::{
+        creatinine::CreatinineExt, potassium::SerumPotassiumExt, sodium::SerumSodiumExt,
+    };
+
+    /// Narrows the sodium normal range to 137-143; defers everything else.
+    struct StrictSodium;
+    impl ReferenceRangeProvider for StrictSodium {
+        fn thresholds(&self, analyte: AnalyteKind, unit: &str) -> RangeThreshold {
+            match analyte {
+                AnalyteKind::Sodium => RangeThreshold {
+                    crit_low: 130.0,
+                    low_norm: 137.0,
+                    norm_hi: 143.0,
+                    hi_crit: 150.0,
+                },
+                _ => DefaultRanges.thresholds(analyte, unit),
+            }
+        }
+    }
+
+    #[test]
+    fn default_ranges_match_compiled_in_classification() {
+        let results: Vec<LabResult> = vec![
+            3.0.k_serum_meq().into(),
+            1.1.cr_serum_mg_dl().into(),
+            4.0.cr_serum_mg_dl().into(),
+        ];
+        for result in &results {
+            assert_eq!(classify_with(&DefaultRanges, result), result.range());
+        }
+    }
+
+    #[test]
+    fn custom_provider_reclassifies_sodium() {
+        let sodium: LabResult = 136.0.na_serum_meq().into();
+        assert_eq!(classify_with(&DefaultRanges, &sodium), ResultRange::Normal);
+        assert_eq!(classify_with(&StrictSodium, &sodium), ResultRange::Low);
+
+        let potassium: LabResult = 4.0.k_serum_meq().into();
+        assert_eq!(
+            classify_with(&StrictSodium, &potassium),
+            ResultRange::Normal
+        );
+    }
+
+    #[test]
+    fn default_ranges_pick_thresholds_by_unit() {
+        assert_eq!(
+            DefaultRanges.thresholds(AnalyteKind::Creatinine, MgdL::ABBR),
+            SCR_THRESHOLDS_MG_DL
+        );
+        assert_eq!(
+            DefaultRanges.thresholds(AnalyteKind::Creatinine, "µmol/L"),
+            SCR_THRESHOLDS_UMOL_L
+        );
+    }
+}
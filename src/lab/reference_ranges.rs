@@ -0,0 +1,250 @@
+//! Demographic reference range registry
+//!
+//! Some analytes have normal limits that depend on the patient's sex or life stage
+//! rather than a single population range. This module is the one place those
+//! thresholds are recorded, keyed by `(AnalyteKind, SexAtBirth, AgeGroup, unit)`. Analytes
+//! without a demographic entry fall back on the `DefaultRanges` thresholds.
+
+use crate::{
+    constants::TROP_NGL_TO_NGML,
+    history::{AgeGroup, SexAtBirth},
+    lab::{
+        blood::{
+            hemoglobin::{
+                hgb_thresholds_g_l, HGB_FEMALE_THRESHOLDS_G_DL, HGB_MALE_THRESHOLDS_G_DL,
+            },
+            troponin::{HS_TNI_URL_FEMALE_NG_L, HS_TNI_URL_MALE_NG_L},
+        },
+        reference::{AnalyteKind, DefaultRanges, ReferenceRangeProvider},
+        RangeThreshold,
+    },
+    units::{GdL, NgL, NgmL, Unit, GL},
+};
+
+/// Troponin is only flagged above its 99th-percentile URL; there is no low or
+/// critical tier, so everything above the URL classifies as High.
+pub(crate) const fn troponin_thresholds(url: f64) -> RangeThreshold {
+    RangeThreshold {
+        crit_low: 0.0,
+        low_norm: 0.0,
+        norm_hi: url,
+        hi_crit: f64::INFINITY,
+    }
+}
+
+/// One row of the registry. `None` for `sex` or `ages` matches any patient.
+struct DemographicRange {
+    analyte: AnalyteKind,
+    sex: Option<SexAtBirth>,
+    ages: Option<&'static [AgeGroup]>,
+    unit: &'static str,
+    thresholds: RangeThreshold,
+}
+
+const ADULTS: &[AgeGroup] = &[AgeGroup::Adult, AgeGroup::Geriatric];
+
+const REGISTRY: &[DemographicRange] = &[
+    DemographicRange {
+        analyte: AnalyteKind::Troponin,
+        sex: Some(SexAtBirth::Female),
+        ages: Some(ADULTS),
+        unit: NgL::ABBR,
+        thresholds: troponin_thresholds(HS_TNI_URL_FEMALE_NG_L),
+    },
+    DemographicRange {
+        analyte: AnalyteKind::Troponin,
+        sex: Some(SexAtBirth::Male),
+        ages: Some(ADULTS),
+        unit: NgL::ABBR,
+        thresholds: troponin_thresholds(HS_TNI_URL_MALE_NG_L),
+    },
+    DemographicRange {
+        analyte: AnalyteKind::Troponin,
+        sex: Some(SexAtBirth::Female),
+        ages: Some(ADULTS),
+        unit: NgmL::ABBR,
+        thresholds: troponin_thresholds(HS_TNI_URL_FEMALE_NG_L * TROP_NGL_TO_NGML),
+    },
+    DemographicRange {
+        analyte: AnalyteKind::Troponin,
+        sex: Some(SexAtBirth::Male),
+        ages: Some(ADULTS),
+        unit: NgmL::ABBR,
+        thresholds: troponin_thresholds(HS_TNI_URL_MALE_NG_L * TROP_NGL_TO_NGML),
+    },
+    DemographicRange {
+        analyte: AnalyteKind::Hemoglobin,
+        sex: Some(SexAtBirth::Female),
+        ages: Some(ADULTS),
+        unit: GdL::ABBR,
+        thresholds: HGB_FEMALE_THRESHOLDS_G_DL,
+    },
+    DemographicRange {
+        analyte: AnalyteKind::Hemoglobin,
+        sex: Some(SexAtBirth::Male),
+        ages: Some(ADULTS),
+        unit: GdL::ABBR,
+        thresholds: HGB_MALE_THRESHOLDS_G_DL,
+    },
+    DemographicRange {
+        analyte: AnalyteKind::Hemoglobin,
+        sex: Some(SexAtBirth::Female),
+        ages: Some(ADULTS),
+        unit: GL::ABBR,
+        thresholds: hgb_thresholds_g_l(HGB_FEMALE_THRESHOLDS_G_DL),
+    },
+    DemographicRange {
+        analyte: AnalyteKind::Hemoglobin,
+        sex: Some(SexAtBirth::Male),
+        ages: Some(ADULTS),
+        unit: GL::ABBR,
+        thresholds: hgb_thresholds_g_l(HGB_MALE_THRESHOLDS_G_DL),
+    },
+];
+
+/// The demographic-specific thresholds for `analyte` in `unit` (matched against
+/// `Unit::ABBR`), or `None` if the registry has no entry for this patient.
+pub fn demographic_thresholds(
+    analyte: AnalyteKind,
+    sex: SexAtBirth,
+    age: AgeGroup,
+    unit: &str,
+) -> Option<RangeThreshold> {
+    REGISTRY
+        .iter()
+        .find(|entry| {
+            entry.analyte == analyte
+                && entry.unit == unit
+                && entry.sex.is_none_or(|s| s == sex)
+                && entry.ages.is_none_or(|ages| ages.contains(&age))
+        })
+        .map(|entry| entry.thresholds)
+}
+
+/// The thresholds for `analyte` in `unit` for a given patient: the registry entry
+/// when there is one, the `DefaultRanges` thresholds otherwise.
+pub fn thresholds_for(
+    analyte: AnalyteKind,
+    sex: SexAtBirth,
+    age: AgeGroup,
+    unit: &str,
+) -> RangeThreshold {
+    demographic_thresholds(analyte, sex, age, unit)
+        .unwrap_or_else(|| DefaultRanges.thresholds(analyte, unit))
+}
+
+/// A `ReferenceRangeProvider` for one patient, so `classify_with` picks up their
+/// sex- and age-specific ranges.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DemographicRanges {
+    pub sex: SexAtBirth,
+    pub age: AgeGroup,
+}
+impl ReferenceRangeProvider for DemographicRanges {
+    fn thresholds(&self, analyte: AnalyteKind, unit: &str) -> RangeThreshold {
+        thresholds_for(analyte, self.sex, self.age, unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        lab::{
+            blood::creatinine::{CreatinineExt, SCR_THRESHOLDS_MG_DL, SCR_THRESHOLDS_UMOL_L},
+            reference::classify_with,
+            LabResult, ResultRange,
+        },
+        units::{MgdL, UmolL},
+    };
+
+    #[test]
+    fn registry_returns_the_inline_troponin_urls() {
+        let female = thresholds_for(
+            AnalyteKind::Troponin,
+            SexAtBirth::Female,
+            AgeGroup::Adult,
+            NgL::ABBR,
+        );
+        let male = thresholds_for(
+            AnalyteKind::Troponin,
+            SexAtBirth::Male,
+            AgeGroup::Geriatric,
+            NgL::ABBR,
+        );
+        assert_eq!(female.norm_hi, 16.0);
+        assert_eq!(male.norm_hi, 34.0);
+
+        let male_ng_ml = thresholds_for(
+            AnalyteKind::Troponin,
+            SexAtBirth::Male,
+            AgeGroup::Adult,
+            NgmL::ABBR,
+        );
+        assert!((male_ng_ml.norm_hi - 0.034).abs() < 1e-12);
+    }
+
+    #[test]
+    fn registry_has_sex_specific_hemoglobin() {
+        let female = thresholds_for(
+            AnalyteKind::Hemoglobin,
+            SexAtBirth::Female,
+            AgeGroup::Adult,
+            GdL::ABBR,
+        );
+        let male = thresholds_for(
+            AnalyteKind::Hemoglobin,
+            SexAtBirth::Male,
+            AgeGroup::Adult,
+            GL::ABBR,
+        );
+        assert_eq!(female, HGB_FEMALE_THRESHOLDS_G_DL);
+        assert_eq!(male.low_norm, 135.0);
+    }
+
+    #[test]
+    fn analytes_without_demographic_entries_fall_back_on_defaults() {
+        for sex in [SexAtBirth::Female, SexAtBirth::Male] {
+            assert_eq!(
+                thresholds_for(AnalyteKind::Creatinine, sex, AgeGroup::Adult, MgdL::ABBR),
+                SCR_THRESHOLDS_MG_DL
+            );
+            assert_eq!(
+                thresholds_for(AnalyteKind::Creatinine, sex, AgeGroup::Adult, UmolL::ABBR),
+                SCR_THRESHOLDS_UMOL_L
+            );
+        }
+        assert_eq!(
+            demographic_thresholds(
+                AnalyteKind::Creatinine,
+                SexAtBirth::Male,
+                AgeGroup::Adult,
+                MgdL::ABBR
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn pediatric_troponin_has_no_registry_entry() {
+        assert_eq!(
+            demographic_thresholds(
+                AnalyteKind::Troponin,
+                SexAtBirth::Female,
+                AgeGroup::Child,
+                NgL::ABBR
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn demographic_provider_matches_default_classification() {
+        let provider = DemographicRanges {
+            sex: SexAtBirth::Female,
+            age: AgeGroup::Adult,
+        };
+        let scr: LabResult = 2.0.cr_serum_mg_dl().into();
+        assert_eq!(classify_with(&provider, &scr), ResultRange::High);
+    }
+}
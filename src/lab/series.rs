@@ -0,0 +1,187 @@
+//! Lab series module
+//!
+//! A `LabSeries` holds repeated results for one analyte, each stamped with the
+//! hours elapsed since some fixed reference point (e.g. admission), so changes over
+//! time can be assessed.
+
+use crate::{
+    lab::{blood::creatinine::Creatinine, NumericRanged},
+    units::{creatinine::CreatinineUnit, MgdL, Unit},
+};
+
+/// KDIGO absolute creatinine rise (mg/dL) within 48 hours that defines AKI.
+pub const KDIGO_ABSOLUTE_RISE_MG_DL: f64 = 0.3;
+
+/// KDIGO window (hours) for the absolute creatinine rise.
+pub const KDIGO_ABSOLUTE_WINDOW_HOURS: f64 = 48.0;
+
+/// KDIGO creatinine ratio to baseline that defines AKI.
+pub const KDIGO_RELATIVE_RISE: f64 = 1.5;
+
+/// KDIGO window (hours) within which the baseline for the relative rise is taken.
+pub const KDIGO_RELATIVE_WINDOW_HOURS: f64 = 7.0 * 24.0;
+
+/// Timestamped results for a single analyte, kept in time order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LabSeries<T> {
+    points: Vec<(f64, T)>,
+}
+impl<T> Default for LabSeries<T> {
+    fn default() -> Self {
+        Self { points: Vec::new() }
+    }
+}
+impl<T> LabSeries<T> /* builder / setters */ {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Add a result taken `hours` after the series' reference point. Results may be
+    /// added in any order.
+    pub fn with(mut self, hours: f64, result: T) -> Self {
+        let index = self.points.partition_point(|(at, _)| *at <= hours);
+        self.points.insert(index, (hours, result));
+        self
+    }
+}
+
+impl<T> LabSeries<T> /* getters */ {
+    /// The results as `(hours, result)` pairs, earliest first.
+    pub fn points(&self) -> &[(f64, T)] {
+        &self.points
+    }
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+}
+
+impl<T> LabSeries<T> /* calculations */ {
+    /// Latest value minus earliest value, or `None` with fewer than two results.
+    pub fn delta<U: Unit>(&self) -> Option<f64>
+    where
+        T: NumericRanged<U>,
+    {
+        let (first, last) = self.first_and_last()?;
+        Some(last.value() - first.value())
+    }
+
+    /// Change from the earliest to the latest value as a percentage of the earliest,
+    /// or `None` with fewer than two results or an earliest value of zero.
+    pub fn percent_change<U: Unit>(&self) -> Option<f64>
+    where
+        T: NumericRanged<U>,
+    {
+        let (first, last) = self.first_and_last()?;
+        if first.value() == 0.0 {
+            return None;
+        }
+        Some(100.0 * (last.value() - first.value()) / first.value())
+    }
+
+    fn first_and_last(&self) -> Option<(&T, &T)> {
+        match self.points.as_slice() {
+            [(_, first), .., (_, last)] => Some((first, last)),
+            _ => None,
+        }
+    }
+}
+
+impl<U: CreatinineUnit> LabSeries<Creatinine<U>> {
+    /// Whether the series meets the KDIGO creatinine criteria for acute kidney
+    /// injury (stage 1 or above): a rise of at least 0.3 mg/dL within 48 hours, or a
+    /// value at least 1.5 times the lowest value in the preceding 7 days.
+    pub fn meets_kdigo_aki(&self) -> bool {
+        let mg_dl: Vec<(f64, f64)> = self
+            .points
+            .iter()
+            .map(|(hours, scr)| (*hours, MgdL::from_umol_l(U::to_umol_l(scr.value()))))
+            .collect();
+
+        mg_dl.iter().enumerate().any(|(i, &(hours, scr))| {
+            mg_dl[..i].iter().any(|&(earlier_hours, baseline)| {
+                let elapsed = hours - earlier_hours;
+                (elapsed <= KDIGO_ABSOLUTE_WINDOW_HOURS
+                    && scr - baseline >= KDIGO_ABSOLUTE_RISE_MG_DL)
+                    || (elapsed <= KDIGO_RELATIVE_WINDOW_HOURS
+                        && scr >= KDIGO_RELATIVE_RISE * baseline)
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lab::blood::creatinine::CreatinineExt;
+
+    fn approx_eq(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} !~= {}", a, b);
+    }
+
+    #[test]
+    fn results_are_kept_in_time_order() {
+        let series = LabSeries::new()
+            .with(24.0, 1.2.cr_serum_mg_dl())
+            .with(0.0, 1.0.cr_serum_mg_dl());
+        assert_eq!(series.points()[0].0, 0.0);
+        approx_eq(series.delta().unwrap(), 0.2);
+        approx_eq(series.percent_change().unwrap(), 20.0);
+    }
+
+    #[test]
+    fn single_result_has_no_delta() {
+        let series = LabSeries::new().with(0.0, 1.0.cr_serum_mg_dl());
+        assert_eq!(series.delta(), None);
+        assert_eq!(series.percent_change(), None);
+    }
+
+    #[test]
+    fn zero_baseline_has_no_percent_change() {
+        let series = LabSeries::new()
+            .with(0.0, 0.0.cr_serum_mg_dl())
+            .with(24.0, 1.0.cr_serum_mg_dl());
+        approx_eq(series.delta().unwrap(), 1.0);
+        assert_eq!(series.percent_change(), None);
+    }
+
+    #[test]
+    fn rise_of_0_3_within_48_hours_is_aki() {
+        let series = LabSeries::new()
+            .with(0.0, 0.9.cr_serum_mg_dl())
+            .with(24.0, 1.0.cr_serum_mg_dl())
+            .with(40.0, 1.25.cr_serum_mg_dl());
+        assert!(series.meets_kdigo_aki());
+    }
+
+    #[test]
+    fn one_and_a_half_times_baseline_within_7_days_is_aki() {
+        // a slow rise, never 0.3 mg/dL in 48 h, reaching 1.5x by day 6
+        let series = LabSeries::new()
+            .with(0.0, 0.4.cr_serum_mg_dl())
+            .with(72.0, 0.5.cr_serum_mg_dl())
+            .with(144.0, 0.62.cr_serum_mg_dl());
+        assert!(series.meets_kdigo_aki());
+    }
+
+    #[test]
+    fn gradual_drift_is_not_aki() {
+        let series = LabSeries::new()
+            .with(0.0, 1.0.cr_serum_mg_dl())
+            .with(48.0, 1.2.cr_serum_mg_dl())
+            .with(120.0, 1.3.cr_serum_mg_dl())
+            // 1.45x the first value, but more than 7 days after it
+            .with(200.0, 1.45.cr_serum_mg_dl());
+        assert!(!series.meets_kdigo_aki());
+    }
+
+    #[test]
+    fn kdigo_uses_mg_dl_for_si_creatinine() {
+        let factor = crate::constants::SCR_MGDL_TO_UMOLL;
+        let series = LabSeries::new()
+            .with(0.0, (1.0 * factor).cr_serum_umol_l())
+            .with(24.0, (1.3 * factor).cr_serum_umol_l());
+        assert!(series.meets_kdigo_aki());
+    }
+}
@@ -0,0 +1,125 @@
+//! Urine indices module
+//!
+//! Derived urine values such as the fractional excretion of sodium (FENa), which
+//! compare urine and plasma concentrations to judge renal tubular handling, and
+//! weight-indexed urine output.
+
+use crate::{lab::vitals::Weight, units::vitals::WeightUnit};
+
+/// KDIGO urine output criterion for AKI: below this many mL/kg/h (sustained for
+/// 6 hours or more).
+pub const KDIGO_OLIGURIA_ML_KG_HR: f64 = 0.5;
+
+/// A percentage result, as produced by fractional excretion calculations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Percentage {
+    value: f64,
+}
+impl Percentage {
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Interpret the percentage as a FENa: below 1% suggests a prerenal cause of
+    /// AKI and above 2% suggests intrinsic renal injury (e.g. ATN).
+    ///
+    /// Diuretics raise FENa, so a high value is unreliable in patients taking them.
+    pub fn range(&self) -> FenaRange {
+        match self.value {
+            val if val < 1.0 => FenaRange::Prerenal,
+            val if val <= 2.0 => FenaRange::Indeterminate,
+            _ => FenaRange::Intrinsic,
+        }
+    }
+}
+impl std::fmt::Display for Percentage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.2}%", self.value)
+    }
+}
+impl From<f64> for Percentage {
+    fn from(value: f64) -> Self {
+        Percentage { value }
+    }
+}
+
+/// Interpretation of a fractional excretion of sodium in AKI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FenaRange {
+    /// FENa < 1%
+    Prerenal,
+    /// FENa 1–2%
+    Indeterminate,
+    /// FENa > 2%
+    Intrinsic,
+}
+
+/// Urine output indexed to body weight and time, in mL/kg/h.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UrineOutput {
+    ml_kg_hr: f64,
+}
+impl UrineOutput {
+    /// Urine output from the total volume (mL) collected over `hours`, for a
+    /// patient of the given weight (converted to kg).
+    pub fn new<W: WeightUnit>(volume_ml: f64, hours: f64, weight: Weight<W>) -> Self {
+        UrineOutput {
+            ml_kg_hr: volume_ml / W::to_kg(weight.value()) / hours,
+        }
+    }
+    /// The output rate in mL/kg/h.
+    pub fn ml_kg_hr(&self) -> f64 {
+        self.ml_kg_hr
+    }
+    /// Whether the rate is below `KDIGO_OLIGURIA_ML_KG_HR`. KDIGO also requires the
+    /// low rate to last at least 6 hours, which is up to the caller's collection
+    /// period.
+    pub fn is_oliguric(&self) -> bool {
+        self.ml_kg_hr < KDIGO_OLIGURIA_ML_KG_HR
+    }
+}
+impl std::fmt::Display for UrineOutput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "UO ({:.2} mL/kg/h)", self.ml_kg_hr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lab::vitals::WeightExt;
+
+    #[test]
+    fn fena_ranges_are_selected_correctly() {
+        assert_eq!(Percentage::from(0.4).range(), FenaRange::Prerenal);
+        assert_eq!(Percentage::from(1.0).range(), FenaRange::Indeterminate);
+        assert_eq!(Percentage::from(2.0).range(), FenaRange::Indeterminate);
+        assert_eq!(Percentage::from(3.5).range(), FenaRange::Intrinsic);
+    }
+
+    #[test]
+    fn percentage_display_format() {
+        assert_eq!(format!("{}", Percentage::from(0.456)), "0.46%");
+    }
+
+    #[test]
+    fn low_urine_output_is_oliguric() {
+        let uo = UrineOutput::new(100.0, 4.0, 70.0.weight_kg());
+        assert!((uo.ml_kg_hr() - 100.0 / 280.0).abs() < 1e-12);
+        assert!(uo.is_oliguric());
+        assert_eq!(format!("{uo}"), "UO (0.36 mL/kg/h)");
+    }
+
+    #[test]
+    fn well_perfused_patient_is_not_oliguric() {
+        let uo = UrineOutput::new(420.0, 6.0, 70.0.weight_kg());
+        assert!((uo.ml_kg_hr() - 1.0).abs() < 1e-12);
+        assert!(!uo.is_oliguric());
+    }
+
+    #[test]
+    fn urine_output_converts_weight_to_kg() {
+        let lb = UrineOutput::new(420.0, 6.0, 154.324.weight_lb());
+        assert!((lb.ml_kg_hr() - 1.0).abs() < 1e-3);
+    }
+}
@@ -2,22 +2,35 @@
 //!
 //! Contains definitions for measurements of vital signs.
 
-use std::marker::PhantomData;
+use std::{marker::PhantomData, str::FromStr};
 
 use crate::{
     constants::{FT_TO_M, KG_TO_LB, LB_TO_KG, M_TO_FT},
-    units::{Foot, Kg, KgM2, Lb, Meter, Unit},
+    lab::{
+        parse::{parse_measurement, ParseMeasurementError},
+        select_range, NumericRanged, RangeThreshold, ResultRange,
+    },
+    units::{
+        vitals::{HeightUnit, TemperatureUnit},
+        Celsius, Fahrenheit, Foot, Kg, KgM2, Lb, Meter, PerMinute, Unit, M2,
+    },
 };
 
+pub mod blood_pressure;
+pub mod growth;
+
 /*
  *          Weight measurements
  */
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
 pub struct Weight<U: Unit> {
     value: f64,
     _ghost: PhantomData<U>,
 }
+#[cfg(feature = "serde")]
+crate::serialize::impl_serde_measurement!(Weight, _ghost);
+crate::ops::impl_measurement_ops!(Weight, _ghost);
 impl<U: Unit> Weight<U> {
     pub fn value(&self) -> f64 {
         self.value
@@ -66,16 +79,29 @@ impl<U: Unit> std::fmt::Display for Weight<U> {
         write!(f, "Weight ({:.1} {})", self.value, U::ABBR)
     }
 }
+impl<U: Unit> FromStr for Weight<U> {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Weight {
+            value: parse_measurement::<U>(s)?,
+            _ghost: PhantomData,
+        })
+    }
+}
 
 /*
  *      Height measurements
  */
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 pub struct Height<U: Unit> {
     value: f64,
     _unit: PhantomData<U>,
 }
+#[cfg(feature = "serde")]
+crate::serialize::impl_serde_measurement!(Height, _unit);
+crate::ops::impl_measurement_ops!(Height, _unit);
 impl<U: Unit> Height<U> {
     /// Get the raw value for the height.
     pub fn value(&self) -> f64 {
@@ -131,25 +157,256 @@ impl<U: Unit> std::fmt::Display for Height<U> {
         write!(f, "Height ({:.1} {})", self.value, U::ABBR)
     }
 }
+impl<U: Unit> FromStr for Height<U> {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Height {
+            value: parse_measurement::<U>(s)?,
+            _unit: PhantomData,
+        })
+    }
+}
+
+/// Broselow tape zones: (upper length bound in cm, zone midpoint weight in kg).
+/// Each zone spans from the previous bound (or `BROSELOW_MIN_CM`) up to its own.
+const BROSELOW_ZONES: [(f64, f64); 9] = [
+    (53.0, 4.0),   // grey, 3-5 kg
+    (67.0, 6.5),   // pink, 6-7 kg
+    (75.0, 8.5),   // red, 8-9 kg
+    (85.0, 10.5),  // purple, 10-11 kg
+    (98.0, 13.0),  // yellow, 12-14 kg
+    (110.0, 16.5), // white, 15-18 kg
+    (122.0, 21.0), // blue, 19-23 kg
+    (134.0, 26.5), // orange, 24-29 kg
+    (147.0, 33.0), // green, 30-36 kg
+];
+const BROSELOW_MIN_CM: f64 = 46.0;
+
+/// Estimate a child's weight from supine length, Broselow-tape style.
+///
+/// Returns the midpoint weight of the color zone the length falls in. The tape is
+/// only valid from 46 cm up to 147 cm (roughly 3-36 kg); outside that range there
+/// is no zone and `None` is returned.
+pub fn estimate_weight_from_length<U: HeightUnit>(length: Height<U>) -> Option<Weight<Kg>> {
+    let length_cm = U::to_m(length.value()) * 100.0;
+    if length_cm < BROSELOW_MIN_CM {
+        return None;
+    }
+    BROSELOW_ZONES
+        .iter()
+        .find(|(upper_cm, _)| length_cm < *upper_cm)
+        .map(|(_, kg)| kg.weight_kg())
+}
+
+/*
+ *      Temperature measurements
+ */
+
+/// Body temperature thresholds in °C: below 35 is hypothermia and above 40
+/// hyperpyrexia; 37.5-40 is fever.
+pub(crate) const TEMP_THRESHOLDS_C: RangeThreshold = RangeThreshold {
+    crit_low: 35.0,
+    low_norm: 36.0,
+    norm_hi: 37.5,
+    hi_crit: 40.0,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Temperature<U: Unit> {
+    value: f64,
+    _unit: PhantomData<U>,
+}
+#[cfg(feature = "serde")]
+crate::serialize::impl_serde_measurement!(Temperature, _unit);
+impl<U: Unit> Temperature<U> {
+    /// Get the raw value for the temperature.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+// construct from f64
+pub trait TemperatureExt {
+    fn temp_c(self) -> Temperature<Celsius>;
+    fn temp_f(self) -> Temperature<Fahrenheit>;
+}
+impl TemperatureExt for f64 {
+    fn temp_c(self) -> Temperature<Celsius> {
+        Temperature {
+            value: self,
+            _unit: PhantomData,
+        }
+    }
+    fn temp_f(self) -> Temperature<Fahrenheit> {
+        Temperature {
+            value: self,
+            _unit: PhantomData,
+        }
+    }
+}
+// convert temperature between unit types (offset, so not a simple factor)
+impl From<Temperature<Fahrenheit>> for Temperature<Celsius> {
+    fn from(other: Temperature<Fahrenheit>) -> Self {
+        Temperature {
+            value: Fahrenheit::to_celsius(other.value),
+            _unit: PhantomData,
+        }
+    }
+}
+impl From<Temperature<Celsius>> for Temperature<Fahrenheit> {
+    fn from(other: Temperature<Celsius>) -> Self {
+        Temperature {
+            value: Fahrenheit::from_celsius(other.value),
+            _unit: PhantomData,
+        }
+    }
+}
+impl<U: Unit> std::fmt::Display for Temperature<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Temperature ({:.1} {})", self.value, U::ABBR)
+    }
+}
+// Both units classify against the Celsius thresholds, since °F isn't a simple
+// multiple of °C.
+impl NumericRanged<Celsius> for Temperature<Celsius> {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn range(&self) -> ResultRange {
+        select_range(self.value, &TEMP_THRESHOLDS_C)
+    }
+}
+impl NumericRanged<Fahrenheit> for Temperature<Fahrenheit> {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn range(&self) -> ResultRange {
+        select_range(Fahrenheit::to_celsius(self.value), &TEMP_THRESHOLDS_C)
+    }
+}
+impl<U: Unit> FromStr for Temperature<U> {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Temperature {
+            value: parse_measurement::<U>(s)?,
+            _unit: PhantomData,
+        })
+    }
+}
+
+/*
+ *      Respiratory rate
+ */
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RespiratoryRate<U: Unit> {
+    value: f64,
+    _unit: PhantomData<U>,
+}
+#[cfg(feature = "serde")]
+crate::serialize::impl_serde_measurement!(RespiratoryRate, _unit);
+impl<U: Unit> RespiratoryRate<U> {
+    /// Get the raw value (breaths per minute).
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+pub trait RespiratoryRateExt {
+    fn breaths_per_min(self) -> RespiratoryRate<PerMinute>;
+}
+impl RespiratoryRateExt for f64 {
+    fn breaths_per_min(self) -> RespiratoryRate<PerMinute> {
+        RespiratoryRate {
+            value: self,
+            _unit: PhantomData,
+        }
+    }
+}
+impl<U: Unit> std::fmt::Display for RespiratoryRate<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RR ({:.0} {})", self.value, U::ABBR)
+    }
+}
+impl<U: Unit> FromStr for RespiratoryRate<U> {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(RespiratoryRate {
+            value: parse_measurement::<U>(s)?,
+            _unit: PhantomData,
+        })
+    }
+}
 
 //
 //      BMI Result / Value
 //
 
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Bmi<U: Unit> {
     value: f64,
     _units: PhantomData<U>,
 }
+#[cfg(feature = "serde")]
+crate::serialize::impl_serde_measurement!(Bmi, _units);
 impl<U: Unit> Bmi<U> {
     pub fn value(&self) -> f64 {
         self.value
     }
 }
+impl Bmi<KgM2> {
+    /// WHO weight category. Each cutoff belongs to the category above it, e.g. a BMI of
+    /// exactly 25.0 is overweight.
+    pub fn classify(&self) -> BmiClass {
+        match self.value {
+            val if val < 18.5 => BmiClass::Underweight,
+            val if val < 25.0 => BmiClass::Normal,
+            val if val < 30.0 => BmiClass::Overweight,
+            val if val < 35.0 => BmiClass::ObeseClass1,
+            val if val < 40.0 => BmiClass::ObeseClass2,
+            _ => BmiClass::ObeseClass3,
+        }
+    }
+}
+
+/// WHO adult BMI categories, finer-grained than `ResultRange`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BmiClass {
+    Underweight,
+    Normal,
+    Overweight,
+    ObeseClass1,
+    ObeseClass2,
+    ObeseClass3,
+}
+
+// Coarse mapping onto the shared ranges: only class III obesity is treated as critical.
+impl NumericRanged<KgM2> for Bmi<KgM2> {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn range(&self) -> ResultRange {
+        match self.classify() {
+            BmiClass::Underweight => ResultRange::Low,
+            BmiClass::Normal => ResultRange::Normal,
+            BmiClass::Overweight | BmiClass::ObeseClass1 | BmiClass::ObeseClass2 => {
+                ResultRange::High
+            }
+            BmiClass::ObeseClass3 => ResultRange::CriticalHigh,
+        }
+    }
+}
 impl<U: Unit> std::fmt::Display for Bmi<U> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "BMI ({:.1} {})", self.value, U::ABBR)
     }
 }
+/// Wraps an already-computed BMI value. To calculate BMI from a `Weight` and
+/// `Height` in any units, use `calculators::bmi`.
 pub trait BmiExt {
     fn to_bmi(self) -> Bmi<KgM2>;
 }
@@ -162,6 +419,39 @@ impl BmiExt for f64 {
     }
 }
 
+//
+//      Body Surface Area
+//
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BodySurfaceArea<U: Unit> {
+    value: f64,
+    _units: PhantomData<U>,
+}
+#[cfg(feature = "serde")]
+crate::serialize::impl_serde_measurement!(BodySurfaceArea, _units);
+impl<U: Unit> BodySurfaceArea<U> {
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+impl<U: Unit> std::fmt::Display for BodySurfaceArea<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BSA ({:.2} {})", self.value, U::ABBR)
+    }
+}
+pub trait BsaExt {
+    fn to_bsa(self) -> BodySurfaceArea<M2>;
+}
+impl BsaExt for f64 {
+    fn to_bsa(self) -> BodySurfaceArea<M2> {
+        BodySurfaceArea {
+            value: self,
+            _units: PhantomData,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -170,6 +460,22 @@ mod tests {
         assert!((a - b).abs() < 1e-6, "{} !~= {}", a, b);
     }
 
+    #[test]
+    fn weights_add_and_subtract_in_the_same_unit() {
+        assert_eq!(70.0.weight_kg() + 5.0.weight_kg(), 75.0.weight_kg());
+        assert_eq!(70.0.weight_kg() - 5.0.weight_kg(), 65.0.weight_kg());
+    }
+
+    #[test]
+    fn weights_and_heights_scale_by_a_factor() {
+        // adjusted body weight: IBW + 0.4 × (actual - IBW)
+        let ibw = 60.0.weight_kg();
+        let actual = 100.0.weight_kg();
+        let adjusted = ibw + (actual - ibw) * 0.4;
+        approx_eq(adjusted.value(), 76.0);
+        approx_eq((1.8.height_in_m() / 2.0).value(), 0.9);
+    }
+
     // Weight tests
 
     #[test]
@@ -311,6 +617,74 @@ mod tests {
         approx_eq(height2.value(), 1.6256);
     }
 
+    #[test]
+    fn broselow_weight_matches_zone_midpoints() {
+        // yellow zone (85-97.9 cm): 12-14 kg
+        let yellow = estimate_weight_from_length(0.90.height_in_m()).unwrap();
+        approx_eq(yellow.value(), 13.0);
+        // blue zone (110-121.9 cm): 19-23 kg
+        let blue = estimate_weight_from_length(1.15.height_in_m()).unwrap();
+        approx_eq(blue.value(), 21.0);
+        // 2 ft ≈ 61 cm, pink zone: 6-7 kg
+        let pink = estimate_weight_from_length(2.0.height_in_ft()).unwrap();
+        approx_eq(pink.value(), 6.5);
+    }
+
+    #[test]
+    fn broselow_weight_is_none_off_the_tape() {
+        assert!(estimate_weight_from_length(0.40.height_in_m()).is_none());
+        assert!(estimate_weight_from_length(1.50.height_in_m()).is_none());
+    }
+
+    // Temperature tests
+
+    #[test]
+    fn temperature_celsius_to_fahrenheit() {
+        let temp_f: Temperature<Fahrenheit> = Temperature::from(37.0.temp_c());
+        approx_eq(temp_f.value(), 98.6);
+    }
+
+    #[test]
+    fn temperature_round_trip_conversion() {
+        let original = 101.3.temp_f();
+        let as_c: Temperature<Celsius> = Temperature::from(original);
+        let back_to_f: Temperature<Fahrenheit> = Temperature::from(as_c);
+        approx_eq(back_to_f.value(), original.value());
+    }
+
+    #[test]
+    fn temperature_display_format() {
+        let display_string = format!("{}", 38.25.temp_c());
+        assert!(display_string.contains("38.2") || display_string.contains("38.3"));
+        assert!(display_string.contains("°C"));
+    }
+
+    #[test]
+    fn temperature_ranges_flag_fever_and_hypothermia() {
+        assert_eq!(34.0.temp_c().range(), ResultRange::CriticalLow);
+        assert_eq!(35.5.temp_c().range(), ResultRange::Low);
+        assert_eq!(37.0.temp_c().range(), ResultRange::Normal);
+        assert_eq!(38.5.temp_c().range(), ResultRange::High);
+        assert_eq!(41.0.temp_c().range(), ResultRange::CriticalHigh);
+    }
+
+    #[test]
+    fn fahrenheit_classifies_like_celsius() {
+        assert_eq!(98.6.temp_f().range(), ResultRange::Normal);
+        assert_eq!(101.3.temp_f().range(), ResultRange::High);
+        assert_eq!(93.0.temp_f().range(), ResultRange::CriticalLow);
+        assert_eq!(98.6.temp_f().units(), "°F");
+    }
+
+    // Respiratory rate tests
+
+    #[test]
+    fn respiratory_rate_construction_and_display() {
+        let rr = 18.0.breaths_per_min();
+        approx_eq(rr.value(), 18.0);
+        assert_eq!(format!("{}", rr), "RR (18 /min)");
+    }
+
     // BMI tests
 
     #[test]
@@ -357,6 +731,32 @@ mod tests {
         assert!(bmi.value() >= 30.0);
     }
 
+    #[test]
+    fn bmi_classification_at_each_boundary() {
+        assert_eq!(18.4.to_bmi().classify(), BmiClass::Underweight);
+        assert_eq!(18.5.to_bmi().classify(), BmiClass::Normal);
+        assert_eq!(24.9.to_bmi().classify(), BmiClass::Normal);
+        assert_eq!(25.0.to_bmi().classify(), BmiClass::Overweight);
+        assert_eq!(30.0.to_bmi().classify(), BmiClass::ObeseClass1);
+        assert_eq!(35.0.to_bmi().classify(), BmiClass::ObeseClass2);
+        assert_eq!(40.0.to_bmi().classify(), BmiClass::ObeseClass3);
+    }
+
+    #[test]
+    fn bmi_numeric_range() {
+        assert_eq!(17.0.to_bmi().range(), ResultRange::Low);
+        assert_eq!(22.0.to_bmi().range(), ResultRange::Normal);
+        assert_eq!(32.0.to_bmi().range(), ResultRange::High);
+        assert_eq!(42.0.to_bmi().range(), ResultRange::CriticalHigh);
+    }
+
+    // BSA tests
+
+    #[test]
+    fn bsa_display_format() {
+        assert_eq!(format!("{}", 1.234.to_bsa()), "BSA (1.23 m²)");
+    }
+
     // Conversion constant tests
 
     #[test]
@@ -371,4 +771,19 @@ mod tests {
         approx_eq(LB_TO_KG, 0.45359237);
         approx_eq(FT_TO_M, 0.3048);
     }
+
+    #[test]
+    fn weight_and_height_compare_within_a_unit() {
+        assert!(70.0.weight_kg() < 82.5.weight_kg());
+        assert!(1.80.height_in_m() > 1.65.height_in_m());
+    }
+
+    #[test]
+    fn vitals_parse_from_strings() {
+        let wt: Weight<Lb> = "154 lb".parse().unwrap();
+        assert_eq!(wt.value(), 154.0);
+        let temp: Temperature<Celsius> = "38.4°c".parse().unwrap();
+        assert_eq!(temp.value(), 38.4);
+        assert!("38.4 °F".parse::<Temperature<Celsius>>().is_err());
+    }
 }
@@ -0,0 +1,131 @@
+//! Blood pressure module
+//!
+//! Systolic and diastolic pressures are always reported together, in mmHg, so
+//! `BloodPressure` holds the pair rather than following the single-value pattern.
+
+use crate::units::{MmHg, Unit};
+
+/// A blood pressure reading, in mmHg.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BloodPressure {
+    systolic: f64,
+    diastolic: f64,
+}
+impl BloodPressure {
+    pub fn new(systolic: f64, diastolic: f64) -> Self {
+        Self {
+            systolic,
+            diastolic,
+        }
+    }
+    pub fn systolic(&self) -> f64 {
+        self.systolic
+    }
+    pub fn diastolic(&self) -> f64 {
+        self.diastolic
+    }
+    /// Mean arterial pressure: (SBP + 2 × DBP) / 3
+    pub fn map(&self) -> f64 {
+        (self.systolic + 2.0 * self.diastolic) / 3.0
+    }
+    /// Pulse pressure: SBP - DBP
+    pub fn pulse_pressure(&self) -> f64 {
+        self.systolic - self.diastolic
+    }
+    /// ACC/AHA (2017) hypertension stage. When systolic and diastolic fall in
+    /// different stages, the higher one applies.
+    pub fn stage(&self) -> BpStage {
+        match (self.systolic, self.diastolic) {
+            (sbp, dbp) if sbp >= 140.0 || dbp >= 90.0 => BpStage::Stage2,
+            (sbp, dbp) if sbp >= 130.0 || dbp >= 80.0 => BpStage::Stage1,
+            (sbp, _) if sbp >= 120.0 => BpStage::Elevated,
+            _ => BpStage::Normal,
+        }
+    }
+}
+impl std::fmt::Display for BloodPressure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "BP ({:.0}/{:.0} {})",
+            self.systolic,
+            self.diastolic,
+            MmHg::ABBR
+        )
+    }
+}
+
+/// ACC/AHA blood pressure categories.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BpStage {
+    /// < 120 / < 80
+    Normal,
+    /// 120-129 / < 80
+    Elevated,
+    /// 130-139 / 80-89
+    Stage1,
+    /// ≥ 140 / ≥ 90
+    Stage2,
+}
+
+/// Shock index: heart rate / systolic blood pressure.
+pub fn shock_index(heart_rate: f64, bp: BloodPressure) -> f64 {
+    heart_rate / bp.systolic()
+}
+
+/// Interpretation of a shock index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShockIndexClass {
+    /// ≤ 0.9 (typically 0.5-0.7 in healthy adults)
+    Normal,
+    /// > 0.9: concerning for occult shock or need for transfusion
+    Concerning,
+}
+
+/// Classify a shock index, flagging values above 0.9.
+pub fn classify_shock_index(shock_index: f64) -> ShockIndexClass {
+    if shock_index > 0.9 {
+        ShockIndexClass::Concerning
+    } else {
+        ShockIndexClass::Normal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_of_120_over_80_is_about_93() {
+        let bp = BloodPressure::new(120.0, 80.0);
+        assert!((bp.map() - 93.333).abs() < 0.001);
+        assert_eq!(bp.pulse_pressure(), 40.0);
+    }
+
+    #[test]
+    fn bp_stages_use_the_higher_category() {
+        assert_eq!(BloodPressure::new(115.0, 75.0).stage(), BpStage::Normal);
+        assert_eq!(BloodPressure::new(125.0, 78.0).stage(), BpStage::Elevated);
+        assert_eq!(BloodPressure::new(125.0, 85.0).stage(), BpStage::Stage1);
+        assert_eq!(BloodPressure::new(135.0, 70.0).stage(), BpStage::Stage1);
+        assert_eq!(BloodPressure::new(128.0, 92.0).stage(), BpStage::Stage2);
+    }
+
+    #[test]
+    fn shock_index_above_0_9_is_concerning() {
+        let normal = shock_index(70.0, BloodPressure::new(120.0, 80.0));
+        assert_eq!(classify_shock_index(normal), ShockIndexClass::Normal);
+
+        let shocky = shock_index(120.0, BloodPressure::new(90.0, 50.0));
+        assert!((shocky - 1.333).abs() < 0.001);
+        assert_eq!(classify_shock_index(shocky), ShockIndexClass::Concerning);
+    }
+
+    #[test]
+    fn bp_display_format() {
+        assert_eq!(
+            format!("{}", BloodPressure::new(120.0, 80.0)),
+            "BP (120/80 mmHg)"
+        );
+    }
+}
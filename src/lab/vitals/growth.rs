@@ -0,0 +1,293 @@
+//! Growth module
+//!
+//! Weight-for-age and length-for-age z-scores for children under two, from the
+//! WHO Child Growth Standards. Each standard is summarized by Box-Cox LMS
+//! parameters (skew L, median M, coefficient of variation S) at each age, and
+//!
+//!   z = ((X / M)^L - 1) / (L × S)        (or ln(X / M) / S when L = 0).
+//!
+//! The monthly LMS rows from birth to 24 months are embedded; ages between two
+//! months are linearly interpolated between them. Length is the recumbent
+//! measurement WHO uses under two; the standing-height standard from 24 months
+//! on, which runs about 0.7 cm shorter, isn't included. WHO's restricted
+//! extrapolation of weight z-scores beyond ±3 isn't applied.
+
+use crate::{
+    history::{SexAtBirth, Years},
+    lab::vitals::{Height, Weight},
+    units::vitals::{HeightUnit, WeightUnit},
+};
+
+/// Oldest age covered by the embedded standards, in months.
+pub const WHO_GROWTH_MAX_MONTHS: f64 = 24.0;
+
+/// One LMS row: L, M and S. Tables are indexed by age in completed months.
+type Lms = (f64, f64, f64);
+
+/// Weight-for-age, boys, by completed month (kg).
+const WEIGHT_FOR_AGE_BOYS: [Lms; 25] = [
+    (0.3487, 3.3464, 0.14602),
+    (0.2297, 4.4709, 0.13395),
+    (0.1970, 5.5675, 0.12385),
+    (0.1738, 6.3762, 0.11727),
+    (0.1553, 7.0023, 0.11316),
+    (0.1395, 7.5105, 0.11080),
+    (0.1257, 7.9340, 0.10958),
+    (0.1134, 8.2970, 0.10902),
+    (0.1021, 8.6151, 0.10882),
+    (0.0917, 8.9014, 0.10881),
+    (0.0820, 9.1649, 0.10891),
+    (0.0730, 9.4122, 0.10906),
+    (0.0644, 9.6479, 0.10925),
+    (0.0563, 9.8749, 0.10949),
+    (0.0487, 10.0953, 0.10976),
+    (0.0413, 10.3108, 0.11007),
+    (0.0343, 10.5228, 0.11041),
+    (0.0275, 10.7319, 0.11079),
+    (0.0211, 10.9385, 0.11119),
+    (0.0148, 11.1430, 0.11164),
+    (0.0087, 11.3462, 0.11211),
+    (0.0029, 11.5486, 0.11261),
+    (-0.0028, 11.7504, 0.11314),
+    (-0.0083, 11.9514, 0.11369),
+    (-0.0137, 12.1515, 0.11426),
+];
+
+/// Weight-for-age, girls, by completed month (kg).
+const WEIGHT_FOR_AGE_GIRLS: [Lms; 25] = [
+    (0.3809, 3.2322, 0.14171),
+    (0.1714, 4.1873, 0.13724),
+    (0.0962, 5.1282, 0.13000),
+    (0.0402, 5.8458, 0.12619),
+    (-0.0050, 6.4237, 0.12402),
+    (-0.0430, 6.8985, 0.12274),
+    (-0.0756, 7.2970, 0.12204),
+    (-0.1039, 7.6422, 0.12178),
+    (-0.1288, 7.9487, 0.12181),
+    (-0.1507, 8.2254, 0.12199),
+    (-0.1700, 8.4800, 0.12223),
+    (-0.1872, 8.7192, 0.12247),
+    (-0.2024, 8.9481, 0.12268),
+    (-0.2158, 9.1699, 0.12283),
+    (-0.2278, 9.3870, 0.12294),
+    (-0.2384, 9.6008, 0.12299),
+    (-0.2478, 9.8124, 0.12303),
+    (-0.2562, 10.0226, 0.12306),
+    (-0.2637, 10.2315, 0.12309),
+    (-0.2703, 10.4393, 0.12315),
+    (-0.2762, 10.6464, 0.12323),
+    (-0.2815, 10.8534, 0.12335),
+    (-0.2862, 11.0608, 0.12350),
+    (-0.2903, 11.2688, 0.12369),
+    (-0.2941, 11.4775, 0.12390),
+];
+
+/// Recumbent length-for-age, boys, by completed month (cm).
+const LENGTH_FOR_AGE_BOYS: [Lms; 25] = [
+    (1.0, 49.8842, 0.03795),
+    (1.0, 54.7244, 0.03557),
+    (1.0, 58.4249, 0.03424),
+    (1.0, 61.4292, 0.03328),
+    (1.0, 63.8860, 0.03257),
+    (1.0, 65.9026, 0.03204),
+    (1.0, 67.6236, 0.03165),
+    (1.0, 69.1645, 0.03139),
+    (1.0, 70.5994, 0.03124),
+    (1.0, 71.9687, 0.03117),
+    (1.0, 73.2812, 0.03118),
+    (1.0, 74.5388, 0.03125),
+    (1.0, 75.7488, 0.03137),
+    (1.0, 76.9186, 0.03154),
+    (1.0, 78.0497, 0.03174),
+    (1.0, 79.1458, 0.03197),
+    (1.0, 80.2113, 0.03222),
+    (1.0, 81.2487, 0.03250),
+    (1.0, 82.2587, 0.03279),
+    (1.0, 83.2418, 0.03310),
+    (1.0, 84.1996, 0.03342),
+    (1.0, 85.1348, 0.03376),
+    (1.0, 86.0477, 0.03410),
+    (1.0, 86.9410, 0.03445),
+    (1.0, 87.8161, 0.03479),
+];
+
+/// Recumbent length-for-age, girls, by completed month (cm).
+const LENGTH_FOR_AGE_GIRLS: [Lms; 25] = [
+    (1.0, 49.1477, 0.03790),
+    (1.0, 53.6872, 0.03640),
+    (1.0, 57.0673, 0.03568),
+    (1.0, 59.8029, 0.03520),
+    (1.0, 62.0899, 0.03486),
+    (1.0, 64.0301, 0.03463),
+    (1.0, 65.7311, 0.03448),
+    (1.0, 67.2873, 0.03441),
+    (1.0, 68.7498, 0.03440),
+    (1.0, 70.1435, 0.03444),
+    (1.0, 71.4818, 0.03452),
+    (1.0, 72.7710, 0.03464),
+    (1.0, 74.0150, 0.03479),
+    (1.0, 75.2176, 0.03496),
+    (1.0, 76.3817, 0.03514),
+    (1.0, 77.5099, 0.03534),
+    (1.0, 78.6055, 0.03555),
+    (1.0, 79.6710, 0.03576),
+    (1.0, 80.7079, 0.03598),
+    (1.0, 81.7182, 0.03620),
+    (1.0, 82.7036, 0.03643),
+    (1.0, 83.6654, 0.03666),
+    (1.0, 84.6040, 0.03688),
+    (1.0, 85.5202, 0.03711),
+    (1.0, 86.4153, 0.03734),
+];
+
+/// How far below the median a growth z-score lies, by the WHO cutoffs. For
+/// height-for-age this grades stunting; for weight-for-age, underweight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GrowthStatus {
+    /// z < -3
+    Severe,
+    /// -3 ≤ z < -2
+    Moderate,
+    /// z ≥ -2
+    Normal,
+}
+
+/// A growth z-score against the WHO standard.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GrowthZScore {
+    z: f64,
+}
+impl GrowthZScore {
+    pub fn z(&self) -> f64 {
+        self.z
+    }
+    pub fn status(&self) -> GrowthStatus {
+        match self.z {
+            z if z < -3.0 => GrowthStatus::Severe,
+            z if z < -2.0 => GrowthStatus::Moderate,
+            _ => GrowthStatus::Normal,
+        }
+    }
+}
+
+/// Weight-for-age z-score for a child from birth to 24 months, or `None` outside
+/// that range. A status other than `Normal` means the child is underweight.
+pub fn weight_for_age_zscore<W: WeightUnit>(
+    weight: Weight<W>,
+    age: Years,
+    sex: SexAtBirth,
+) -> Option<GrowthZScore> {
+    let table = match sex {
+        SexAtBirth::Female => &WEIGHT_FOR_AGE_GIRLS,
+        SexAtBirth::Male => &WEIGHT_FOR_AGE_BOYS,
+    };
+    lms_zscore(table, W::to_kg(weight.value()), age)
+}
+
+/// Length-for-age z-score for a child from birth to 24 months, measured lying
+/// down, or `None` outside that range. A status other than `Normal` means the
+/// child is stunted.
+pub fn length_for_age_zscore<H: HeightUnit>(
+    length: Height<H>,
+    age: Years,
+    sex: SexAtBirth,
+) -> Option<GrowthZScore> {
+    let table = match sex {
+        SexAtBirth::Female => &LENGTH_FOR_AGE_GIRLS,
+        SexAtBirth::Male => &LENGTH_FOR_AGE_BOYS,
+    };
+    lms_zscore(table, H::to_m(length.value()) * 100.0, age)
+}
+
+/// Interpolate the LMS row for `age` and compute the z-score of `measurement`.
+fn lms_zscore(table: &[Lms], measurement: f64, age: Years) -> Option<GrowthZScore> {
+    let months = age.0 * 12.0;
+    if !(0.0..=WHO_GROWTH_MAX_MONTHS).contains(&months) {
+        return None;
+    }
+    // months is in range, so both indices are within the 25-row tables
+    let lower = months.floor() as usize;
+    let (l0, m0, s0) = table[lower];
+    let (l1, m1, s1) = table[(lower + 1).min(table.len() - 1)];
+    let t = months - lower as f64;
+    let (l, m, s) = (l0 + t * (l1 - l0), m0 + t * (m1 - m0), s0 + t * (s1 - s0));
+
+    let z = if l.abs() < 1e-9 {
+        (measurement / m).ln() / s
+    } else {
+        ((measurement / m).powf(l) - 1.0) / (l * s)
+    };
+    Some(GrowthZScore { z })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lab::vitals::{HeightExt, WeightExt};
+
+    fn approx_eq(a: f64, b: f64, tol: f64) {
+        assert!((a - b).abs() < tol, "{} !~= {}", a, b);
+    }
+
+    #[test]
+    fn median_weight_scores_zero() {
+        // WHO boys at 3 months: median 6.4 kg
+        let z = weight_for_age_zscore(6.3762.weight_kg(), Years(0.25), SexAtBirth::Male).unwrap();
+        approx_eq(z.z(), 0.0, 1e-9);
+        assert_eq!(z.status(), GrowthStatus::Normal);
+    }
+
+    #[test]
+    fn underweight_infant_between_table_rows() {
+        // WHO boys at 3 months: -2 SD 5.0 kg, -3 SD 4.4 kg
+        let z = weight_for_age_zscore(5.0.weight_kg(), Years(0.25), SexAtBirth::Male).unwrap();
+        approx_eq(z.z(), -2.0, 0.05);
+        let severe = weight_for_age_zscore(4.3.weight_kg(), Years(0.25), SexAtBirth::Male).unwrap();
+        assert_eq!(severe.status(), GrowthStatus::Severe);
+        // WHO girls at 9 months: -2 SD 6.5 kg
+        let z = weight_for_age_zscore(6.5.weight_kg(), Years(0.75), SexAtBirth::Female).unwrap();
+        approx_eq(z.z(), -2.0, 0.05);
+    }
+
+    #[test]
+    fn stunted_infant_is_flagged() {
+        // WHO boys at 3 months: median 61.4 cm, -2 SD 57.3 cm, -3 SD 55.3 cm
+        let median =
+            length_for_age_zscore(0.614292.height_in_m(), Years(0.25), SexAtBirth::Male).unwrap();
+        approx_eq(median.z(), 0.0, 1e-9);
+        let moderate =
+            length_for_age_zscore(0.570.height_in_m(), Years(0.25), SexAtBirth::Male).unwrap();
+        assert_eq!(moderate.status(), GrowthStatus::Moderate);
+        let severe =
+            length_for_age_zscore(0.550.height_in_m(), Years(0.25), SexAtBirth::Male).unwrap();
+        assert_eq!(severe.status(), GrowthStatus::Severe);
+    }
+
+    #[test]
+    fn length_at_eighteen_months_matches_who() {
+        // WHO girls at 18 months: median 80.7 cm, -2 SD 74.9 cm
+        let median =
+            length_for_age_zscore(0.807.height_in_m(), Years(1.5), SexAtBirth::Female).unwrap();
+        approx_eq(median.z(), 0.0, 0.01);
+        let low =
+            length_for_age_zscore(0.749.height_in_m(), Years(1.5), SexAtBirth::Female).unwrap();
+        approx_eq(low.z(), -2.0, 0.01);
+    }
+
+    #[test]
+    fn interpolates_within_a_month() {
+        // Halfway through the fourth month sits halfway between the 3- and
+        // 4-month medians for boys
+        let median = (61.4292 + 63.8860) / 2.0 / 100.0;
+        let z = length_for_age_zscore(median.height_in_m(), Years(3.5 / 12.0), SexAtBirth::Male)
+            .unwrap();
+        approx_eq(z.z(), 0.0, 1e-9);
+    }
+
+    #[test]
+    fn ages_outside_the_standard_are_none() {
+        assert!(weight_for_age_zscore(14.0.weight_kg(), Years(2.5), SexAtBirth::Male).is_none());
+        assert!(length_for_age_zscore(0.5.height_in_m(), Years(-0.1), SexAtBirth::Male).is_none());
+        assert!(weight_for_age_zscore(12.0.weight_kg(), Years(2.0), SexAtBirth::Male).is_some());
+    }
+}
@@ -1,5 +1,10 @@
+pub mod batch;
 pub mod calculators;
 pub mod constants;
+pub mod error;
 pub mod history;
 pub mod lab;
+mod ops;
+#[cfg(feature = "serde")]
+mod serialize;
 pub mod units;
@@ -1,7 +1,7 @@
 use medicalc::calculators::bmi;
 use medicalc::calculators::cha2ds2_va::Cha2Ds2VA;
 use medicalc::calculators::cha2ds2_vasc::ChadsVasc;
-use medicalc::history::{Gender, Years};
+use medicalc::history::{SexAtBirth, Years};
 use medicalc::lab::vitals::{Height, Weight, WeightExt};
 use medicalc::units::{Foot, Kg, Lb, Meter};
 
@@ -10,6 +10,7 @@ fn main() {
     println!("Not part of the library.");
 
     let input_wt = 201.8.weight_lb();
+    #[allow(clippy::useless_conversion)]
     let weight_lb: Weight<Lb> = Weight::from(input_wt); /* can convert to itself via core From impl */
     let weight_kg: Weight<Kg> = Weight::from(weight_lb);
     println!("{weight_lb} → {weight_kg}");
@@ -26,7 +27,7 @@ fn main() {
     println!("{}", bmi(height_m, weight_lb));
 
     println!("\nCHA₂DS₂-VA(Sc) tests:\n");
-    let chads_vasc = ChadsVasc::new(Years(68.0), Gender::Female)
+    let chads_vasc = ChadsVasc::new(Years(68.0), SexAtBirth::Female)
         .has_diabetes()
         .has_htn()
         .calculate();
@@ -0,0 +1,59 @@
+//! Arithmetic module
+//!
+//! `Add`/`Sub` between measurements of the same type and unit, and `Mul<f64>`/
+//! `Div<f64>` for scaling, so values don't need unwrapping to f64 first. Adding
+//! measurements in different units doesn't compile; convert one side first:
+//!
+//! ```compile_fail
+//! use medicalc::lab::vitals::WeightExt;
+//!
+//! let total = 70.0.weight_kg() + 10.0.weight_lb();
+//! ```
+//!
+//! Temperatures are deliberately left out: °F has an offset zero, so adding two
+//! readings isn't meaningful.
+
+/// Implements the arithmetic operators for a `Type<U: Unit>` measurement with a
+/// `value` field and the named `PhantomData` field. Invoke it in the type's own
+/// module so the private fields are reachable.
+macro_rules! impl_measurement_ops {
+    ($ty:ident, $phantom:ident) => {
+        impl<U: $crate::units::Unit> std::ops::Add for $ty<U> {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                $ty {
+                    value: self.value + rhs.value,
+                    $phantom: std::marker::PhantomData,
+                }
+            }
+        }
+        impl<U: $crate::units::Unit> std::ops::Sub for $ty<U> {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self {
+                $ty {
+                    value: self.value - rhs.value,
+                    $phantom: std::marker::PhantomData,
+                }
+            }
+        }
+        impl<U: $crate::units::Unit> std::ops::Mul<f64> for $ty<U> {
+            type Output = Self;
+            fn mul(self, factor: f64) -> Self {
+                $ty {
+                    value: self.value * factor,
+                    $phantom: std::marker::PhantomData,
+                }
+            }
+        }
+        impl<U: $crate::units::Unit> std::ops::Div<f64> for $ty<U> {
+            type Output = Self;
+            fn div(self, divisor: f64) -> Self {
+                $ty {
+                    value: self.value / divisor,
+                    $phantom: std::marker::PhantomData,
+                }
+            }
+        }
+    };
+}
+pub(crate) use impl_measurement_ops;
@@ -0,0 +1,146 @@
+//! Serialization module
+//!
+//! Serde support, enabled with the `serde` feature. Measurements serialize as their
+//! value plus the unit abbreviation, e.g. `{"value": 100.0, "unit": "mg/dL"}`. The
+//! unit is implied by the type parameter, so on deserialize it's only checked
+//! against the target type's `ABBR` and a mismatch is an error rather than a silent
+//! conversion.
+
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::units::Unit;
+
+#[derive(Serialize)]
+struct MeasurementRef<'a> {
+    value: f64,
+    unit: &'a str,
+}
+
+#[derive(Deserialize)]
+struct MeasurementOwned {
+    value: f64,
+    unit: String,
+}
+
+/// Serializes a measurement value along with its unit abbreviation.
+pub(crate) fn serialize_measurement<U: Unit, S: Serializer>(
+    value: f64,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    MeasurementRef {
+        value,
+        unit: U::ABBR,
+    }
+    .serialize(serializer)
+}
+
+/// Deserializes a measurement value, checking that its unit matches `U`.
+pub(crate) fn deserialize_measurement<'de, U: Unit, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<f64, D::Error> {
+    let measurement = MeasurementOwned::deserialize(deserializer)?;
+    if measurement.unit != U::ABBR {
+        return Err(D::Error::custom(format!(
+            "expected unit \"{}\", found \"{}\"",
+            U::ABBR,
+            measurement.unit
+        )));
+    }
+    Ok(measurement.value)
+}
+
+/// Implements `Serialize` and `Deserialize` for a `Type<U: Unit>` measurement with
+/// a `value` field and the named `PhantomData` field. Invoke it in the type's own
+/// module so the private fields are reachable.
+macro_rules! impl_serde_measurement {
+    ($ty:ident, $phantom:ident) => {
+        impl<U: $crate::units::Unit> serde::Serialize for $ty<U> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                $crate::serialize::serialize_measurement::<U, S>(self.value, serializer)
+            }
+        }
+        impl<'de, U: $crate::units::Unit> serde::Deserialize<'de> for $ty<U> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = $crate::serialize::deserialize_measurement::<U, D>(deserializer)?;
+                Ok($ty {
+                    value,
+                    $phantom: std::marker::PhantomData,
+                })
+            }
+        }
+    };
+}
+pub(crate) use impl_serde_measurement;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        history::{SexAtBirth, Years},
+        lab::{
+            blood::{
+                creatinine::{Creatinine, CreatinineExt},
+                glucose::{Glucose, SerumGlucoseExt},
+            },
+            vitals::{Weight, WeightExt},
+            RangeThreshold, ResultRange,
+        },
+        units::{Kg, MgdL, MmolL, UmolL},
+    };
+
+    #[test]
+    fn measurement_serializes_with_unit() {
+        let json = serde_json::to_string(&100.0.glu_serum_mg_dl()).unwrap();
+        assert_eq!(json, r#"{"value":100.0,"unit":"mg/dL"}"#);
+    }
+
+    #[test]
+    fn measurements_round_trip() {
+        let glu = 5.5.glu_serum_mmol_l();
+        let back: Glucose<MmolL> =
+            serde_json::from_str(&serde_json::to_string(&glu).unwrap()).unwrap();
+        assert_eq!(glu, back);
+
+        let scr = 97.2.cr_serum_umol_l();
+        let back: Creatinine<UmolL> =
+            serde_json::from_str(&serde_json::to_string(&scr).unwrap()).unwrap();
+        assert_eq!(scr, back);
+
+        let wt = 72.5.weight_kg();
+        let back: Weight<Kg> = serde_json::from_str(&serde_json::to_string(&wt).unwrap()).unwrap();
+        assert_eq!(wt, back);
+    }
+
+    #[test]
+    fn deserialize_rejects_mismatched_unit() {
+        let result: Result<Glucose<MgdL>, _> =
+            serde_json::from_str(r#"{"value":5.5,"unit":"mmol/L"}"#);
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("expected unit \"mg/dL\""), "{err}");
+    }
+
+    #[test]
+    fn supporting_types_round_trip() {
+        let age: Years =
+            serde_json::from_str(&serde_json::to_string(&Years(64.0)).unwrap()).unwrap();
+        assert_eq!(age, Years(64.0));
+
+        let sex: SexAtBirth =
+            serde_json::from_str(&serde_json::to_string(&SexAtBirth::Female).unwrap()).unwrap();
+        assert_eq!(sex, SexAtBirth::Female);
+
+        let range: ResultRange =
+            serde_json::from_str(&serde_json::to_string(&ResultRange::CriticalHigh).unwrap())
+                .unwrap();
+        assert_eq!(range, ResultRange::CriticalHigh);
+
+        let thresholds = RangeThreshold {
+            crit_low: 1.0,
+            low_norm: 2.0,
+            norm_hi: 3.0,
+            hi_crit: 4.0,
+        };
+        let back: RangeThreshold =
+            serde_json::from_str(&serde_json::to_string(&thresholds).unwrap()).unwrap();
+        assert_eq!(back, thresholds);
+    }
+}
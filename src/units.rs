@@ -8,85 +8,334 @@ pub trait Unit {
     const ABBR: &'static str;
 }
 
+/// Most decimal places `format_value` will add before falling back on scientific
+/// notation.
+const MAX_SMALL_VALUE_DECIMALS: usize = 6;
+
+/// Format a measurement value with `decimals` places, unless that would round a
+/// nonzero value to zero (e.g. a 0.04 µg/dL hormone at one decimal). Such values get
+/// enough decimals for two significant figures, or scientific notation if that
+/// would take more than six places.
+pub fn format_value(value: f64, decimals: usize) -> String {
+    let smallest_shown = 0.5 * 10f64.powi(-(decimals as i32));
+    if value == 0.0 || !value.is_finite() || value.abs() >= smallest_shown {
+        return format!("{value:.decimals$}");
+    }
+    let places = (-value.abs().log10()).ceil() as usize + 1;
+    if places <= MAX_SMALL_VALUE_DECIMALS {
+        format!("{value:.places$}")
+    } else {
+        format!("{value:.1e}")
+    }
+}
+
+pub mod albumin;
+pub mod analyte;
+pub mod bicarbonate;
 pub mod bilirubin;
+pub mod bun;
+pub mod calcium;
+pub mod chloride;
+pub mod cholesterol;
+pub mod cortisol;
 pub mod creatinine;
+pub mod electrolyte;
+pub mod enzyme;
 pub mod glucose;
+pub mod hemoglobin;
+pub mod lactate;
+pub mod magnesium;
+pub mod phosphate;
+pub mod potassium;
 pub mod sodium;
+pub mod troponin;
 pub mod vitals;
 
 /// INR "Units" (actually unitless)
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 pub struct InrUnit;
 impl Unit for InrUnit {
     const ABBR: &'static str = "INR";
 }
 
 /// GFR Units (mL/min/1.73m^2)
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 pub struct GfrUnit;
 impl Unit for GfrUnit {
     const ABBR: &'static str = "mL/min/1.73m²";
 }
 
+/// Milliliters per minute (mL/min), for clearances not indexed to body surface area
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct MlMin;
+impl Unit for MlMin {
+    const ABBR: &'static str = "mL/min";
+}
+
+/// Milliliters per hour (mL/hr), for infusion rates
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct MlHr;
+impl Unit for MlHr {
+    const ABBR: &'static str = "mL/hr";
+}
+
 /// Milliequivalents per liter (mEq/L).
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 pub struct MeqL;
 impl Unit for MeqL {
     const ABBR: &'static str = "mEq/L";
 }
 
 /// Milligrams per deciliter (mg/dL).
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 pub struct MgdL;
 impl Unit for MgdL {
     const ABBR: &'static str = "mg/dL";
 }
 
+/// Grams per deciliter (g/dL).
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct GdL;
+impl Unit for GdL {
+    const ABBR: &'static str = "g/dL";
+}
+
+/// Grams per liter (g/L).
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct GL;
+impl Unit for GL {
+    const ABBR: &'static str = "g/L";
+}
+
+/// Milligrams per liter (mg/L).
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct MgL;
+impl Unit for MgL {
+    const ABBR: &'static str = "mg/L";
+}
+
 /// Millimoles per liter (mmol/L).
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 pub struct MmolL;
 impl Unit for MmolL {
     const ABBR: &'static str = "mmol/L";
 }
 
+/// Nanomoles per liter (nmol/L)
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct NmolL;
+impl Unit for NmolL {
+    const ABBR: &'static str = "nmol/L";
+}
+
+/// Micrograms per deciliter (µg/dL), e.g. cortisol.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct UgdL;
+impl Unit for UgdL {
+    const ABBR: &'static str = "µg/dL";
+}
+
+/// Nanograms per deciliter (ng/dL), e.g. testosterone.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct NgdL;
+impl Unit for NgdL {
+    const ABBR: &'static str = "ng/dL";
+}
+
+/// Nanograms per liter (ng/L), e.g. high-sensitivity troponin.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct NgL;
+impl Unit for NgL {
+    const ABBR: &'static str = "ng/L";
+}
+
+/// Nanograms per milliliter (ng/mL), e.g. conventional troponin.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct NgmL;
+impl Unit for NgmL {
+    const ABBR: &'static str = "ng/mL";
+}
+
+/// Picograms per milliliter (pg/mL), e.g. BNP and NT-proBNP.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct PgmL;
+impl Unit for PgmL {
+    const ABBR: &'static str = "pg/mL";
+}
+
 /// Micromoles per liter (µmol/L)
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 pub struct UmolL;
 impl Unit for UmolL {
     const ABBR: &'static str = "µmol/L";
 }
 
+/// Units per liter (U/L), for enzyme activity
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct UL;
+impl Unit for UL {
+    const ABBR: &'static str = "U/L";
+}
+
+/// Microkatals per liter (µkat/L), the SI unit of enzyme activity
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct UkatL;
+impl Unit for UkatL {
+    const ABBR: &'static str = "µkat/L";
+}
+
 /// Kilograms
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 pub struct Kg;
 impl Unit for Kg {
     const ABBR: &'static str = "kg";
 }
 
 /// Pounds
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 pub struct Lb;
 impl Unit for Lb {
     const ABBR: &'static str = "lb";
 }
 
 /// Meters
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 pub struct Meter;
 impl Unit for Meter {
     const ABBR: &'static str = "m";
 }
 
 /// Feet
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 pub struct Foot;
 impl Unit for Foot {
     const ABBR: &'static str = "ft";
 }
 
 /// Kilograms per meter squared (for BMI)
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 pub struct KgM2;
 impl Unit for KgM2 {
     const ABBR: &'static str = "kg/m²";
 }
+
+/// Square meters (for body surface area)
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct M2;
+impl Unit for M2 {
+    const ABBR: &'static str = "m²";
+}
+
+/// Millimeters of mercury (blood pressure, partial pressures)
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct MmHg;
+impl Unit for MmHg {
+    const ABBR: &'static str = "mmHg";
+}
+
+/// Degrees Celsius
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Celsius;
+impl Unit for Celsius {
+    const ABBR: &'static str = "°C";
+}
+
+/// Degrees Fahrenheit
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Fahrenheit;
+impl Unit for Fahrenheit {
+    const ABBR: &'static str = "°F";
+}
+
+/// Events per minute (respiratory rate, heart rate)
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct PerMinute;
+impl Unit for PerMinute {
+    const ABBR: &'static str = "/min";
+}
+
+/// Milliosmoles per kilogram of water (osmolality)
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct MosmKg;
+impl Unit for MosmKg {
+    const ABBR: &'static str = "mOsm/kg";
+}
+
+/// Cells per nanoliter, i.e. ×10⁹/L (equivalently K/µL), for blood cell counts
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct PerNanoLiter;
+impl Unit for PerNanoLiter {
+    const ABBR: &'static str = "×10⁹/L";
+}
+
+/// Cells per liter, for absolute counts written out in full (e.g. 7.5e9 /L)
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct PerLiter;
+impl Unit for PerLiter {
+    const ABBR: &'static str = "/L";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_value_keeps_default_precision_for_normal_values() {
+        assert_eq!(format_value(1.25, 1), "1.2");
+        assert_eq!(format_value(140.0, 0), "140");
+        assert_eq!(format_value(0.0, 1), "0.0");
+    }
+
+    #[test]
+    fn format_value_does_not_round_small_values_to_zero() {
+        assert_eq!(format_value(0.004, 1), "0.0040");
+        assert_eq!(format_value(0.04, 1), "0.040");
+        assert_eq!(format_value(0.3, 0), "0.30");
+        assert_eq!(format_value(-0.004, 1), "-0.0040");
+        assert_eq!(format_value(0.000_000_4, 1), "4.0e-7");
+    }
+
+    /// Every unit marker, listed by hand since associated consts can't be iterated.
+    /// Add new units here as they're defined.
+    const ALL_ABBRS: &[(&str, &str)] = &[
+        ("InrUnit", InrUnit::ABBR),
+        ("GfrUnit", GfrUnit::ABBR),
+        ("MlMin", MlMin::ABBR),
+        ("MlHr", MlHr::ABBR),
+        ("MeqL", MeqL::ABBR),
+        ("MgdL", MgdL::ABBR),
+        ("GdL", GdL::ABBR),
+        ("GL", GL::ABBR),
+        ("MgL", MgL::ABBR),
+        ("MmolL", MmolL::ABBR),
+        ("UmolL", UmolL::ABBR),
+        ("NmolL", NmolL::ABBR),
+        ("UgdL", UgdL::ABBR),
+        ("NgdL", NgdL::ABBR),
+        ("NgL", NgL::ABBR),
+        ("NgmL", NgmL::ABBR),
+        ("PgmL", PgmL::ABBR),
+        ("UL", UL::ABBR),
+        ("UkatL", UkatL::ABBR),
+        ("Kg", Kg::ABBR),
+        ("Lb", Lb::ABBR),
+        ("Meter", Meter::ABBR),
+        ("Foot", Foot::ABBR),
+        ("KgM2", KgM2::ABBR),
+        ("M2", M2::ABBR),
+        ("MmHg", MmHg::ABBR),
+        ("Celsius", Celsius::ABBR),
+        ("Fahrenheit", Fahrenheit::ABBR),
+        ("PerMinute", PerMinute::ABBR),
+        ("MosmKg", MosmKg::ABBR),
+        ("PerNanoLiter", PerNanoLiter::ABBR),
+        ("PerLiter", PerLiter::ABBR),
+    ];
+
+    #[test]
+    fn every_unit_has_a_non_empty_abbreviation() {
+        for (name, abbr) in ALL_ABBRS {
+            assert!(!abbr.trim().is_empty(), "{name} has an empty ABBR");
+        }
+    }
+}
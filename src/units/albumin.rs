@@ -0,0 +1,48 @@
+use super::{GdL, Unit, GL};
+use crate::constants::{ALB_GDL_TO_GL, ALB_GL_TO_GDL};
+
+/// Describes methods for converting serum albumin values to and from g/L.
+///
+/// Conversion factor: 1 g/dL = 10 g/L
+pub trait AlbuminUnit: Unit {
+    fn to_g_l(value: f64) -> f64;
+    fn from_g_l(value: f64) -> f64;
+}
+impl AlbuminUnit for GdL {
+    fn to_g_l(value: f64) -> f64 {
+        value * ALB_GDL_TO_GL
+    }
+    fn from_g_l(value: f64) -> f64 {
+        value * ALB_GL_TO_GDL
+    }
+}
+impl AlbuminUnit for GL {
+    fn to_g_l(value: f64) -> f64 {
+        value
+    }
+    fn from_g_l(value: f64) -> f64 {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gdl_conversions_scale_by_constant() {
+        let value = 3.8;
+        let as_si = GdL::to_g_l(value);
+        assert!((as_si - value * ALB_GDL_TO_GL).abs() < 1e-9);
+
+        let back = GdL::from_g_l(as_si);
+        assert!((back - value).abs() < 1e-9);
+    }
+
+    #[test]
+    fn gl_conversions_are_identity() {
+        let value = 3.8;
+        assert_eq!(GL::to_g_l(value), value);
+        assert_eq!(GL::from_g_l(value), value);
+    }
+}
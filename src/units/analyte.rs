@@ -0,0 +1,47 @@
+//! Analyte markers
+//!
+//! Zero-sized types naming what a concentration measures, used as the first type
+//! parameter of `Concentration<A, U>`. Several analytes share units (glucose and
+//! creatinine are both reported in mg/dL), so the unit alone can't keep them apart.
+
+/// An analyte that can be measured as a concentration.
+pub trait Analyte {
+    /// Name used in `Debug` output.
+    const NAME: &'static str;
+}
+
+macro_rules! analytes {
+    ($($marker:ident),+ $(,)?) => {
+        $(
+            #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+            pub struct $marker;
+            impl Analyte for $marker {
+                const NAME: &'static str = stringify!($marker);
+            }
+        )+
+    };
+}
+
+analytes!(
+    Albumin,
+    Bicarbonate,
+    Bilirubin,
+    Bnp,
+    Bun,
+    Calcium,
+    Chloride,
+    Cholesterol,
+    Cortisol,
+    Creatinine,
+    CystatinC,
+    Glucose,
+    Hdl,
+    Hemoglobin,
+    Lactate,
+    Magnesium,
+    NtProBnp,
+    Phosphate,
+    Potassium,
+    Sodium,
+    Troponin,
+);
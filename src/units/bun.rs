@@ -0,0 +1,48 @@
+use super::{MgdL, MmolL, Unit};
+use crate::constants::{BUN_MGDL_TO_UREA_MMOLL, UREA_MMOLL_TO_BUN_MGDL};
+
+/// Describes methods for converting blood urea nitrogen values to and from mmol/L.
+///
+/// Conversion factor: 1 mg/dL BUN = 0.357 mmol/L urea
+pub trait BunUnit: Unit {
+    fn to_urea_mmol_l(value: f64) -> f64;
+    fn from_urea_mmol_l(value: f64) -> f64;
+}
+impl BunUnit for MgdL {
+    fn to_urea_mmol_l(value: f64) -> f64 {
+        value * BUN_MGDL_TO_UREA_MMOLL
+    }
+    fn from_urea_mmol_l(value: f64) -> f64 {
+        value * UREA_MMOLL_TO_BUN_MGDL
+    }
+}
+impl BunUnit for MmolL {
+    fn to_urea_mmol_l(value: f64) -> f64 {
+        value
+    }
+    fn from_urea_mmol_l(value: f64) -> f64 {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mgdl_conversions_scale_by_constant() {
+        let value = 14.0;
+        let as_si = MgdL::to_urea_mmol_l(value);
+        assert!((as_si - value * BUN_MGDL_TO_UREA_MMOLL).abs() < 1e-9);
+
+        let back = MgdL::from_urea_mmol_l(as_si);
+        assert!((back - value).abs() < 1e-9);
+    }
+
+    #[test]
+    fn mmoll_conversions_are_identity() {
+        let value = 14.0;
+        assert_eq!(MmolL::to_urea_mmol_l(value), value);
+        assert_eq!(MmolL::from_urea_mmol_l(value), value);
+    }
+}
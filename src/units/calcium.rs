@@ -0,0 +1,68 @@
+use super::{electrolyte, MeqL, MgdL, MmolL, Unit};
+use crate::constants::{CA_MGDL_TO_MMOLL, CA_MMOLL_TO_MGDL};
+
+/// Charge of the calcium ion (Ca²⁺).
+pub const CA_VALENCE: u8 = 2;
+
+/// Describes methods for converting serum calcium values to and from mmol/L.
+///
+/// Conversion factors: 1 mmol/L = 4.008 mg/dL = 2 mEq/L
+///
+/// The methods are named for calcium so they don't collide with the glucose
+/// conversions on `MgdL` and `MmolL`, which implement both traits.
+pub trait CalciumUnit: Unit {
+    fn to_calcium_mmol_l(value: f64) -> f64;
+    fn from_calcium_mmol_l(value: f64) -> f64;
+}
+impl CalciumUnit for MgdL {
+    fn to_calcium_mmol_l(value: f64) -> f64 {
+        value * CA_MGDL_TO_MMOLL
+    }
+    fn from_calcium_mmol_l(value: f64) -> f64 {
+        value * CA_MMOLL_TO_MGDL
+    }
+}
+impl CalciumUnit for MmolL {
+    fn to_calcium_mmol_l(value: f64) -> f64 {
+        value
+    }
+    fn from_calcium_mmol_l(value: f64) -> f64 {
+        value
+    }
+}
+impl CalciumUnit for MeqL {
+    fn to_calcium_mmol_l(value: f64) -> f64 {
+        <MeqL as electrolyte::ElectrolyteUnit>::to_mmol_l(value, CA_VALENCE)
+    }
+    fn from_calcium_mmol_l(value: f64) -> f64 {
+        <MeqL as electrolyte::ElectrolyteUnit>::from_mmol_l(value, CA_VALENCE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mgdl_conversions_scale_by_constant() {
+        let value = 9.2;
+        let as_si = MgdL::to_calcium_mmol_l(value);
+        assert!((as_si - value * CA_MGDL_TO_MMOLL).abs() < 1e-9);
+
+        let back = MgdL::from_calcium_mmol_l(as_si);
+        assert!((back - value).abs() < 1e-9);
+    }
+
+    #[test]
+    fn meql_is_twice_mmoll() {
+        assert_eq!(MeqL::to_calcium_mmol_l(2.0), 1.0);
+        assert_eq!(MeqL::from_calcium_mmol_l(1.25), 2.5);
+    }
+
+    #[test]
+    fn mmoll_conversions_are_identity() {
+        let value = 9.2;
+        assert_eq!(MmolL::to_calcium_mmol_l(value), value);
+        assert_eq!(MmolL::from_calcium_mmol_l(value), value);
+    }
+}
@@ -0,0 +1,38 @@
+use super::{MeqL, MmolL, Unit};
+
+/// Describes a chloride measurement that can be converted to and from mmol/L units.
+pub trait ChlorideUnit: Unit {
+    fn to_mmol_l(val: f64) -> f64;
+    fn from_mmol_l(val: f64) -> f64;
+}
+impl ChlorideUnit for MeqL {
+    fn from_mmol_l(val: f64) -> f64 {
+        val
+    }
+    fn to_mmol_l(val: f64) -> f64 {
+        val
+    }
+}
+impl ChlorideUnit for MmolL {
+    fn from_mmol_l(val: f64) -> f64 {
+        val
+    }
+    fn to_mmol_l(val: f64) -> f64 {
+        val
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chloride_meq_and_mmol_are_equivalent() {
+        let value = 100.0;
+        assert_eq!(MeqL::to_mmol_l(value), value);
+        assert_eq!(MeqL::from_mmol_l(value), value);
+
+        assert_eq!(MmolL::to_mmol_l(value), value);
+        assert_eq!(MmolL::from_mmol_l(value), value);
+    }
+}
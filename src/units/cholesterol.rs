@@ -0,0 +1,42 @@
+use super::{MgdL, MmolL, Unit};
+use crate::constants::{CHOL_MGDL_TO_MMOLL, CHOL_MMOLL_TO_MGDL};
+
+/// Describes methods for converting cholesterol values (total, HDL or LDL) to and
+/// from mmol/L.
+///
+/// Conversion factor: 1 mg/dL = 0.0259 mmol/L
+pub trait CholesterolUnit: Unit {
+    fn to_cholesterol_mmol_l(value: f64) -> f64;
+    fn from_cholesterol_mmol_l(value: f64) -> f64;
+}
+impl CholesterolUnit for MgdL {
+    fn to_cholesterol_mmol_l(value: f64) -> f64 {
+        value * CHOL_MGDL_TO_MMOLL
+    }
+    fn from_cholesterol_mmol_l(value: f64) -> f64 {
+        value * CHOL_MMOLL_TO_MGDL
+    }
+}
+impl CholesterolUnit for MmolL {
+    fn to_cholesterol_mmol_l(value: f64) -> f64 {
+        value
+    }
+    fn from_cholesterol_mmol_l(value: f64) -> f64 {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mgdl_conversions_scale_by_constant() {
+        let value = 200.0;
+        let as_si = MgdL::to_cholesterol_mmol_l(value);
+        assert!((as_si - 5.18).abs() < 1e-9);
+
+        let back = MgdL::from_cholesterol_mmol_l(as_si);
+        assert!((back - value).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,47 @@
+use super::{NmolL, UgdL, Unit};
+use crate::constants::{CORTISOL_NMOLL_TO_UGDL, CORTISOL_UGDL_TO_NMOLL};
+
+/// Describes methods for converting cortisol values from µg/dL to nmol/L and back.
+///
+/// Conversion factor: 1 µg/dL = 27.59 nmol/L
+pub trait CortisolUnit: Unit {
+    fn to_nmol_l(value: f64) -> f64;
+    fn from_nmol_l(value: f64) -> f64;
+}
+impl CortisolUnit for UgdL {
+    fn to_nmol_l(value: f64) -> f64 {
+        value * CORTISOL_UGDL_TO_NMOLL
+    }
+    fn from_nmol_l(value: f64) -> f64 {
+        value * CORTISOL_NMOLL_TO_UGDL
+    }
+}
+impl CortisolUnit for NmolL {
+    fn to_nmol_l(value: f64) -> f64 {
+        value
+    }
+    fn from_nmol_l(value: f64) -> f64 {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ugdl_conversions_scale_by_constant() {
+        let as_nmol = UgdL::to_nmol_l(10.0);
+        assert!((as_nmol - 275.9).abs() < 1e-9);
+
+        let back_to_ugdl = UgdL::from_nmol_l(as_nmol);
+        assert!((back_to_ugdl - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn nmol_conversions_are_identity() {
+        let value = 300.0;
+        assert_eq!(NmolL::to_nmol_l(value), value);
+        assert_eq!(NmolL::from_nmol_l(value), value);
+    }
+}
@@ -0,0 +1,46 @@
+use super::{MeqL, MmolL, Unit};
+
+/// Describes methods for converting electrolyte values to and from mmol/L, given the
+/// ion's valence.
+///
+/// An equivalent is a mole of charge, so mEq/L = mmol/L × valence. For monovalent
+/// ions (Na⁺, K⁺, Cl⁻, HCO₃⁻) the two are the same; for divalent ions (Ca²⁺, Mg²⁺)
+/// 2 mEq/L is 1 mmol/L.
+pub trait ElectrolyteUnit: Unit {
+    fn to_mmol_l(value: f64, valence: u8) -> f64;
+    fn from_mmol_l(value: f64, valence: u8) -> f64;
+}
+impl ElectrolyteUnit for MmolL {
+    fn to_mmol_l(value: f64, _valence: u8) -> f64 {
+        value
+    }
+    fn from_mmol_l(value: f64, _valence: u8) -> f64 {
+        value
+    }
+}
+impl ElectrolyteUnit for MeqL {
+    fn to_mmol_l(value: f64, valence: u8) -> f64 {
+        value / f64::from(valence)
+    }
+    fn from_mmol_l(value: f64, valence: u8) -> f64 {
+        value * f64::from(valence)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn monovalent_meq_equals_mmol() {
+        assert_eq!(MeqL::to_mmol_l(140.0, 1), 140.0);
+        assert_eq!(MeqL::from_mmol_l(140.0, 1), 140.0);
+    }
+
+    #[test]
+    fn divalent_meq_is_twice_mmol() {
+        assert_eq!(MeqL::to_mmol_l(2.0, 2), 1.0);
+        assert_eq!(MeqL::from_mmol_l(1.0, 2), 2.0);
+        assert_eq!(MmolL::to_mmol_l(1.0, 2), 1.0);
+    }
+}
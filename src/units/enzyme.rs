@@ -0,0 +1,48 @@
+use super::{UkatL, Unit, UL};
+use crate::constants::{ENZ_UKATL_TO_UL, ENZ_UL_TO_UKATL};
+
+/// Describes methods for converting enzyme activities (e.g. AST, ALT) to and from U/L.
+///
+/// Conversion factor: 1 µkat/L = 60 U/L
+pub trait EnzymeUnit: Unit {
+    fn to_u_l(value: f64) -> f64;
+    fn from_u_l(value: f64) -> f64;
+}
+impl EnzymeUnit for UL {
+    fn to_u_l(value: f64) -> f64 {
+        value
+    }
+    fn from_u_l(value: f64) -> f64 {
+        value
+    }
+}
+impl EnzymeUnit for UkatL {
+    fn to_u_l(value: f64) -> f64 {
+        value * ENZ_UKATL_TO_UL
+    }
+    fn from_u_l(value: f64) -> f64 {
+        value * ENZ_UL_TO_UKATL
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ukatl_conversions_scale_by_constant() {
+        let value = 0.75;
+        let as_u_l = UkatL::to_u_l(value);
+        assert!((as_u_l - 45.0).abs() < 1e-9);
+
+        let back = UkatL::from_u_l(as_u_l);
+        assert!((back - value).abs() < 1e-9);
+    }
+
+    #[test]
+    fn ul_conversions_are_identity() {
+        let value = 45.0;
+        assert_eq!(UL::to_u_l(value), value);
+        assert_eq!(UL::from_u_l(value), value);
+    }
+}
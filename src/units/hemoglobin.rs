@@ -0,0 +1,41 @@
+use super::{GdL, Unit, GL};
+use crate::constants::{HGB_GDL_TO_GL, HGB_GL_TO_GDL};
+
+/// Describes methods for converting hemoglobin values to and from g/L.
+///
+/// Conversion factor: 1 g/dL = 10 g/L
+pub trait HemoglobinUnit: Unit {
+    fn to_g_l(value: f64) -> f64;
+    fn from_g_l(value: f64) -> f64;
+}
+impl HemoglobinUnit for GdL {
+    fn to_g_l(value: f64) -> f64 {
+        value * HGB_GDL_TO_GL
+    }
+    fn from_g_l(value: f64) -> f64 {
+        value * HGB_GL_TO_GDL
+    }
+}
+impl HemoglobinUnit for GL {
+    fn to_g_l(value: f64) -> f64 {
+        value
+    }
+    fn from_g_l(value: f64) -> f64 {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gdl_conversions_scale_by_constant() {
+        let value = 13.5;
+        let as_si = GdL::to_g_l(value);
+        assert!((as_si - value * HGB_GDL_TO_GL).abs() < 1e-9);
+
+        let back = GdL::from_g_l(as_si);
+        assert!((back - value).abs() < 1e-9);
+    }
+}
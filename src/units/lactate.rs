@@ -0,0 +1,35 @@
+use super::{MgdL, MmolL, Unit};
+use crate::constants::{LAC_MGDL_TO_MMOLL, LAC_MMOLL_TO_MGDL};
+
+/// Describes methods to convert lactate values to/from mmol/L for calculations.
+pub trait LactateUnit: Unit {
+    fn to_lactate_mmol_l(val: f64) -> f64;
+    fn from_lactate_mmol_l(val: f64) -> f64;
+}
+impl LactateUnit for MgdL {
+    fn to_lactate_mmol_l(val: f64) -> f64 {
+        val * LAC_MGDL_TO_MMOLL
+    }
+    fn from_lactate_mmol_l(val: f64) -> f64 {
+        val * LAC_MMOLL_TO_MGDL
+    }
+}
+impl LactateUnit for MmolL {
+    fn from_lactate_mmol_l(val: f64) -> f64 {
+        val
+    }
+    fn to_lactate_mmol_l(val: f64) -> f64 {
+        val
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mgdl_is_nine_times_mmoll() {
+        assert_eq!(MgdL::from_lactate_mmol_l(4.0), 36.0);
+        assert!((MgdL::to_lactate_mmol_l(18.0) - 2.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,58 @@
+use super::{electrolyte, MeqL, MgdL, MmolL, Unit};
+use crate::constants::{MG_MGDL_TO_MMOLL, MG_MMOLL_TO_MGDL};
+
+/// Charge of the magnesium ion (Mg²⁺).
+pub const MG_VALENCE: u8 = 2;
+
+/// Describes methods for converting serum magnesium values to and from mmol/L.
+///
+/// Conversion factors: 1 mg/dL = 0.411 mmol/L; 1 mmol/L = 2 mEq/L
+pub trait MagnesiumUnit: Unit {
+    fn to_magnesium_mmol_l(value: f64) -> f64;
+    fn from_magnesium_mmol_l(value: f64) -> f64;
+}
+impl MagnesiumUnit for MgdL {
+    fn to_magnesium_mmol_l(value: f64) -> f64 {
+        value * MG_MGDL_TO_MMOLL
+    }
+    fn from_magnesium_mmol_l(value: f64) -> f64 {
+        value * MG_MMOLL_TO_MGDL
+    }
+}
+impl MagnesiumUnit for MmolL {
+    fn to_magnesium_mmol_l(value: f64) -> f64 {
+        value
+    }
+    fn from_magnesium_mmol_l(value: f64) -> f64 {
+        value
+    }
+}
+impl MagnesiumUnit for MeqL {
+    fn to_magnesium_mmol_l(value: f64) -> f64 {
+        <MeqL as electrolyte::ElectrolyteUnit>::to_mmol_l(value, MG_VALENCE)
+    }
+    fn from_magnesium_mmol_l(value: f64) -> f64 {
+        <MeqL as electrolyte::ElectrolyteUnit>::from_mmol_l(value, MG_VALENCE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mgdl_conversions_scale_by_constant() {
+        let value = 2.0;
+        let as_si = MgdL::to_magnesium_mmol_l(value);
+        assert!((as_si - 0.822).abs() < 1e-9);
+
+        let back = MgdL::from_magnesium_mmol_l(as_si);
+        assert!((back - value).abs() < 1e-9);
+    }
+
+    #[test]
+    fn meql_is_twice_mmoll() {
+        assert_eq!(MeqL::to_magnesium_mmol_l(2.0), 1.0);
+        assert_eq!(MeqL::from_magnesium_mmol_l(0.85), 1.7);
+    }
+}
@@ -0,0 +1,41 @@
+use super::{MgdL, MmolL, Unit};
+use crate::constants::{PHOS_MGDL_TO_MMOLL, PHOS_MMOLL_TO_MGDL};
+
+/// Describes methods for converting serum phosphate values to and from mmol/L.
+///
+/// Conversion factor: 1 mg/dL = 0.323 mmol/L (as elemental phosphorus)
+pub trait PhosphateUnit: Unit {
+    fn to_phosphate_mmol_l(value: f64) -> f64;
+    fn from_phosphate_mmol_l(value: f64) -> f64;
+}
+impl PhosphateUnit for MgdL {
+    fn to_phosphate_mmol_l(value: f64) -> f64 {
+        value * PHOS_MGDL_TO_MMOLL
+    }
+    fn from_phosphate_mmol_l(value: f64) -> f64 {
+        value * PHOS_MMOLL_TO_MGDL
+    }
+}
+impl PhosphateUnit for MmolL {
+    fn to_phosphate_mmol_l(value: f64) -> f64 {
+        value
+    }
+    fn from_phosphate_mmol_l(value: f64) -> f64 {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mgdl_conversions_scale_by_constant() {
+        let value = 3.5;
+        let as_si = MgdL::to_phosphate_mmol_l(value);
+        assert!((as_si - value * PHOS_MGDL_TO_MMOLL).abs() < 1e-9);
+
+        let back = MgdL::from_phosphate_mmol_l(as_si);
+        assert!((back - value).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,43 @@
+use super::{NgL, NgmL, Unit};
+use crate::constants::{TROP_NGL_TO_NGML, TROP_NGML_TO_NGL};
+
+/// Describes methods for converting troponin values to and from ng/L.
+///
+/// Conversion factor: 1 ng/mL = 1000 ng/L
+pub trait TroponinUnit: Unit {
+    fn to_ng_l(value: f64) -> f64;
+    fn from_ng_l(value: f64) -> f64;
+}
+impl TroponinUnit for NgmL {
+    fn to_ng_l(value: f64) -> f64 {
+        value * TROP_NGML_TO_NGL
+    }
+    fn from_ng_l(value: f64) -> f64 {
+        value * TROP_NGL_TO_NGML
+    }
+}
+impl TroponinUnit for NgL {
+    fn to_ng_l(value: f64) -> f64 {
+        value
+    }
+    fn from_ng_l(value: f64) -> f64 {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ngml_conversions_scale_by_constant() {
+        assert!((NgmL::to_ng_l(0.04) - 40.0).abs() < 1e-9);
+        assert!((NgmL::from_ng_l(40.0) - 0.04).abs() < 1e-12);
+    }
+
+    #[test]
+    fn ngl_conversions_are_identity() {
+        assert_eq!(NgL::to_ng_l(12.0), 12.0);
+        assert_eq!(NgL::from_ng_l(12.0), 12.0);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     constants::{FT_TO_M, KG_TO_LB, LB_TO_KG, M_TO_FT},
-    units::{Foot, Kg, Lb, Meter},
+    units::{Celsius, Fahrenheit, Foot, Kg, Lb, Meter},
 };
 
 use super::Unit;
@@ -54,3 +54,28 @@ impl HeightUnit for Foot {
         val * FT_TO_M
     }
 }
+
+//
+//      Temperature Units
+//
+
+pub trait TemperatureUnit: Unit {
+    fn to_celsius(val: f64) -> f64;
+    fn from_celsius(val: f64) -> f64;
+}
+impl TemperatureUnit for Celsius {
+    fn to_celsius(val: f64) -> f64 {
+        val
+    }
+    fn from_celsius(val: f64) -> f64 {
+        val
+    }
+}
+impl TemperatureUnit for Fahrenheit {
+    fn to_celsius(val: f64) -> f64 {
+        (val - 32.0) * 5.0 / 9.0
+    }
+    fn from_celsius(val: f64) -> f64 {
+        val * 9.0 / 5.0 + 32.0
+    }
+}
@@ -0,0 +1,30 @@
+use medicalc::batch::{process_egfr_csv, CsvError};
+
+#[test]
+fn egfr_csv_appends_egfr_and_stage_columns() {
+    let input = "scr,unit,age,sex\n\
+                 0.8,mg/dL,45,F\n\
+                 1.4,mg/dL,70,M\n\
+                 \n\
+                 265.2,µmol/L,62,Female\n";
+    let expected = "scr,unit,age,sex,egfr,ckd_stage\n\
+                    0.8,mg/dL,45,F,93,G1\n\
+                    1.4,mg/dL,70,M,54,G3a\n\
+                    265.2,µmol/L,62,Female,17,G4\n";
+
+    assert_eq!(process_egfr_csv(input).unwrap(), expected);
+}
+
+#[test]
+fn egfr_csv_reports_the_offending_line() {
+    let input = "scr,unit,age,sex\n1.0,mg/dL,50,M\nabc,mg/dL,50,M\n";
+
+    assert_eq!(
+        process_egfr_csv(input),
+        Err(CsvError::InvalidValue {
+            line: 3,
+            column: "scr",
+            value: "abc".to_string()
+        })
+    );
+}
@@ -0,0 +1,286 @@
+//! Round-trip precision checks for every pair of unit conversions.
+//!
+//! Each pair is converted forward and back over a spread of values, and the worst
+//! relative error is compared against `TOLERANCE`. The tests report every pair that
+//! exceeds it rather than stopping at the first, so one run shows the whole picture.
+
+use medicalc::{
+    constants::*,
+    lab::{
+        blood::{
+            albumin::{Albumin, SerumAlbuminExt},
+            alt::{Alt, AltExt},
+            ast::{Ast, AstExt},
+            bicarbonate::{Bicarbonate, SerumBicarbonateExt},
+            bilirubin::{Bilirubin, BilirubinExt},
+            bun::{Bun, BunExt},
+            calcium::{Calcium, SerumCalciumExt},
+            chloride::{Chloride, SerumChlorideExt},
+            cholesterol::{Cholesterol, CholesterolExt, Hdl, HdlExt},
+            cortisol::{Cortisol, CortisolExt},
+            creatinine::{Creatinine, CreatinineExt},
+            glucose::{Glucose, SerumGlucoseExt},
+            hemoglobin::{Hemoglobin, SerumHemoglobinExt},
+            lactate::{Lactate, SerumLactateExt},
+            magnesium::{Magnesium, SerumMagnesiumExt},
+            phosphate::{Phosphate, SerumPhosphateExt},
+            potassium::{Potassium, SerumPotassiumExt},
+            sodium::{SerumSodiumExt, Sodium},
+            troponin::{Troponin, TroponinExt},
+            white_blood_cells::{WhiteBloodCells, WhiteBloodCellsExt},
+        },
+        vitals::{Height, HeightExt, Temperature, TemperatureExt, Weight, WeightExt},
+    },
+    units::*,
+};
+
+/// Largest acceptable relative error after converting a value there and back.
+const TOLERANCE: f64 = 1e-12;
+
+/// Values to push through each conversion, spanning several orders of magnitude.
+const SAMPLES: [f64; 8] = [0.01, 0.5, 1.0, 3.7, 17.1, 88.4, 140.0, 2500.0];
+
+/// Worst relative error of `round_trip` over all `SAMPLES`.
+fn worst_error(round_trip: impl Fn(f64) -> f64) -> f64 {
+    SAMPLES
+        .iter()
+        .map(|&value| ((round_trip(value) - value) / value).abs())
+        .fold(0.0, f64::max)
+}
+
+/// Builds a `(name, worst error)` entry for converting values made by `$ctor` to
+/// `$ty<$to>` and back again.
+macro_rules! round_trip {
+    ($name:literal, $ty:ident, $ctor:ident, $to:ty) => {
+        (
+            $name,
+            worst_error(|v| {
+                let mut back = v.$ctor();
+                let converted: $ty<$to> = back.into();
+                back = converted.into();
+                back.value()
+            }),
+        )
+    };
+}
+
+#[test]
+fn conversion_pairs_round_trip_within_tolerance() {
+    let results = [
+        round_trip!("Albumin g/dL -> g/L", Albumin, alb_serum_g_dl, GL),
+        round_trip!("Albumin g/L -> g/dL", Albumin, alb_serum_g_l, GdL),
+        round_trip!(
+            "Cholesterol mg/dL -> mmol/L",
+            Cholesterol,
+            chol_total_mg_dl,
+            MmolL
+        ),
+        round_trip!(
+            "Cholesterol mmol/L -> mg/dL",
+            Cholesterol,
+            chol_total_mmol_l,
+            MgdL
+        ),
+        round_trip!("HDL mg/dL -> mmol/L", Hdl, hdl_mg_dl, MmolL),
+        round_trip!("HDL mmol/L -> mg/dL", Hdl, hdl_mmol_l, MgdL),
+        round_trip!("Hemoglobin g/dL -> g/L", Hemoglobin, hgb_g_dl, GL),
+        round_trip!("Hemoglobin g/L -> g/dL", Hemoglobin, hgb_g_l, GdL),
+        round_trip!("Lactate mmol/L -> mg/dL", Lactate, lac_serum_mmol_l, MgdL),
+        round_trip!("Lactate mg/dL -> mmol/L", Lactate, lac_serum_mg_dl, MmolL),
+        round_trip!(
+            "Magnesium mg/dL -> mmol/L",
+            Magnesium,
+            mg_serum_mg_dl,
+            MmolL
+        ),
+        round_trip!(
+            "Magnesium mmol/L -> mg/dL",
+            Magnesium,
+            mg_serum_mmol_l,
+            MgdL
+        ),
+        round_trip!("Magnesium mEq/L -> mg/dL", Magnesium, mg_serum_meq_l, MgdL),
+        round_trip!("Magnesium mg/dL -> mEq/L", Magnesium, mg_serum_mg_dl, MeqL),
+        round_trip!(
+            "Phosphate mg/dL -> mmol/L",
+            Phosphate,
+            phos_serum_mg_dl,
+            MmolL
+        ),
+        round_trip!(
+            "Phosphate mmol/L -> mg/dL",
+            Phosphate,
+            phos_serum_mmol_l,
+            MgdL
+        ),
+        round_trip!("ALT U/L -> µkat/L", Alt, alt_u_l, UkatL),
+        round_trip!("ALT µkat/L -> U/L", Alt, alt_ukat_l, UL),
+        round_trip!("AST U/L -> µkat/L", Ast, ast_u_l, UkatL),
+        round_trip!("AST µkat/L -> U/L", Ast, ast_ukat_l, UL),
+        round_trip!(
+            "Bicarbonate mEq/L -> mmol/L",
+            Bicarbonate,
+            hco3_serum_meq,
+            MmolL
+        ),
+        round_trip!(
+            "Bicarbonate mmol/L -> mEq/L",
+            Bicarbonate,
+            hco3_serum_mmol,
+            MeqL
+        ),
+        round_trip!(
+            "Bilirubin mg/dL -> µmol/L",
+            Bilirubin,
+            serum_bili_mgdl,
+            UmolL
+        ),
+        round_trip!(
+            "Bilirubin µmol/L -> mg/dL",
+            Bilirubin,
+            serum_bili_umoll,
+            MgdL
+        ),
+        round_trip!("BUN mg/dL -> urea mmol/L", Bun, bun_mg_dl, MmolL),
+        round_trip!("Urea mmol/L -> BUN mg/dL", Bun, urea_mmol_l, MgdL),
+        round_trip!("Calcium mg/dL -> mmol/L", Calcium, ca_serum_mg_dl, MmolL),
+        round_trip!("Calcium mmol/L -> mg/dL", Calcium, ca_serum_mmol_l, MgdL),
+        round_trip!("Calcium mEq/L -> mmol/L", Calcium, ca_serum_meq_l, MmolL),
+        round_trip!("Calcium mmol/L -> mEq/L", Calcium, ca_serum_mmol_l, MeqL),
+        round_trip!("Calcium mEq/L -> mg/dL", Calcium, ca_serum_meq_l, MgdL),
+        round_trip!("Calcium mg/dL -> mEq/L", Calcium, ca_serum_mg_dl, MeqL),
+        round_trip!("Chloride mEq/L -> mmol/L", Chloride, cl_serum_meq, MmolL),
+        round_trip!("Chloride mmol/L -> mEq/L", Chloride, cl_serum_mmol, MeqL),
+        round_trip!("Cortisol µg/dL -> nmol/L", Cortisol, cortisol_ug_dl, NmolL),
+        round_trip!("Cortisol nmol/L -> µg/dL", Cortisol, cortisol_nmol_l, UgdL),
+        round_trip!(
+            "Creatinine mg/dL -> µmol/L",
+            Creatinine,
+            cr_serum_mg_dl,
+            UmolL
+        ),
+        round_trip!(
+            "Creatinine µmol/L -> mg/dL",
+            Creatinine,
+            cr_serum_umol_l,
+            MgdL
+        ),
+        round_trip!("Glucose mg/dL -> mmol/L", Glucose, glu_serum_mg_dl, MmolL),
+        round_trip!("Glucose mmol/L -> mg/dL", Glucose, glu_serum_mmol_l, MgdL),
+        round_trip!("Potassium mEq/L -> mmol/L", Potassium, k_serum_meq, MmolL),
+        round_trip!("Potassium mmol/L -> mEq/L", Potassium, k_serum_mmol, MeqL),
+        round_trip!("Sodium mEq/L -> mmol/L", Sodium, na_serum_meq, MmolL),
+        round_trip!("Sodium mmol/L -> mEq/L", Sodium, na_serum_mmol, MeqL),
+        round_trip!("Troponin ng/L -> ng/mL", Troponin, trop_ng_l, NgmL),
+        round_trip!("Troponin ng/mL -> ng/L", Troponin, trop_ng_ml, NgL),
+        round_trip!("WBC ×10⁹/L -> /L", WhiteBloodCells, wbc_x10e9_l, PerLiter),
+        round_trip!("WBC /L -> ×10⁹/L", WhiteBloodCells, wbc_per_l, PerNanoLiter),
+        round_trip!("Weight kg -> lb", Weight, weight_kg, Lb),
+        round_trip!("Weight lb -> kg", Weight, weight_lb, Kg),
+        round_trip!("Height m -> ft", Height, height_in_m, Foot),
+        round_trip!("Height ft -> m", Height, height_in_ft, Meter),
+        round_trip!("Temperature °C -> °F", Temperature, temp_c, Fahrenheit),
+        round_trip!("Temperature °F -> °C", Temperature, temp_f, Celsius),
+    ];
+
+    let failures: Vec<String> = results
+        .iter()
+        .filter(|(_, error)| *error > TOLERANCE)
+        .map(|(name, error)| format!("{name}: relative error {error:e}"))
+        .collect();
+    assert!(
+        failures.is_empty(),
+        "round trips exceeding {TOLERANCE:e}:\n{}",
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn conversion_constants_are_reciprocal_pairs() {
+    // Each pair should be defined as a factor and its `.recip()`; a pair typed in
+    // separately (e.g. 17.1 and 0.0585) would drift here.
+    let pairs = [
+        ("glucose", GLU_MMOLL_TO_MGDL, GLU_MGDL_TO_MMOLL),
+        ("creatinine", SCR_MGDL_TO_UMOLL, SCR_UMOLL_TO_MGDL),
+        ("weight", LB_TO_KG, KG_TO_LB),
+        ("height", FT_TO_M, M_TO_FT),
+        ("bilirubin", SBILI_MGDL_TO_UMOLL, SBILI_UMOLL_TO_MGDL),
+        ("cortisol", CORTISOL_UGDL_TO_NMOLL, CORTISOL_NMOLL_TO_UGDL),
+        ("calcium", CA_MGDL_TO_MMOLL, CA_MMOLL_TO_MGDL),
+        ("albumin", ALB_GDL_TO_GL, ALB_GL_TO_GDL),
+        ("troponin", TROP_NGML_TO_NGL, TROP_NGL_TO_NGML),
+        ("BUN / urea", BUN_MGDL_TO_UREA_MMOLL, UREA_MMOLL_TO_BUN_MGDL),
+        ("enzyme activity", ENZ_UKATL_TO_UL, ENZ_UL_TO_UKATL),
+        ("cell count", CELLS_PER_NL_TO_PER_L, CELLS_PER_L_TO_PER_NL),
+    ];
+
+    let asymmetric: Vec<String> = pairs
+        .iter()
+        .filter(|(_, there, back)| (there * back - 1.0).abs() > TOLERANCE)
+        .map(|(name, there, back)| format!("{name}: {there} × {back} = {}", there * back))
+        .collect();
+    assert!(
+        asymmetric.is_empty(),
+        "asymmetric conversion constants:\n{}",
+        asymmetric.join("\n")
+    );
+}
+
+#[test]
+fn convert_round_trips_within_tolerance() {
+    const CONVERT_TOLERANCE: f64 = 1e-9;
+    let results = [
+        (
+            "creatinine",
+            worst_error(|v| {
+                v.cr_serum_mg_dl()
+                    .convert::<UmolL>()
+                    .convert::<MgdL>()
+                    .value()
+            }),
+        ),
+        (
+            "glucose",
+            worst_error(|v| {
+                v.glu_serum_mmol_l()
+                    .convert::<MgdL>()
+                    .convert::<MmolL>()
+                    .value()
+            }),
+        ),
+        (
+            "sodium",
+            worst_error(|v| {
+                v.na_serum_meq()
+                    .convert::<MmolL>()
+                    .convert::<MeqL>()
+                    .value()
+            }),
+        ),
+        (
+            "bilirubin",
+            worst_error(|v| {
+                v.serum_bili_umoll()
+                    .convert::<MgdL>()
+                    .convert::<UmolL>()
+                    .value()
+            }),
+        ),
+    ];
+    for (name, error) in results {
+        assert!(
+            error <= CONVERT_TOLERANCE,
+            "{name}: relative error {error:e}"
+        );
+    }
+}
+
+#[test]
+fn convert_to_same_unit_is_a_no_op() {
+    for v in SAMPLES {
+        assert_eq!(v.cr_serum_mg_dl().convert::<MgdL>().value(), v);
+        assert_eq!(v.glu_serum_mmol_l().convert::<MmolL>().value(), v);
+        assert_eq!(v.na_serum_mmol().convert::<MmolL>().value(), v);
+        assert_eq!(v.serum_bili_mgdl().convert::<MgdL>().value(), v);
+    }
+}